@@ -0,0 +1,183 @@
+use crate::*;
+
+use arc_standard::guild::test_utils::*;
+use arc_standard::{
+    get_account_bob, get_account_nitya, get_account_nodra, get_account_vecno, get_guild_id,
+};
+use near_sdk::test_utils::get_created_receipts;
+use near_sdk::{PromiseResult, ONE_NEAR};
+
+const BOND: Balance = ONE_NEAR;
+
+fn get_contract_api() -> Contract {
+    Contract {
+        guild: get_contract(),
+    }
+}
+
+/// Passes a policy motion that requires a bond to open a spend.
+fn set_spend_bond(contract: &mut Contract) {
+    let policy = VotePolicy {
+        bond: Some(U128(BOND)),
+        ..get_default_policy(MotionKind::SpendContract)
+    };
+    set_caller(get_account_vecno!());
+    contract.act_policy_contract(MotionKind::SpendContract, policy, "bond".to_string());
+    for account in [
+        get_account_vecno!(),
+        get_account_nodra!(),
+        get_account_nitya!(),
+        get_account_bob!(),
+    ] {
+        set_caller(account);
+        contract.vote_policy_contract();
+    }
+}
+
+fn get_open_motion(contract: &Contract) -> MotionId {
+    let motions = contract.guild_motions(Some(MotionStatus::Open), None, None);
+    assert_eq!(1, motions.len());
+    motions[0].id.clone()
+}
+
+fn pass_spend(contract: &mut Contract, id: &MotionId) {
+    for account in [
+        get_account_vecno!(),
+        get_account_nodra!(),
+        get_account_nitya!(),
+    ] {
+        set_caller(account);
+        contract.vote_spend_contract(id.clone());
+    }
+}
+
+// ==== Contract ====
+
+#[test]
+fn contract_initialize() {
+    set_caller(get_account_vecno!());
+    let mut board_map = HashMap::new();
+    board_map.insert(get_account_nodra!(), 5000);
+    let mut member_map = HashMap::new();
+    member_map.insert(get_account_nodra!(), U128(100));
+    member_map.insert(get_account_vecno!(), U128(100));
+    let contract =
+        Contract::initialize(get_guild_id!(), get_guild_info(1, 2), board_map, member_map);
+
+    let guild = contract
+        .guild_info(get_guild_id!())
+        .expect("missing guild info");
+    assert_eq!(get_account_vecno!(), guild.ceo_id);
+    assert_eq!(1, guild.board_size);
+    assert_eq!(2, guild.members_size);
+
+    let state = contract.guild_contract_state();
+    assert_eq!(LockedFor::None, state.lock);
+    assert!(state.vote.is_none());
+    assert!(contract.get_available_amount().0 > 0);
+}
+
+#[test]
+fn contract_lock() {
+    let mut contract = get_contract_api();
+    set_caller(get_account_vecno!());
+    contract.act_lock_contract(None, false);
+    assert_eq!(LockedFor::Emergency, contract.guild_contract_state().lock);
+}
+
+#[test]
+fn contract_lock_guild() {
+    let mut contract = get_contract_api();
+    set_caller(get_account_nodra!());
+    contract.act_lock_guild(get_guild_id!(), Some("lock".to_string()));
+    contract.vote_lock_guild(get_guild_id!());
+
+    let motion = contract
+        .guild_latest_motion(get_guild_id!())
+        .expect("missing lock motion");
+    assert_eq!(
+        Some(get_vote_id_agree()),
+        contract.guild_motion_vote(motion, get_account_nodra!())
+    );
+}
+
+// ==== Income ====
+
+#[test]
+fn contract_income() {
+    let mut contract = get_contract_api();
+    set_deposit(get_account_bob!(), 100 * ONE_NEAR);
+    contract.deposit_guild_income(get_guild_id!());
+
+    // members 50% / 4
+    let balance = contract.guild_income_balance(get_guild_id!(), get_account_bob!());
+    assert_eq!(U128(12_500 * ONE_NEAR / 1000), balance);
+
+    set_caller(get_account_bob!());
+    contract.claim_guild_income(get_guild_id!());
+    let receipts = get_created_receipts();
+    assert_eq!(get_account_bob!(), receipts[0].receiver_id);
+    assert_eq!(
+        U128(0),
+        contract.guild_income_balance(get_guild_id!(), get_account_bob!())
+    );
+
+    // Note: A failed transfer restores the balance
+    set_result(PromiseResult::Failed);
+    contract.resolve_claim_guild_income(get_guild_id!(), get_account_bob!(), balance);
+    assert_eq!(
+        balance,
+        contract.guild_income_balance(get_guild_id!(), get_account_bob!())
+    );
+}
+
+// ==== Treasury ====
+
+#[test]
+fn contract_spend() {
+    let mut contract = get_contract_api();
+    set_caller(get_account_nodra!());
+    let available = contract.get_available_amount().0;
+    contract.act_spend_contract(get_account_bob!(), U128(10 * ONE_NEAR), "spend".to_string());
+    let id = get_open_motion(&contract);
+    assert!(contract.get_available_amount().0 <= available - 10 * ONE_NEAR);
+
+    pass_spend(&mut contract, &id);
+    let receipts = get_created_receipts();
+    assert_eq!(get_account_bob!(), receipts[0].receiver_id);
+    let spend = contract.guild_treasury_spend(id.clone()).unwrap();
+    assert_eq!(SpendStatus::Paying, spend.status);
+
+    set_result(PromiseResult::Successful(vec![]));
+    assert!(contract.resolve_spend_contract(id.clone()));
+    let spend = contract.guild_treasury_spend(id).unwrap();
+    assert_eq!(SpendStatus::Paid, spend.status);
+}
+
+// ==== Bonds ====
+
+#[test]
+fn contract_spend_bond() {
+    let mut contract = get_contract_api();
+    set_spend_bond(&mut contract);
+
+    set_deposit(get_account_nodra!(), BOND);
+    let available = contract.get_available_amount().0;
+    contract.act_spend_contract(get_account_bob!(), U128(ONE_NEAR), "spend".to_string());
+    let id = get_open_motion(&contract);
+
+    let bond = contract.guild_motion(id).unwrap().bond.unwrap();
+    assert_eq!(U128(BOND), bond.amount);
+    assert_eq!(BondStatus::Held, bond.status);
+    assert!(contract.get_available_amount().0 <= available - BOND - ONE_NEAR);
+}
+
+#[test]
+#[should_panic(expected = "The motion requires a bond of")]
+fn contract_spend_bond_missing() {
+    let mut contract = get_contract_api();
+    set_spend_bond(&mut contract);
+
+    set_caller(get_account_nodra!());
+    contract.act_spend_contract(get_account_bob!(), U128(ONE_NEAR), "spend".to_string());
+}
@@ -2,6 +2,7 @@
 set -e
 
 cd ./arc-actor && ./build.sh && cd .. 
+cd ./arc-guild && ./build.sh && cd .. 
 
 # cd ./app-core && ./build.sh && cd .. 
 # cd ./app-market && ./build.sh && cd ..
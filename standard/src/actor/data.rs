@@ -91,11 +91,11 @@ impl Handle {
         // Note: Look in to regex in near
         // https://github.com/rust-lang/regex
         require!(
-            &self.last.len() < &16,
+            self.last.len() < 16,
             "Last name can not be longer than 16 characters"
         );
         require!(
-            &self.first.len() > &2 && &self.first.len() < &16,
+            self.first.len() > 2 && self.first.len() < 16,
             "First name needs to be between 2 and 16 characters"
         );
     }
@@ -19,14 +19,19 @@ pub struct Actors {
     pub link_for_token: LookupMap<TokenId, TokenId>,
 }
 
+impl Default for Actors {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl Actors {
     pub fn new() -> Self {
-        let this = Self {
+        Self {
             data_for_id: UnorderedMap::new(StorageKey::ActorDataForId.try_to_vec().unwrap()),
             list_per_owner: LookupMap::new(StorageKey::ActorListPerOwner.try_to_vec().unwrap()),
             link_for_token: LookupMap::new(StorageKey::ActorLinkForToken.try_to_vec().unwrap()),
-        };
-        this
+        }
     }
 
     pub fn register(
@@ -43,7 +48,7 @@ impl Actors {
             "An actor with the provided id already exits"
         );
 
-        self.add_to_owner(owner.account.clone().into(), token_id);
+        self.add_to_owner(owner.account.clone(), token_id);
 
         let arc_register_log: ArcEventLog = ArcEventLog {
             module: EVENT_ARC_STANDARD_ACTOR.to_string(),
@@ -58,8 +63,8 @@ impl Actors {
     }
 
     pub fn transfer(&mut self, token_id: &TokenId, sender_id: &AccountId, receiver_id: &AccountId) {
-        self.remove_from_owner(sender_id.clone().into(), &token_id);
-        self.add_to_owner(receiver_id.clone().into(), &token_id);
+        self.remove_from_owner(sender_id.clone(), token_id);
+        self.add_to_owner(receiver_id.clone(), token_id);
     }
 }
 
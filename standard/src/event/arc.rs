@@ -15,6 +15,24 @@ pub const EVENT_ARC_STANDARD_GUILD: &str = "guild";
 #[non_exhaustive]
 pub enum ArcEventVariant {
     ArcRegister(Vec<ArcRegisterLog>),
+    GuildRegister(Vec<GuildRegisterLog>),
+    GuildLock(Vec<GuildLockLog>),
+    GuildUnlock(Vec<GuildLockLog>),
+    GuildMemberAdd(Vec<GuildMemberLog>),
+    GuildMemberRemove(Vec<GuildMemberLog>),
+    GuildMemberStake(Vec<GuildMemberLog>),
+    GuildBoardSeat(Vec<GuildBoardLog>),
+    GuildBoardRemove(Vec<GuildBoardLog>),
+    GuildCeoReplace(Vec<GuildCeoLog>),
+    PolicyChange(Vec<PolicyChangeLog>),
+    ContractChange(Vec<ContractChangeLog>),
+    SpendPay(Vec<SpendPayLog>),
+    ProposalExecute(Vec<ProposalExecuteLog>),
+    MotionIssue(Vec<MotionIssueLog>),
+    MotionVote(Vec<MotionVoteLog>),
+    MotionUnvote(Vec<MotionVoteLog>),
+    MotionExecute(Vec<MotionExecuteLog>),
+    MotionFinalize(Vec<MotionFinalizeLog>),
 }
 
 /// Interface to capture data about an event
@@ -58,6 +76,193 @@ pub struct ArcRegisterLog {
     pub memo: Option<String>,
 }
 
+/// An event log to capture guild registration
+///
+/// Arguments
+/// * `guild_id`: "G:01"
+/// * `ceo_id`: "ceo.near"
+/// * `board_ids`: ["board.near"]
+/// * `member_ids`: ["ceo.near", "member.near"]
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(crate = "near_sdk::serde")]
+pub struct GuildRegisterLog {
+    pub guild_id: String,
+    pub ceo_id: String,
+    pub board_ids: Vec<String>,
+    pub member_ids: Vec<String>,
+}
+
+/// An event log to capture a lock change on a guild or the contract
+///
+/// Arguments
+/// * `guild_id`: "G:01", none for the contract lock
+/// * `lock`: the lock that is set or lifted, e.g. "Emergency"
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(crate = "near_sdk::serde")]
+pub struct GuildLockLog {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub guild_id: Option<String>,
+    pub lock: String,
+}
+
+/// An event log to capture a member joining or leaving a guild, or a change of its stake
+///
+/// Arguments
+/// * `guild_id`: "G:01"
+/// * `account_id`: "member.near"
+/// * `stake`: "100", the stake that is added, removed or set
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(crate = "near_sdk::serde")]
+pub struct GuildMemberLog {
+    pub guild_id: String,
+    pub account_id: String,
+    pub stake: String,
+}
+
+/// An event log to capture a board member being seated or removed
+///
+/// Arguments
+/// * `guild_id`: "G:01"
+/// * `account_id`: "board.near"
+/// * `share`: "5000", the board share in basis points
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(crate = "near_sdk::serde")]
+pub struct GuildBoardLog {
+    pub guild_id: String,
+    pub account_id: String,
+    pub share: String,
+}
+
+/// An event log to capture the CEO of a guild being replaced
+///
+/// Arguments
+/// * `guild_id`: "G:01"
+/// * `ceo_id`: "ceo.near", the new CEO
+/// * `prev_id`: "old.near", the replaced CEO
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(crate = "near_sdk::serde")]
+pub struct GuildCeoLog {
+    pub guild_id: String,
+    pub ceo_id: String,
+    pub prev_id: String,
+}
+
+/// An event log to capture the vote policy of a motion kind being changed
+///
+/// Arguments
+/// * `kind`: "ChallengeContract"
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(crate = "near_sdk::serde")]
+pub struct PolicyChangeLog {
+    pub kind: String,
+}
+
+/// An event log to capture a contract setting being changed
+///
+/// Arguments
+/// * `change`: "guardian" or "create"
+/// * `value`: "guardian.near" or "Open", none when the setting is cleared
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(crate = "near_sdk::serde")]
+pub struct ContractChangeLog {
+    pub change: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value: Option<String>,
+}
+
+/// An event log to capture the payout of a treasury spend
+///
+/// Arguments
+/// * `spend_id`: "SC:1"
+/// * `receiver_id`: "account.near"
+/// * `amount`: "1000000000000000000000000", yoctoNEAR
+/// * `status`: "paid" or "failed"
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(crate = "near_sdk::serde")]
+pub struct SpendPayLog {
+    pub spend_id: String,
+    pub receiver_id: String,
+    pub amount: String,
+    pub status: String,
+}
+
+/// An event log to capture the result of an executed proposal action
+///
+/// Arguments
+/// * `proposal_id`: "XC:1"
+/// * `receiver_id`: "account.near"
+/// * `method_name`: "ping"
+/// * `status`: "succeeded" or "failed"
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(crate = "near_sdk::serde")]
+pub struct ProposalExecuteLog {
+    pub proposal_id: String,
+    pub receiver_id: String,
+    pub method_name: String,
+    pub status: String,
+}
+
+/// An event log to capture a motion being issued
+///
+/// Arguments
+/// * `motion_id`: "LG:1"
+/// * `issuer_id`: "account.near"
+/// * `title`: "Lock Guild"
+/// * `starts_at`: "1650000000000000000", nanoseconds
+/// * `expires_at`: "1650086400000000000", nanoseconds
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(crate = "near_sdk::serde")]
+pub struct MotionIssueLog {
+    pub motion_id: String,
+    pub issuer_id: String,
+    pub title: String,
+    pub starts_at: String,
+    pub expires_at: String,
+}
+
+/// An event log to capture a vote on a motion, or its retraction
+///
+/// Arguments
+/// * `motion_id`: "LG:1"
+/// * `voter_id`: "account.near"
+/// * `vote_id`: "V:A"
+/// * `role`: "ceo", "board" or "member"
+/// * `weight`: "100", the stake of the voter
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(crate = "near_sdk::serde")]
+pub struct MotionVoteLog {
+    pub motion_id: String,
+    pub voter_id: String,
+    pub vote_id: String,
+    pub role: String,
+    pub weight: String,
+}
+
+/// An event log to capture a motion being executed
+///
+/// Arguments
+/// * `motion_id`: "LG:1"
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(crate = "near_sdk::serde")]
+pub struct MotionExecuteLog {
+    pub motion_id: String,
+}
+
+/// An event log to capture a closed motion being finalized
+///
+/// Arguments
+/// * `motion_id`: "LG:1"
+/// * `status`: "executed" or "expired"
+/// * `voters`: "3", the number of cleared voices
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(crate = "near_sdk::serde")]
+pub struct MotionFinalizeLog {
+    pub motion_id: String,
+    pub status: String,
+    pub voters: String,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -90,4 +295,34 @@ mod tests {
         };
         assert_eq!(expected, log.to_string());
     }
+
+    #[test]
+    fn arc_format_guild_lock() {
+        let expected = r#"EVENT_ARC:{"module":"guild","version":"1.0.0","event":"guild_lock","data":[{"guild_id":"G:01","lock":"Emergency"}]}"#;
+        let log = ArcEventLog {
+            module: EVENT_ARC_STANDARD_GUILD.to_string(),
+            version: EVENT_ARC_METADATA_SPEC.to_string(),
+            event: ArcEventVariant::GuildLock(vec![GuildLockLog {
+                guild_id: Some("G:01".to_string()),
+                lock: "Emergency".to_string(),
+            }]),
+        };
+        assert_eq!(expected, log.to_string());
+    }
+    #[test]
+    fn arc_format_motion_vote() {
+        let expected = r#"EVENT_ARC:{"module":"guild","version":"1.0.0","event":"motion_vote","data":[{"motion_id":"LG:1","voter_id":"vecno.near","vote_id":"V:A","role":"ceo","weight":"100"}]}"#;
+        let log = ArcEventLog {
+            module: EVENT_ARC_STANDARD_GUILD.to_string(),
+            version: EVENT_ARC_METADATA_SPEC.to_string(),
+            event: ArcEventVariant::MotionVote(vec![MotionVoteLog {
+                motion_id: "LG:1".to_string(),
+                voter_id: "vecno.near".to_string(),
+                vote_id: "V:A".to_string(),
+                role: "ceo".to_string(),
+                weight: "100".to_string(),
+            }]),
+        };
+        assert_eq!(expected, log.to_string());
+    }
 }
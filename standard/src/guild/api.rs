@@ -1,21 +1,61 @@
 use super::*;
 
+pub const MAX_GAS_FOR_PROPOSAL: Gas = Gas(250_000_000_000_000);
+pub const GAS_FOR_RESOLVE_PROPOSAL: Gas = Gas(10_000_000_000_000);
+pub const GAS_FOR_RESOLVE_CLAIM: Gas = Gas(5_000_000_000_000);
+pub const GAS_FOR_RESOLVE_SPEND: Gas = Gas(5_000_000_000_000);
+
 // ==== Lock Contract ====
 
 pub trait ContractLocking {
-    fn act_lock_contract(&mut self, details: Option<String>);
+    fn act_lock_contract(&mut self, details: Option<String>, sealed: bool);
     fn vote_lock_contract(&mut self);
     fn act_unlock_contract(&mut self, details: String);
     fn vote_unlock_contract(&mut self);
+    fn abort_lock_contract(&mut self);
 }
 
 pub trait ContractChallenging {
-    fn act_challenge_contract_exec(&mut self, details: String);
+    fn act_challenge_contract_exec(&mut self, details: String, sealed: bool);
     fn vote_challenge_contract_exec(&mut self);
-    fn act_replace_contract_exec(&mut self, details: String);
+    fn abort_challenge_contract_exec(&mut self);
+    fn act_replace_contract_exec(&mut self, ceo_id: AccountId, details: String, sealed: bool);
     fn vote_replace_contract_exec(&mut self);
 }
 
+pub trait ContractSealing {
+    fn commit_contract_motion(&mut self, hash: String);
+    fn reveal_contract_motion(&mut self, salt: String);
+}
+
+pub trait ContractPolicy {
+    fn act_policy_contract(&mut self, kind: MotionKind, policy: VotePolicy, details: String);
+    fn vote_policy_contract(&mut self);
+    fn act_change_contract(&mut self, change: ContractChange, details: String);
+    fn vote_change_contract(&mut self);
+}
+
+pub trait ContractProposal {
+    fn act_proposal_contract(&mut self, action: ProposalAction, details: String);
+    fn vote_proposal_contract(&mut self, id: MotionId);
+    fn exec_proposal_contract(&mut self, id: MotionId) -> Promise;
+    fn resolve_proposal_contract(&mut self, id: MotionId) -> bool;
+}
+
+pub trait ContractTreasury {
+    fn act_spend_contract(&mut self, receiver_id: AccountId, amount: U128, details: String);
+    fn vote_spend_contract(&mut self, id: MotionId);
+    fn resolve_spend_contract(&mut self, id: MotionId) -> bool;
+    fn release_spend_contract(&mut self, id: MotionId);
+}
+
+pub trait ContractQueue {
+    fn exec_queued_contract(&mut self, id: MotionId);
+    fn cancel_queued_contract(&mut self, id: MotionId);
+    fn act_cancel_queued_contract(&mut self, id: MotionId, details: String);
+    fn vote_cancel_queued_contract(&mut self, id: MotionId);
+}
+
 pub trait ContractBoard {
     fn act_lock_contract_ceo(&mut self, details: String);
     fn vote_lock_contract_ceo(&mut self);
@@ -24,13 +64,199 @@ pub trait ContractBoard {
 // ==== Lock Guild ====
 
 pub trait LockGuild {
-    fn act_lock_guild(&mut self, guild_id: &GuildId, details: String);
-    fn vote_lock_guild(&mut self, guild_id: &GuildId, details: Option<String>);
-    fn vote_unlock_guild(&mut self, guild_id: &GuildId);
+    fn act_lock_guild(&mut self, guild_id: GuildId, details: Option<String>);
+    fn vote_lock_guild(&mut self, guild_id: GuildId);
+    fn act_unlock_guild(&mut self, guild_id: GuildId, details: String);
+    fn vote_unlock_guild(&mut self, guild_id: GuildId);
+    fn abort_lock_guild(&mut self, guild_id: GuildId);
+}
+
+// ==== Guild Members ====
+
+pub trait GuildMembership {
+    fn act_add_guild_member(
+        &mut self,
+        guild_id: GuildId,
+        account_id: AccountId,
+        value: U128,
+        details: String,
+    );
+    fn act_remove_guild_member(
+        &mut self,
+        guild_id: GuildId,
+        account_id: AccountId,
+        details: String,
+    );
+    fn act_stake_guild_member(
+        &mut self,
+        guild_id: GuildId,
+        account_id: AccountId,
+        value: U128,
+        details: String,
+    );
+    fn act_share_board_member(
+        &mut self,
+        guild_id: GuildId,
+        account_id: AccountId,
+        share: u16,
+        details: String,
+    );
+    fn act_remove_board_member(
+        &mut self,
+        guild_id: GuildId,
+        account_id: AccountId,
+        details: String,
+    );
+    fn act_update_guild_metadata(
+        &mut self,
+        guild_id: GuildId,
+        metadata: GuildMetadata,
+        details: String,
+    );
+    fn vote_guild_motion(&mut self, guild_id: GuildId);
+}
+
+// ==== Guild Board ====
+
+pub trait GuildElection {
+    fn act_board_election(
+        &mut self,
+        guild_id: GuildId,
+        seats: u64,
+        share: u16,
+        candidates: Vec<AccountId>,
+        details: String,
+    );
+    fn vote_board_election(&mut self, guild_id: GuildId, candidate: AccountId);
+    fn close_board_election(&mut self, guild_id: GuildId);
+}
+
+// ==== Guild Choice ====
+
+pub trait GuildChoice {
+    fn act_choice_motion(
+        &mut self,
+        guild_id: GuildId,
+        title: String,
+        options: Vec<VoteInfo>,
+        method: ChoiceMethod,
+        sealed: bool,
+        details: String,
+    );
+    fn vote_choice_motion(&mut self, motion_id: MotionId, ranking: Vec<VoteId>);
+    fn commit_choice_motion(&mut self, motion_id: MotionId, hash: String);
+    fn reveal_choice_motion(&mut self, motion_id: MotionId, ranking: Vec<VoteId>, salt: String);
+}
+
+// ==== Vote Delegation ====
+
+pub trait GuildDelegation {
+    fn delegate_vote(&mut self, guild_id: Option<GuildId>, delegate_id: AccountId);
+    fn revoke_delegation(&mut self, guild_id: Option<GuildId>);
+}
+
+// ==== Motion Ballot ====
+
+pub trait MotionBallot {
+    fn unvote_motion(&mut self, motion_id: MotionId);
+    fn revote_motion(&mut self, motion_id: MotionId, vote_id: VoteId);
+}
+
+// ==== Motion Finalize ====
+
+pub trait MotionFinalize {
+    fn finalize_motion(&mut self, motion_id: MotionId) -> MotionResult;
+    fn set_refund_policy_contract(&mut self, refund: bool);
+}
+
+// ==== Guild Income ====
+
+pub trait GuildIncome {
+    fn deposit_guild_income(&mut self, guild_id: GuildId);
+    fn claim_guild_income(&mut self, guild_id: GuildId) -> Promise;
+    fn resolve_claim_guild_income(
+        &mut self,
+        guild_id: GuildId,
+        account_id: AccountId,
+        amount: U128,
+    ) -> bool;
+}
+
+// ==== Guild Registry ====
+
+pub trait GuildRegistry {
+    fn arc_create_guild(
+        &mut self,
+        guild_id: GuildId,
+        guild: GuildInfo,
+        board_map: HashMap<AccountId, u16>,
+        member_map: HashMap<AccountId, U128>,
+        motion_id: Option<MotionId>,
+    );
+    fn act_create_guild_contract(
+        &mut self,
+        guild_id: GuildId,
+        creator_id: AccountId,
+        details: String,
+    );
+    fn vote_create_guild_contract(&mut self, id: MotionId);
+}
+
+// ==== Guild Views ====
+
+pub trait GuildViews {
+    fn guild_contract_state(&self) -> State;
+    fn guild_metadata(&self, guild_id: GuildId) -> Option<GuildMetadata>;
+    fn guild_info(&self, guild_id: GuildId) -> Option<GuildInfo>;
+    fn guild_list(&self, from_index: Option<U128>, limit: Option<u64>)
+        -> Vec<(GuildId, GuildInfo)>;
+    fn guild_members(
+        &self,
+        guild_id: GuildId,
+        from_index: Option<U128>,
+        limit: Option<u64>,
+    ) -> Vec<(AccountId, U128)>;
+    fn guild_board(
+        &self,
+        guild_id: GuildId,
+        from_index: Option<U128>,
+        limit: Option<u64>,
+    ) -> Vec<(AccountId, u16)>;
+    fn guild_member_set(
+        &self,
+        account_id: AccountId,
+        from_index: Option<U128>,
+        limit: Option<u64>,
+    ) -> Option<JsonMemberSet>;
+    fn guild_latest_motion(&self, guild_id: GuildId) -> Option<MotionId>;
+    fn guild_active_motions(&self, guild_id: GuildId) -> Vec<MotionId>;
+    fn guild_motion(&self, motion_id: MotionId) -> Option<JsonMotion>;
+    fn guild_motions(
+        &self,
+        status: Option<MotionStatus>,
+        from_index: Option<U128>,
+        limit: Option<u64>,
+    ) -> Vec<JsonMotion>;
+    fn guild_motion_vote(&self, motion_id: MotionId, account_id: AccountId) -> Option<VoteId>;
+    fn guild_motion_result(&self, motion_id: MotionId) -> Option<MotionResult>;
+    fn guild_queued_motions(&self) -> Vec<(MotionId, QueuedMotion)>;
+    fn guild_treasury_locked(&self) -> U128;
+    fn guild_income_balance(&self, guild_id: GuildId, account_id: AccountId) -> U128;
+    fn guild_delegation(&self, account_id: AccountId) -> Option<Delegation>;
+    fn guild_delegators(&self, account_id: AccountId) -> Vec<AccountId>;
+    fn guild_treasury_spend(&self, motion_id: MotionId) -> Option<Spend>;
+    fn guild_treasury_spends(
+        &self,
+        from_index: Option<U128>,
+        limit: Option<u64>,
+    ) -> Vec<(MotionId, Spend)>;
 }
 
 // ==== Standard Implementation ====
 
+/// The stake an account had before each checkpoint it changed after, oldest first.
+pub type StakeHistory = Vec<(u64, Option<u128>)>;
+
 #[derive(BorshDeserialize, BorshSerialize)]
 pub struct Guilds {
     pub state: LazyOption<State>,
@@ -38,6 +264,33 @@ pub struct Guilds {
     pub board_map: LookupMap<GuildId, BoardMembers>,
     pub member_map: LookupMap<GuildId, GuildMembers>,
     pub account_map: UnorderedMap<AccountId, MemberSet>,
+    pub proposal_map: LookupMap<MotionId, Proposal>,
+    pub spend_map: UnorderedMap<MotionId, Spend>,
+    pub locked_amount: Balance,
+    /// Accrued guild income for each guild and account, claimable by the account.
+    pub income_map: LookupMap<(GuildId, AccountId), Balance>,
+    pub income_amount: Balance,
+    pub create_map: LookupMap<MotionId, GuildCreate>,
+    /// The delegates for each delegating account.
+    pub delegate_map: LookupMap<AccountId, Delegation>,
+    /// The delegating accounts for each delegate.
+    pub delegator_map: LookupMap<AccountId, Vec<AccountId>>,
+    /// The guild of each open multi-option motion.
+    pub choice_map: LookupMap<MotionId, GuildId>,
+    /// Passed contract motions waiting for their delay.
+    pub queue_map: UnorderedMap<MotionId, QueuedMotion>,
+    /// Motion bonds held until the motions are finalized.
+    pub bond_amount: Balance,
+    /// Motion storage deposits held until the motions are finalized.
+    pub storage_amount: Balance,
+    /// The human readable identity of each guild.
+    pub metadata_map: LookupMap<GuildId, GuildMetadata>,
+    /// The checkpoint of the next motion snapshot.
+    pub epoch: u64,
+    /// The checkpoints of the motion snapshots that are not finalized, oldest first.
+    pub checkpoints: Vec<u64>,
+    /// The guild stake of each account before its first change after a checkpoint.
+    pub stake_history: LookupMap<(GuildId, AccountId), StakeHistory>,
 }
 
 impl Guilds {
@@ -50,12 +303,284 @@ impl Guilds {
                     lock: LockedFor::None,
                     vote: None,
                     time: None,
+                    next: None,
+                    policy: None,
+                    create: CreatePolicy::Motion,
+                    refund: false,
+                    guardian: None,
+                    change: None,
                 }),
             ),
             guild_map: UnorderedMap::new(StorageKey::GuildInfoMap.try_to_vec().unwrap()),
             board_map: LookupMap::new(StorageKey::GuildBoardMap.try_to_vec().unwrap()),
             member_map: LookupMap::new(StorageKey::GuildMembersMap.try_to_vec().unwrap()),
             account_map: UnorderedMap::new(StorageKey::GuildAccountMap.try_to_vec().unwrap()),
+            proposal_map: LookupMap::new(StorageKey::GuildProposalMap.try_to_vec().unwrap()),
+            spend_map: UnorderedMap::new(StorageKey::GuildSpendMap.try_to_vec().unwrap()),
+            locked_amount: 0,
+            income_map: LookupMap::new(StorageKey::GuildIncomeMap.try_to_vec().unwrap()),
+            income_amount: 0,
+            create_map: LookupMap::new(StorageKey::GuildCreateMap.try_to_vec().unwrap()),
+            delegate_map: LookupMap::new(StorageKey::GuildDelegateMap.try_to_vec().unwrap()),
+            delegator_map: LookupMap::new(StorageKey::GuildDelegatorMap.try_to_vec().unwrap()),
+            choice_map: LookupMap::new(StorageKey::GuildChoiceMap.try_to_vec().unwrap()),
+            queue_map: UnorderedMap::new(StorageKey::GuildQueueMap.try_to_vec().unwrap()),
+            bond_amount: 0,
+            storage_amount: 0,
+            metadata_map: LookupMap::new(StorageKey::GuildMetadataMap.try_to_vec().unwrap()),
+            epoch: 0,
+            checkpoints: vec![],
+            stake_history: LookupMap::new(StorageKey::GuildStakeHistory.try_to_vec().unwrap()),
+        }
+    }
+
+    /// Requires the guild to not be locked, guards membership and payout changes.
+    pub fn assert_unlocked(&self, id: &GuildId) {
+        let guild = self.guild_map.get(id).expect("missing guild");
+        require!(guild.lock != LockedFor::Emergency, "The guild is locked");
+    }
+
+    /// Returns the balance that is not used for storage, reserved by spends,
+    /// owed as income or held as motion bonds and storage deposits.
+    pub fn get_available_amount(&self) -> Balance {
+        let storage = env::storage_byte_cost() * (env::storage_usage() as Balance);
+        env::account_balance()
+            .saturating_sub(storage)
+            .saturating_sub(self.locked_amount)
+            .saturating_sub(self.income_amount)
+            .saturating_sub(self.bond_amount)
+            .saturating_sub(self.storage_amount)
+    }
+
+    /// Splits the amount by the guild shares and credits the accounts, returns the credited total.
+    ///
+    /// Note: Rounding dust and unassigned shares are not credited and stay in the treasury.
+    pub fn distribute(&mut self, id: &GuildId, amount: Balance) -> Balance {
+        // Note: A locked guild does not pay out
+        self.assert_unlocked(id);
+        let guild = self.guild_map.get(id).expect("missing guild");
+        let board = self.board_map.get(id).expect("missing guild board");
+        let members = self.member_map.get(id).expect("missing guild members");
+        let total = MAX_BASIS_POINTS as u128;
+        let mut credited = 0;
+
+        // CEO -> ceo share
+        let value = get_share_amount(amount, guild.info.ceo_share as u128, total);
+        credited += self.credit(id, &guild.info.ceo_id, value);
+
+        // Board -> board share > pro-rata by seat share
+        let pool = get_share_amount(amount, guild.info.board_share as u128, total);
+        let shares: u128 = board.list.values().map(|share| share as u128).sum();
+        for (account, share) in board.list.iter() {
+            let value = get_share_amount(pool, share as u128, shares);
+            credited += self.credit(id, &account, value);
+        }
+
+        // Members -> members share > pro-rata by stake
+        let pool = get_share_amount(amount, guild.info.members_share as u128, total);
+        for (account, stake) in members.list.iter() {
+            let value = get_share_amount(pool, stake, members.value);
+            credited += self.credit(id, &account, value);
+        }
+
+        credited
+    }
+
+    fn credit(&mut self, id: &GuildId, account: &AccountId, value: Balance) -> Balance {
+        if value == 0 {
+            return 0;
+        }
+        let key = (id.clone(), account.clone());
+        let balance = self.income_map.get(&key).unwrap_or(0);
+        self.income_map.insert(&key, &(balance + value));
+        self.income_amount += value;
+        value
+    }
+
+    /// Returns the voices and stake a guild motion is measured against.
+    pub fn get_count(&self, id: &GuildId) -> MotionCount {
+        let board = self.board_map.get(id).expect("missing guild board");
+        let members = self.member_map.get(id).expect("missing guild members");
+        MotionCount {
+            board: board.list.len(),
+            members: members.list.len(),
+            value: members.value,
+        }
+    }
+
+    /// Returns the stake of an account in the guild.
+    pub fn get_weight(&self, id: &GuildId, account: &AccountId) -> u128 {
+        let members = self.member_map.get(id).expect("missing guild members");
+        members.list.get(account).unwrap_or(0)
+    }
+
+    /// Returns the stake of an account over all of its guilds.
+    pub fn get_contract_weight(&self, account: &AccountId) -> u128 {
+        self.account_map
+            .get(account)
+            .map_or(0, |member_set| member_set.value)
+    }
+
+    // ==== Stake Checkpoints ====
+
+    /// Starts a new checkpoint, returns the previous one for a motion snapshot.
+    pub fn checkpoint(&mut self) -> u64 {
+        self.epoch += 1;
+        self.checkpoints.push(self.epoch - 1);
+        self.epoch - 1
+    }
+
+    /// Releases the checkpoint of a finalized motion snapshot.
+    pub fn release(&mut self, epoch: u64) {
+        self.checkpoints.retain(|checkpoint| *checkpoint != epoch);
+    }
+
+    /// Returns the stake the account had in the guild at the checkpoint of the snapshot,
+    /// none when the account was not a member.
+    pub fn get_stake_at(&self, snapshot: &MotionSnapshot, account: &AccountId) -> Option<u128> {
+        // Note: The first change after the checkpoint kept the stake of the checkpoint
+        let kept = self
+            .stake_history
+            .get(&(snapshot.scope.clone(), account.clone()))
+            .unwrap_or_default()
+            .into_iter()
+            .find(|(epoch, _)| *epoch > snapshot.epoch);
+        if let Some((_, value)) = kept {
+            return value;
+        }
+        self.get_stake(&snapshot.scope, account)
+    }
+
+    pub fn is_member_at(&self, snapshot: &MotionSnapshot, account: &AccountId) -> bool {
+        self.get_stake_at(snapshot, account).is_some()
+    }
+
+    /// Returns the stake of the account at the snapshot, zero when it was not a member.
+    pub fn get_weight_at(&self, snapshot: &MotionSnapshot, account: &AccountId) -> u128 {
+        self.get_stake_at(snapshot, account).unwrap_or(0)
+    }
+
+    fn get_stake(&self, id: &GuildId, account: &AccountId) -> Option<u128> {
+        self.member_map
+            .get(id)
+            .and_then(|members| members.list.get(account))
+    }
+
+    /// Keeps the stake of the account before it changes for the first time
+    /// since the latest checkpoint.
+    ///
+    /// Note: Only the snapshots of open motions read the history, the checkpoints
+    /// up to the oldest of them are pruned
+    fn record_stake(&mut self, id: &GuildId, account: &AccountId) {
+        let oldest = self.checkpoints.first().copied().unwrap_or(self.epoch);
+        let key = (id.clone(), account.clone());
+        let mut history = self.stake_history.get(&key).unwrap_or_default();
+        history.retain(|(epoch, _)| *epoch > oldest);
+        if oldest < self.epoch && history.last().map(|(epoch, _)| *epoch) != Some(self.epoch) {
+            history.push((self.epoch, self.get_stake(id, account)));
+        }
+        match history.is_empty() {
+            true => self.stake_history.remove(&key),
+            false => self.stake_history.insert(&key, &history),
+        };
+    }
+
+    // ==== Vote Delegation ====
+
+    /// Delegates the voice of the account for a guild, or globally without a guild.
+    pub fn delegate(&mut self, account: &AccountId, scope: Option<&GuildId>, delegate: &AccountId) {
+        require!(account != delegate, "Can not delegate to the same account");
+        match scope {
+            Some(id) => {
+                let members = self.member_map.get(id).expect("missing guild members");
+                require!(
+                    members.list.get(account).is_some() && members.list.get(delegate).is_some(),
+                    "Delegation requires both accounts to be guild members"
+                );
+            }
+            None => require!(
+                self.account_map.get(account).is_some() && self.account_map.get(delegate).is_some(),
+                "Delegation requires both accounts to be guild members"
+            ),
+        }
+
+        let mut delegation = self.delegate_map.get(account).unwrap_or_default();
+        let prev = match scope {
+            Some(id) => delegation.guilds.insert(id.clone(), delegate.clone()),
+            None => delegation.global.replace(delegate.clone()),
+        };
+        self.delegate_map.insert(account, &delegation);
+
+        if let Some(prev) = prev {
+            self.unlink_delegate(&prev, account, &delegation);
+        }
+        let mut delegators = self.delegator_map.get(delegate).unwrap_or_default();
+        if !delegators.contains(account) {
+            delegators.push(account.clone());
+            self.delegator_map.insert(delegate, &delegators);
+        }
+    }
+
+    /// Revokes the delegation of the account for a guild, or the global one without a guild.
+    pub fn revoke(&mut self, account: &AccountId, scope: Option<&GuildId>) {
+        let mut delegation = self.delegate_map.get(account).unwrap_or_default();
+        let prev = match scope {
+            Some(id) => delegation.guilds.remove(id),
+            None => delegation.global.take(),
+        }
+        .expect("missing delegation");
+
+        if delegation.is_empty() {
+            self.delegate_map.remove(account);
+        } else {
+            self.delegate_map.insert(account, &delegation);
+        }
+        self.unlink_delegate(&prev, account, &delegation);
+    }
+
+    /// Returns the accounts and their weight that delegated their voice to the delegate.
+    ///
+    /// Note: Delegations are not followed further, a delegate can not pass on received voices.
+    pub fn get_proxies(
+        &self,
+        scope: Option<&GuildId>,
+        delegate: &AccountId,
+    ) -> Vec<(AccountId, u128)> {
+        let members = scope.map(|id| self.member_map.get(id).expect("missing guild members"));
+        self.delegator_map
+            .get(delegate)
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|account| {
+                self.delegate_map
+                    .get(account)
+                    .is_some_and(|delegation| delegation.get_delegate(scope) == Some(delegate))
+            })
+            .filter_map(|account| match &members {
+                Some(members) => members.list.get(&account).map(|weight| (account, weight)),
+                None => self
+                    .account_map
+                    .get(&account)
+                    .map(|member_set| (account, member_set.value)),
+            })
+            .collect()
+    }
+
+    fn unlink_delegate(
+        &mut self,
+        delegate: &AccountId,
+        account: &AccountId,
+        delegation: &Delegation,
+    ) {
+        if delegation.is_delegate(delegate) {
+            return;
+        }
+        let mut delegators = self.delegator_map.get(delegate).unwrap_or_default();
+        delegators.retain(|id| id != account);
+        if delegators.is_empty() {
+            self.delegator_map.remove(delegate);
+        } else {
+            self.delegator_map.insert(delegate, &delegators);
         }
     }
 
@@ -69,7 +594,7 @@ impl Guilds {
         guild.assert_valid();
 
         require!(
-            member_map.len() > 0,
+            !member_map.is_empty(),
             "Need at least one guild members or more"
         );
         require!(
@@ -85,17 +610,20 @@ impl Guilds {
             format!("The CEO can not be a board member")
         );
         require!(
-            !member_map.get(&guild.ceo_id).is_none(),
+            member_map.get(&guild.ceo_id).is_some(),
             format!("The CEO must be a guild member")
         );
 
         let state = GuildState {
             info: guild.clone(),
             lock: LockedFor::None,
+            time: None,
             vote: None,
+            motion: None,
+            election: None,
         };
         require!(
-            self.guild_map.insert(&id, &state).is_none(),
+            self.guild_map.insert(id, &state).is_none(),
             "The provided guild id is already in use"
         );
 
@@ -114,16 +642,7 @@ impl Guilds {
                 guild_members.list.insert(account, value).is_none(),
                 format!("Duplicated member entry found for {}", account)
             );
-            let mut guild_set = self.account_map.get(&account).unwrap_or_else(|| MemberSet {
-                value: 0,
-                store: UnorderedSet::new(
-                    StorageKey::GuildAccountSet {
-                        id: account.clone(),
-                    }
-                    .try_to_vec()
-                    .unwrap(),
-                ),
-            });
+            let mut guild_set = self.get_member_set(account);
             guild_set.value += value;
             guild_set.store.insert(id);
             self.account_map.insert(account, &guild_set);
@@ -146,7 +665,7 @@ impl Guilds {
                 format!("Duplicated board entry found: {}", account)
             );
             require!(
-                !guild_members.list.get(account).is_none(),
+                guild_members.list.get(account).is_some(),
                 format!("Board member need to be a guild member: {}", account)
             );
         }
@@ -155,5 +674,793 @@ impl Guilds {
             MAX_BASIS_POINTS >= total,
             "Total board shares can not be more than 100_00 basis points"
         );
+
+        let mut board_ids: Vec<String> = board_map.keys().map(|id| id.to_string()).collect();
+        let mut member_ids: Vec<String> = member_map.keys().map(|id| id.to_string()).collect();
+        board_ids.sort();
+        member_ids.sort();
+        let guild_register_log: ArcEventLog = ArcEventLog {
+            module: EVENT_ARC_STANDARD_GUILD.to_string(),
+            version: EVENT_ARC_METADATA_SPEC.to_string(),
+            event: ArcEventVariant::GuildRegister(vec![GuildRegisterLog {
+                guild_id: id.to_string(),
+                ceo_id: guild.ceo_id.to_string(),
+                board_ids,
+                member_ids,
+            }]),
+        };
+        env::log_str(&guild_register_log.to_string());
+    }
+
+    /// Stores the contract state, logs a change of the contract lock.
+    pub fn set_state(&mut self, state: &State) {
+        let prev = self.state.replace(state).expect("missing guilds state");
+        log_lock_change(None, &prev.lock, &state.lock);
+    }
+
+    /// Stores the guild state, logs a change of the guild lock.
+    pub fn set_guild(&mut self, id: &GuildId, guild: &GuildState) {
+        let prev = self.guild_map.insert(id, guild).expect("missing guild");
+        log_lock_change(Some(id), &prev.lock, &guild.lock);
+    }
+
+    // ==== Guild Membership ====
+
+    /// Validates a membership change against the current guild roster.
+    pub fn assert_change(&self, id: &GuildId, change: &GuildChange) {
+        self.assert_unlocked(id);
+
+        let guild = self.guild_map.get(id).expect("missing guild");
+        let members = self.member_map.get(id).expect("missing guild members");
+        match change {
+            GuildChange::AddMember { account_id, .. } => {
+                require!(
+                    members.list.get(account_id).is_none(),
+                    format!("The account is already a guild member: {}", account_id)
+                );
+                require!(
+                    members.list.len() < guild.info.members_size,
+                    "The members list can not be larger than the guilds size"
+                );
+            }
+            GuildChange::RemoveMember { account_id } => {
+                require!(
+                    members.list.get(account_id).is_some(),
+                    format!("The account is not a guild member: {}", account_id)
+                );
+                require!(
+                    &guild.info.ceo_id != account_id,
+                    "The CEO must be a guild member"
+                );
+                let board = self.board_map.get(id).expect("missing guild board");
+                require!(
+                    board.list.get(account_id).is_none(),
+                    format!("Board member need to be a guild member: {}", account_id)
+                );
+            }
+            GuildChange::StakeMember { account_id, .. } => {
+                require!(
+                    members.list.get(account_id).is_some(),
+                    format!("The account is not a guild member: {}", account_id)
+                );
+            }
+            GuildChange::ShareBoard { account_id, share } => {
+                let board = self.board_map.get(id).expect("missing guild board");
+                let current = board.list.get(account_id).expect("missing board member");
+                let total: u16 = board.list.values().sum();
+                require!(
+                    MAX_BASIS_POINTS >= total - current + share,
+                    "Total board shares can not be more than 100_00 basis points"
+                );
+            }
+            GuildChange::RemoveBoard { account_id } => {
+                let board = self.board_map.get(id).expect("missing guild board");
+                require!(
+                    board.list.get(account_id).is_some(),
+                    format!("The account is not a board member: {}", account_id)
+                );
+            }
+            GuildChange::UpdateMetadata { metadata } => metadata.assert_valid(),
+        }
+    }
+
+    /// Applies a membership change, keeps guild and account totals in sync.
+    pub fn apply_change(&mut self, id: &GuildId, change: &GuildChange) {
+        self.assert_change(id, change);
+        match change {
+            GuildChange::AddMember { account_id, .. }
+            | GuildChange::RemoveMember { account_id }
+            | GuildChange::StakeMember { account_id, .. } => self.record_stake(id, account_id),
+            _ => {}
+        }
+
+        let mut members = self.member_map.get(id).expect("missing guild members");
+        match change {
+            GuildChange::AddMember { account_id, value } => {
+                members.value += value;
+                members.list.insert(account_id, value);
+                self.member_map.insert(id, &members);
+
+                let mut member_set = self.get_member_set(account_id);
+                member_set.value += value;
+                member_set.store.insert(id);
+                self.account_map.insert(account_id, &member_set);
+                log_member_change(id, change, *value);
+            }
+            GuildChange::RemoveMember { account_id } => {
+                let value = members.list.remove(account_id).expect("missing member");
+                members.value -= value;
+                self.member_map.insert(id, &members);
+
+                let mut member_set = self.get_member_set(account_id);
+                member_set.value -= value;
+                member_set.store.remove(id);
+                if member_set.store.is_empty() {
+                    self.account_map.remove(account_id);
+                } else {
+                    self.account_map.insert(account_id, &member_set);
+                }
+                log_member_change(id, change, value);
+            }
+            GuildChange::StakeMember { account_id, value } => {
+                let current = members
+                    .list
+                    .insert(account_id, value)
+                    .expect("missing member");
+                members.value = members.value - current + value;
+                self.member_map.insert(id, &members);
+
+                let mut member_set = self.get_member_set(account_id);
+                member_set.value = member_set.value - current + value;
+                self.account_map.insert(account_id, &member_set);
+                log_member_change(id, change, *value);
+            }
+            GuildChange::ShareBoard { account_id, share } => {
+                let guild = self.guild_map.get(id).expect("missing guild");
+                let mut board = self.board_map.get(id).expect("missing guild board");
+                board.list.insert(account_id, share);
+                board.assert_valid(guild.info.board_size);
+                self.board_map.insert(id, &board);
+                log_member_change(id, change, *share as u128);
+            }
+            GuildChange::RemoveBoard { account_id } => {
+                // Note: The seat is open for the next board election
+                let mut board = self.board_map.get(id).expect("missing guild board");
+                let share = board.list.remove(account_id).expect("missing board member");
+                self.board_map.insert(id, &board);
+                log_member_change(id, change, share as u128);
+            }
+            GuildChange::UpdateMetadata { metadata } => {
+                self.metadata_map.insert(id, metadata);
+            }
+        }
+    }
+
+    // ==== Board Election ====
+
+    /// Validates the candidates and seats of a board election.
+    pub fn assert_election(&self, id: &GuildId, election: &BoardElection) {
+        self.assert_unlocked(id);
+
+        let guild = self.guild_map.get(id).expect("missing guild");
+        let board = self.board_map.get(id).expect("missing guild board");
+        let members = self.member_map.get(id).expect("missing guild members");
+        require!(election.seats > 0, "Need at least one open board seat");
+        require!(
+            board.list.len() + election.seats <= guild.info.board_size,
+            "The board list can not be larger than the boards size"
+        );
+        require!(
+            election.candidates.len() as u64 >= election.seats,
+            "Need at least one candidate for every open seat"
+        );
+
+        let total: u16 = board.list.values().sum();
+        require!(
+            // Note: This check depends on the Rust compilers setting: `overflow-checks = true`
+            MAX_BASIS_POINTS >= total + election.share * election.seats as u16,
+            "Total board shares can not be more than 100_00 basis points"
+        );
+
+        for (index, candidate) in election.candidates.iter().enumerate() {
+            require!(
+                !election.candidates[..index].contains(candidate),
+                format!("Duplicated candidate entry found: {}", candidate)
+            );
+            require!(
+                &guild.info.ceo_id != candidate,
+                "The CEO can not be a board member"
+            );
+            require!(
+                board.list.get(candidate).is_none(),
+                format!("The account is already a board member: {}", candidate)
+            );
+            require!(
+                members.list.get(candidate).is_some(),
+                format!("Board member need to be a guild member: {}", candidate)
+            );
+        }
     }
+
+    /// Seats the elected candidates on the board with the given share.
+    pub fn seat_board(&mut self, id: &GuildId, winners: &[AccountId], share: u16) {
+        self.assert_unlocked(id);
+
+        let guild = self.guild_map.get(id).expect("missing guild");
+        let members = self.member_map.get(id).expect("missing guild members");
+        let mut board = self.board_map.get(id).expect("missing guild board");
+        for account in winners.iter() {
+            require!(
+                &guild.info.ceo_id != account,
+                "The CEO can not be a board member"
+            );
+            require!(
+                members.list.get(account).is_some(),
+                format!("Board member need to be a guild member: {}", account)
+            );
+            require!(
+                board.list.insert(account, &share).is_none(),
+                format!("Duplicated board entry found: {}", account)
+            );
+        }
+        board.assert_valid(guild.info.board_size);
+        self.board_map.insert(id, &board);
+
+        log_guild_event(ArcEventVariant::GuildBoardSeat(
+            winners
+                .iter()
+                .map(|account| GuildBoardLog {
+                    guild_id: id.to_string(),
+                    account_id: account.to_string(),
+                    share: share.to_string(),
+                })
+                .collect(),
+        ));
+    }
+
+    fn get_member_set(&self, account: &AccountId) -> MemberSet {
+        self.account_map.get(account).unwrap_or_else(|| MemberSet {
+            value: 0,
+            store: UnorderedSet::new(
+                StorageKey::GuildAccountSet {
+                    id: account.clone(),
+                }
+                .try_to_vec()
+                .unwrap(),
+            ),
+        })
+    }
+}
+
+fn log_lock_change(id: Option<&GuildId>, prev: &LockedFor, next: &LockedFor) {
+    if prev == next {
+        return;
+    }
+    let guild_id = id.map(|id| id.to_string());
+    let event = if *next == LockedFor::None {
+        ArcEventVariant::GuildUnlock(vec![GuildLockLog {
+            guild_id,
+            lock: format!("{:?}", prev),
+        }])
+    } else {
+        ArcEventVariant::GuildLock(vec![GuildLockLog {
+            guild_id,
+            lock: format!("{:?}", next),
+        }])
+    };
+    log_guild_event(event);
+}
+
+/// Logs an event of the guild module.
+pub(crate) fn log_guild_event(event: ArcEventVariant) {
+    let guild_log: ArcEventLog = ArcEventLog {
+        module: EVENT_ARC_STANDARD_GUILD.to_string(),
+        version: EVENT_ARC_METADATA_SPEC.to_string(),
+        event,
+    };
+    env::log_str(&guild_log.to_string());
+}
+
+fn log_member_change(id: &GuildId, change: &GuildChange, value: u128) {
+    let member = |account_id: &AccountId| {
+        vec![GuildMemberLog {
+            guild_id: id.to_string(),
+            account_id: account_id.to_string(),
+            stake: value.to_string(),
+        }]
+    };
+    let board = |account_id: &AccountId| {
+        vec![GuildBoardLog {
+            guild_id: id.to_string(),
+            account_id: account_id.to_string(),
+            share: value.to_string(),
+        }]
+    };
+    log_guild_event(match change {
+        GuildChange::AddMember { account_id, .. } => {
+            ArcEventVariant::GuildMemberAdd(member(account_id))
+        }
+        GuildChange::RemoveMember { account_id } => {
+            ArcEventVariant::GuildMemberRemove(member(account_id))
+        }
+        GuildChange::StakeMember { account_id, .. } => {
+            ArcEventVariant::GuildMemberStake(member(account_id))
+        }
+        GuildChange::ShareBoard { account_id, .. } => {
+            ArcEventVariant::GuildBoardSeat(board(account_id))
+        }
+        GuildChange::RemoveBoard { account_id } => {
+            ArcEventVariant::GuildBoardRemove(board(account_id))
+        }
+        GuildChange::UpdateMetadata { .. } => return,
+    });
+}
+
+// ==== Contract Implementation ====
+
+#[macro_export]
+macro_rules! impl_guild_contract {
+    ($contract: ident, $guild: ident) => {
+        use $crate::guild::*;
+        use $crate::share::*;
+
+        #[near_bindgen]
+        impl ContractLocking for $contract {
+            #[payable]
+            fn act_lock_contract(&mut self, details: Option<String>, sealed: bool) {
+                self.$guild.act_lock_contract(details, sealed)
+            }
+            fn vote_lock_contract(&mut self) {
+                self.$guild.vote_lock_contract()
+            }
+            #[payable]
+            fn act_unlock_contract(&mut self, details: String) {
+                self.$guild.act_unlock_contract(details)
+            }
+            fn vote_unlock_contract(&mut self) {
+                self.$guild.vote_unlock_contract()
+            }
+            fn abort_lock_contract(&mut self) {
+                self.$guild.abort_lock_contract()
+            }
+        }
+
+        #[near_bindgen]
+        impl ContractChallenging for $contract {
+            #[payable]
+            fn act_challenge_contract_exec(&mut self, details: String, sealed: bool) {
+                self.$guild.act_challenge_contract_exec(details, sealed)
+            }
+            fn vote_challenge_contract_exec(&mut self) {
+                self.$guild.vote_challenge_contract_exec()
+            }
+            fn abort_challenge_contract_exec(&mut self) {
+                self.$guild.abort_challenge_contract_exec()
+            }
+            #[payable]
+            fn act_replace_contract_exec(
+                &mut self,
+                ceo_id: AccountId,
+                details: String,
+                sealed: bool,
+            ) {
+                self.$guild
+                    .act_replace_contract_exec(ceo_id, details, sealed)
+            }
+            fn vote_replace_contract_exec(&mut self) {
+                self.$guild.vote_replace_contract_exec()
+            }
+        }
+
+        #[near_bindgen]
+        impl ContractSealing for $contract {
+            fn commit_contract_motion(&mut self, hash: String) {
+                self.$guild.commit_contract_motion(hash)
+            }
+            fn reveal_contract_motion(&mut self, salt: String) {
+                self.$guild.reveal_contract_motion(salt)
+            }
+        }
+
+        #[near_bindgen]
+        impl ContractPolicy for $contract {
+            #[payable]
+            fn act_policy_contract(
+                &mut self,
+                kind: MotionKind,
+                policy: VotePolicy,
+                details: String,
+            ) {
+                self.$guild.act_policy_contract(kind, policy, details)
+            }
+            fn vote_policy_contract(&mut self) {
+                self.$guild.vote_policy_contract()
+            }
+            #[payable]
+            fn act_change_contract(&mut self, change: ContractChange, details: String) {
+                self.$guild.act_change_contract(change, details)
+            }
+            fn vote_change_contract(&mut self) {
+                self.$guild.vote_change_contract()
+            }
+        }
+
+        #[near_bindgen]
+        impl ContractProposal for $contract {
+            #[payable]
+            fn act_proposal_contract(&mut self, action: ProposalAction, details: String) {
+                self.$guild.act_proposal_contract(action, details)
+            }
+            fn vote_proposal_contract(&mut self, id: MotionId) {
+                self.$guild.vote_proposal_contract(id)
+            }
+            fn exec_proposal_contract(&mut self, id: MotionId) -> near_sdk::Promise {
+                self.$guild.exec_proposal_contract(id)
+            }
+            #[private]
+            fn resolve_proposal_contract(&mut self, id: MotionId) -> bool {
+                self.$guild.resolve_proposal_contract(id)
+            }
+        }
+
+        #[near_bindgen]
+        impl ContractTreasury for $contract {
+            #[payable]
+            fn act_spend_contract(
+                &mut self,
+                receiver_id: AccountId,
+                amount: U128,
+                details: String,
+            ) {
+                self.$guild.act_spend_contract(receiver_id, amount, details)
+            }
+            fn vote_spend_contract(&mut self, id: MotionId) {
+                self.$guild.vote_spend_contract(id)
+            }
+            #[private]
+            fn resolve_spend_contract(&mut self, id: MotionId) -> bool {
+                self.$guild.resolve_spend_contract(id)
+            }
+            fn release_spend_contract(&mut self, id: MotionId) {
+                self.$guild.release_spend_contract(id)
+            }
+        }
+
+        #[near_bindgen]
+        impl ContractQueue for $contract {
+            fn exec_queued_contract(&mut self, id: MotionId) {
+                self.$guild.exec_queued_contract(id)
+            }
+            fn cancel_queued_contract(&mut self, id: MotionId) {
+                self.$guild.cancel_queued_contract(id)
+            }
+            #[payable]
+            fn act_cancel_queued_contract(&mut self, id: MotionId, details: String) {
+                self.$guild.act_cancel_queued_contract(id, details)
+            }
+            fn vote_cancel_queued_contract(&mut self, id: MotionId) {
+                self.$guild.vote_cancel_queued_contract(id)
+            }
+        }
+
+        #[near_bindgen]
+        impl LockGuild for $contract {
+            #[payable]
+            fn act_lock_guild(&mut self, guild_id: GuildId, details: Option<String>) {
+                self.$guild.act_lock_guild(guild_id, details)
+            }
+            fn vote_lock_guild(&mut self, guild_id: GuildId) {
+                self.$guild.vote_lock_guild(guild_id)
+            }
+            #[payable]
+            fn act_unlock_guild(&mut self, guild_id: GuildId, details: String) {
+                self.$guild.act_unlock_guild(guild_id, details)
+            }
+            fn vote_unlock_guild(&mut self, guild_id: GuildId) {
+                self.$guild.vote_unlock_guild(guild_id)
+            }
+            fn abort_lock_guild(&mut self, guild_id: GuildId) {
+                self.$guild.abort_lock_guild(guild_id)
+            }
+        }
+
+        #[near_bindgen]
+        impl GuildMembership for $contract {
+            #[payable]
+            fn act_add_guild_member(
+                &mut self,
+                guild_id: GuildId,
+                account_id: AccountId,
+                value: U128,
+                details: String,
+            ) {
+                self.$guild
+                    .act_add_guild_member(guild_id, account_id, value, details)
+            }
+            #[payable]
+            fn act_remove_guild_member(
+                &mut self,
+                guild_id: GuildId,
+                account_id: AccountId,
+                details: String,
+            ) {
+                self.$guild
+                    .act_remove_guild_member(guild_id, account_id, details)
+            }
+            #[payable]
+            fn act_stake_guild_member(
+                &mut self,
+                guild_id: GuildId,
+                account_id: AccountId,
+                value: U128,
+                details: String,
+            ) {
+                self.$guild
+                    .act_stake_guild_member(guild_id, account_id, value, details)
+            }
+            #[payable]
+            fn act_share_board_member(
+                &mut self,
+                guild_id: GuildId,
+                account_id: AccountId,
+                share: u16,
+                details: String,
+            ) {
+                self.$guild
+                    .act_share_board_member(guild_id, account_id, share, details)
+            }
+            #[payable]
+            fn act_remove_board_member(
+                &mut self,
+                guild_id: GuildId,
+                account_id: AccountId,
+                details: String,
+            ) {
+                self.$guild
+                    .act_remove_board_member(guild_id, account_id, details)
+            }
+            #[payable]
+            fn act_update_guild_metadata(
+                &mut self,
+                guild_id: GuildId,
+                metadata: GuildMetadata,
+                details: String,
+            ) {
+                self.$guild
+                    .act_update_guild_metadata(guild_id, metadata, details)
+            }
+            fn vote_guild_motion(&mut self, guild_id: GuildId) {
+                self.$guild.vote_guild_motion(guild_id)
+            }
+        }
+
+        #[near_bindgen]
+        impl GuildElection for $contract {
+            #[payable]
+            fn act_board_election(
+                &mut self,
+                guild_id: GuildId,
+                seats: u64,
+                share: u16,
+                candidates: Vec<AccountId>,
+                details: String,
+            ) {
+                self.$guild
+                    .act_board_election(guild_id, seats, share, candidates, details)
+            }
+            fn vote_board_election(&mut self, guild_id: GuildId, candidate: AccountId) {
+                self.$guild.vote_board_election(guild_id, candidate)
+            }
+            fn close_board_election(&mut self, guild_id: GuildId) {
+                self.$guild.close_board_election(guild_id)
+            }
+        }
+
+        #[near_bindgen]
+        impl GuildChoice for $contract {
+            #[payable]
+            fn act_choice_motion(
+                &mut self,
+                guild_id: GuildId,
+                title: String,
+                options: Vec<VoteInfo>,
+                method: ChoiceMethod,
+                sealed: bool,
+                details: String,
+            ) {
+                self.$guild
+                    .act_choice_motion(guild_id, title, options, method, sealed, details)
+            }
+            fn vote_choice_motion(&mut self, motion_id: MotionId, ranking: Vec<VoteId>) {
+                self.$guild.vote_choice_motion(motion_id, ranking)
+            }
+            fn commit_choice_motion(&mut self, motion_id: MotionId, hash: String) {
+                self.$guild.commit_choice_motion(motion_id, hash)
+            }
+            fn reveal_choice_motion(
+                &mut self,
+                motion_id: MotionId,
+                ranking: Vec<VoteId>,
+                salt: String,
+            ) {
+                self.$guild.reveal_choice_motion(motion_id, ranking, salt)
+            }
+        }
+
+        #[near_bindgen]
+        impl GuildDelegation for $contract {
+            fn delegate_vote(&mut self, guild_id: Option<GuildId>, delegate_id: AccountId) {
+                self.$guild.delegate_vote(guild_id, delegate_id)
+            }
+            fn revoke_delegation(&mut self, guild_id: Option<GuildId>) {
+                self.$guild.revoke_delegation(guild_id)
+            }
+        }
+
+        #[near_bindgen]
+        impl MotionBallot for $contract {
+            fn unvote_motion(&mut self, motion_id: MotionId) {
+                self.$guild.unvote_motion(motion_id)
+            }
+            fn revote_motion(&mut self, motion_id: MotionId, vote_id: VoteId) {
+                self.$guild.revote_motion(motion_id, vote_id)
+            }
+        }
+
+        #[near_bindgen]
+        impl MotionFinalize for $contract {
+            fn finalize_motion(&mut self, motion_id: MotionId) -> MotionResult {
+                self.$guild.finalize_motion(motion_id)
+            }
+            fn set_refund_policy_contract(&mut self, refund: bool) {
+                self.$guild.set_refund_policy_contract(refund)
+            }
+        }
+
+        #[near_bindgen]
+        impl GuildIncome for $contract {
+            #[payable]
+            fn deposit_guild_income(&mut self, guild_id: GuildId) {
+                self.$guild.deposit_guild_income(guild_id)
+            }
+            fn claim_guild_income(&mut self, guild_id: GuildId) -> near_sdk::Promise {
+                self.$guild.claim_guild_income(guild_id)
+            }
+            #[private]
+            fn resolve_claim_guild_income(
+                &mut self,
+                guild_id: GuildId,
+                account_id: AccountId,
+                amount: U128,
+            ) -> bool {
+                self.$guild
+                    .resolve_claim_guild_income(guild_id, account_id, amount)
+            }
+        }
+
+        #[near_bindgen]
+        impl GuildRegistry for $contract {
+            #[payable]
+            fn arc_create_guild(
+                &mut self,
+                guild_id: GuildId,
+                guild: GuildInfo,
+                board_map: std::collections::HashMap<AccountId, u16>,
+                member_map: std::collections::HashMap<AccountId, U128>,
+                motion_id: Option<MotionId>,
+            ) {
+                self.$guild
+                    .arc_create_guild(guild_id, guild, board_map, member_map, motion_id)
+            }
+            #[payable]
+            fn act_create_guild_contract(
+                &mut self,
+                guild_id: GuildId,
+                creator_id: AccountId,
+                details: String,
+            ) {
+                self.$guild
+                    .act_create_guild_contract(guild_id, creator_id, details)
+            }
+            fn vote_create_guild_contract(&mut self, id: MotionId) {
+                self.$guild.vote_create_guild_contract(id)
+            }
+        }
+
+        #[near_bindgen]
+        impl GuildViews for $contract {
+            fn guild_contract_state(&self) -> State {
+                self.$guild.guild_contract_state()
+            }
+            fn guild_metadata(&self, guild_id: GuildId) -> Option<GuildMetadata> {
+                self.$guild.guild_metadata(guild_id)
+            }
+            fn guild_info(&self, guild_id: GuildId) -> Option<GuildInfo> {
+                self.$guild.guild_info(guild_id)
+            }
+            fn guild_list(
+                &self,
+                from_index: Option<U128>,
+                limit: Option<u64>,
+            ) -> Vec<(GuildId, GuildInfo)> {
+                self.$guild.guild_list(from_index, limit)
+            }
+            fn guild_members(
+                &self,
+                guild_id: GuildId,
+                from_index: Option<U128>,
+                limit: Option<u64>,
+            ) -> Vec<(AccountId, U128)> {
+                self.$guild.guild_members(guild_id, from_index, limit)
+            }
+            fn guild_board(
+                &self,
+                guild_id: GuildId,
+                from_index: Option<U128>,
+                limit: Option<u64>,
+            ) -> Vec<(AccountId, u16)> {
+                self.$guild.guild_board(guild_id, from_index, limit)
+            }
+            fn guild_member_set(
+                &self,
+                account_id: AccountId,
+                from_index: Option<U128>,
+                limit: Option<u64>,
+            ) -> Option<JsonMemberSet> {
+                self.$guild.guild_member_set(account_id, from_index, limit)
+            }
+            fn guild_latest_motion(&self, guild_id: GuildId) -> Option<MotionId> {
+                self.$guild.guild_latest_motion(guild_id)
+            }
+            fn guild_active_motions(&self, guild_id: GuildId) -> Vec<MotionId> {
+                self.$guild.guild_active_motions(guild_id)
+            }
+            fn guild_motion(&self, motion_id: MotionId) -> Option<JsonMotion> {
+                self.$guild.guild_motion(motion_id)
+            }
+            fn guild_motions(
+                &self,
+                status: Option<MotionStatus>,
+                from_index: Option<U128>,
+                limit: Option<u64>,
+            ) -> Vec<JsonMotion> {
+                self.$guild.guild_motions(status, from_index, limit)
+            }
+            fn guild_motion_vote(
+                &self,
+                motion_id: MotionId,
+                account_id: AccountId,
+            ) -> Option<VoteId> {
+                self.$guild.guild_motion_vote(motion_id, account_id)
+            }
+            fn guild_motion_result(&self, motion_id: MotionId) -> Option<MotionResult> {
+                self.$guild.guild_motion_result(motion_id)
+            }
+            fn guild_queued_motions(&self) -> Vec<(MotionId, QueuedMotion)> {
+                self.$guild.guild_queued_motions()
+            }
+            fn guild_treasury_locked(&self) -> U128 {
+                self.$guild.guild_treasury_locked()
+            }
+            fn guild_income_balance(&self, guild_id: GuildId, account_id: AccountId) -> U128 {
+                self.$guild.guild_income_balance(guild_id, account_id)
+            }
+            fn guild_delegation(&self, account_id: AccountId) -> Option<Delegation> {
+                self.$guild.guild_delegation(account_id)
+            }
+            fn guild_delegators(&self, account_id: AccountId) -> Vec<AccountId> {
+                self.$guild.guild_delegators(account_id)
+            }
+            fn guild_treasury_spend(&self, motion_id: MotionId) -> Option<Spend> {
+                self.$guild.guild_treasury_spend(motion_id)
+            }
+            fn guild_treasury_spends(
+                &self,
+                from_index: Option<U128>,
+                limit: Option<u64>,
+            ) -> Vec<(MotionId, Spend)> {
+                self.$guild.guild_treasury_spends(from_index, limit)
+            }
+        }
+    };
 }
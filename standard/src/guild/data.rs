@@ -45,21 +45,158 @@ impl GuildInfo {
     }
 }
 
+// ==== Guild Metadata ====
+
+/// The human readable identity of a guild.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize, BorshSerialize)]
+#[serde(crate = "near_sdk::serde")]
+pub struct GuildMetadata {
+    pub name: String,
+    pub description: Option<String>,
+    /// Small image, usually a data URL, shown next to the name.
+    pub icon: Option<String>,
+    pub media: Option<String>,
+    pub media_hash: Option<String>,
+    pub reference: Option<String>,
+    pub reference_hash: Option<String>,
+}
+
+impl GuildMetadata {
+    pub fn assert_valid(&self) {
+        require!(!self.name.is_empty(), "Guild metadata requires a name");
+        require!(
+            self.name.len() <= 28,
+            "Maximum name length is 28 characters"
+        );
+        if let Some(description) = &self.description {
+            require!(
+                description.len() <= 256,
+                "Maximum description length is 256 characters"
+            );
+        }
+
+        require!(
+            self.media.is_some() == self.media_hash.is_some(),
+            "Media hash is required to verify media integrity"
+        );
+        if let Some(media_hash) = &self.media_hash {
+            require!(
+                media_hash.len() == 64,
+                "Media hash has to be hex encoded string (64 bytes)"
+            );
+        }
+        require!(
+            self.reference.is_some() == self.reference_hash.is_some(),
+            "Reference hash is required to verify reference integrity"
+        );
+        if let Some(reference_hash) = &self.reference_hash {
+            require!(
+                reference_hash.len() == 64,
+                "Reference hash has to be hex encoded string (64 bytes)"
+            );
+        }
+    }
+}
+
 // ==== Guild State ====
 
-#[derive(BorshDeserialize, BorshSerialize)]
+#[derive(Serialize, Deserialize, BorshDeserialize, BorshSerialize)]
+#[serde(crate = "near_sdk::serde")]
 pub struct State {
     pub exec: GuildId,
     pub lock: LockedFor,
-    pub time: Option<u64>,
+    pub time: Option<Timestamp>,
     pub vote: Option<MotionId>,
+    /// Candidate to replace the executive CEO while challenging.
+    pub next: Option<AccountId>,
+    /// Active motion to change a vote policy.
+    pub policy: Option<PolicyMotion>,
+    /// Who may create new guilds with a deposit.
+    pub create: CreatePolicy,
+    /// Let issuers prepay the storage of a motion, refunded as finalizing releases it.
+    pub refund: bool,
+    /// Account that can cancel queued motions during their delay.
+    pub guardian: Option<AccountId>,
+    /// Active motion to change a contract setting.
+    pub change: Option<ContractMotion>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize, BorshSerialize)]
+#[serde(crate = "near_sdk::serde")]
+pub enum CreatePolicy {
+    /// Any account can create a guild.
+    Open,
+    /// The creation has to be approved by an executive guild motion.
+    Motion,
+}
+
+#[derive(Clone, Serialize, Deserialize, BorshDeserialize, BorshSerialize)]
+#[serde(crate = "near_sdk::serde")]
+pub struct PolicyMotion {
+    pub id: MotionId,
+    pub kind: MotionKind,
+    pub policy: VotePolicy,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize, BorshSerialize)]
+#[serde(crate = "near_sdk::serde")]
+pub enum ContractChange {
+    /// Sets who may cancel queued motions during their delay.
+    Guardian { guardian: Option<AccountId> },
+    /// Sets who may create guilds.
+    Create { policy: CreatePolicy },
+}
+
+#[derive(Clone, Serialize, Deserialize, BorshDeserialize, BorshSerialize)]
+#[serde(crate = "near_sdk::serde")]
+pub struct ContractMotion {
+    pub id: MotionId,
+    pub change: ContractChange,
 }
 
 #[derive(BorshDeserialize, BorshSerialize)]
 pub struct GuildState {
     pub info: GuildInfo,
     pub lock: LockedFor,
+    pub time: Option<Timestamp>,
     pub vote: Option<MotionId>,
+    /// Active guild motion and the change it proposes.
+    pub motion: Option<GuildMotion>,
+    /// Active board election and its candidates.
+    pub election: Option<BoardElection>,
+}
+
+// ==== Guild Motion ====
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize, BorshSerialize)]
+#[serde(crate = "near_sdk::serde")]
+pub enum GuildChange {
+    AddMember { account_id: AccountId, value: u128 },
+    RemoveMember { account_id: AccountId },
+    StakeMember { account_id: AccountId, value: u128 },
+    ShareBoard { account_id: AccountId, share: u16 },
+    RemoveBoard { account_id: AccountId },
+    UpdateMetadata { metadata: GuildMetadata },
+}
+
+#[derive(Clone, BorshDeserialize, BorshSerialize)]
+pub struct GuildMotion {
+    pub id: MotionId,
+    pub change: GuildChange,
+}
+
+// ==== Board Election ====
+
+#[derive(Clone, Serialize, Deserialize, BorshDeserialize, BorshSerialize)]
+#[serde(crate = "near_sdk::serde")]
+pub struct BoardElection {
+    pub id: MotionId,
+    /// Number of open seats to fill.
+    pub seats: u64,
+    /// Share in basis points for each seated candidate.
+    pub share: u16,
+    /// Nominated candidates, the index is used as vote id.
+    pub candidates: Vec<AccountId>,
 }
 
 // ==== Guild Board ====
@@ -100,7 +237,7 @@ pub struct GuildMembers {
 impl GuildMembers {
     pub fn assert_valid(&self, max_members: u64) {
         require!(
-            self.list.len() > 0,
+            !self.list.is_empty(),
             "Guild members size must be atleast one or more"
         );
         require!(
@@ -127,3 +264,161 @@ pub struct MemberSet {
     /// Set of all the guild IDs for a member.
     pub store: UnorderedSet<GuildId>,
 }
+
+/// A page of the guilds of a member with its total membership value.
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[serde(crate = "near_sdk::serde")]
+pub struct JsonMemberSet {
+    pub value: U128,
+    pub guilds: Vec<GuildId>,
+}
+
+// ==== Vote Delegation ====
+
+/// The delegates of an account, a guild delegation overrides the global one.
+#[derive(
+    Clone, Debug, Default, PartialEq, Serialize, Deserialize, BorshDeserialize, BorshSerialize,
+)]
+#[serde(crate = "near_sdk::serde")]
+pub struct Delegation {
+    pub global: Option<AccountId>,
+    pub guilds: HashMap<GuildId, AccountId>,
+}
+
+impl Delegation {
+    /// Returns the delegate for motions of the guild, or for contract motions without a guild.
+    pub fn get_delegate(&self, scope: Option<&GuildId>) -> Option<&AccountId> {
+        scope
+            .and_then(|id| self.guilds.get(id))
+            .or(self.global.as_ref())
+    }
+
+    pub fn is_delegate(&self, account: &AccountId) -> bool {
+        self.global.as_ref() == Some(account) || self.guilds.values().any(|id| id == account)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.global.is_none() && self.guilds.is_empty()
+    }
+}
+
+// ==== Guild Creation ====
+
+/// A guild creation approved by the executive guild.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize, BorshSerialize)]
+#[serde(crate = "near_sdk::serde")]
+pub struct GuildCreate {
+    pub guild_id: GuildId,
+    pub creator_id: AccountId,
+    pub approved: bool,
+}
+
+// ==== Guild Income ====
+
+/// Returns the part of the amount for a share of the total, rounded down.
+#[inline(always)]
+pub fn get_share_amount(amount: u128, part: u128, total: u128) -> u128 {
+    if total == 0 {
+        return 0;
+    }
+    // Note: Divide first when the product overflows, this only loses dust
+    match amount.checked_mul(part) {
+        Some(value) => value / total,
+        None => amount / total * part,
+    }
+}
+
+// ==== Contract Proposal ====
+
+/// A function call the contract dispatches once its proposal passed.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize, BorshSerialize)]
+#[serde(crate = "near_sdk::serde")]
+pub struct ProposalAction {
+    pub receiver_id: AccountId,
+    pub method_name: String,
+    /// JSON encoded arguments for the method.
+    pub args: String,
+    pub deposit: U128,
+    pub gas: U64,
+}
+
+impl ProposalAction {
+    pub fn assert_valid(&self) {
+        require!(
+            !self.method_name.is_empty(),
+            "Proposal action requires a method name"
+        );
+        require!(
+            serde_json::from_str::<serde_json::Value>(&self.args).is_ok(),
+            "Proposal action args have to be valid JSON"
+        );
+        require!(
+            self.gas.0 > 0 && self.gas.0 <= MAX_GAS_FOR_PROPOSAL.0,
+            "Proposal action gas must be between 1 and 250 TGas"
+        );
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize, BorshSerialize)]
+#[serde(crate = "near_sdk::serde")]
+pub enum ProposalStatus {
+    /// The motion is open for votes.
+    Voting,
+    /// The motion passed, anyone can execute the action.
+    Approved,
+    /// The action is dispatched, waiting on its result.
+    Executing,
+    Succeeded,
+    Failed,
+    /// The motion expired before it passed, the deposit is released.
+    Expired,
+}
+
+#[derive(Clone, Serialize, Deserialize, BorshDeserialize, BorshSerialize)]
+#[serde(crate = "near_sdk::serde")]
+pub struct Proposal {
+    pub action: ProposalAction,
+    pub status: ProposalStatus,
+}
+
+// ==== Contract Queue ====
+
+/// A passed motion waiting for its delay before it takes effect.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize, BorshSerialize)]
+#[serde(crate = "near_sdk::serde")]
+pub struct QueuedMotion {
+    pub kind: MotionKind,
+    /// From this time on anyone can execute the motion.
+    pub eta: Timestamp,
+    /// Active counter-motion to cancel the queued motion.
+    pub cancel: Option<MotionId>,
+}
+
+// ==== Contract Treasury ====
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize, BorshSerialize)]
+#[serde(crate = "near_sdk::serde")]
+pub enum SpendStatus {
+    /// The motion is open for votes, the amount is reserved.
+    Voting,
+    /// The motion passed, the amount is transferred.
+    Paid,
+    /// The motion expired, the reservation is released.
+    Released,
+    /// The motion passed, the transfer is pending.
+    Paying,
+    /// The transfer failed, the amount is reserved until released.
+    Failed,
+}
+
+/// The audit record of a treasury spend.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize, BorshSerialize)]
+#[serde(crate = "near_sdk::serde")]
+pub struct Spend {
+    pub receiver_id: AccountId,
+    pub amount: U128,
+    pub status: SpendStatus,
+    pub proposer_id: AccountId,
+    pub created_at: Timestamp,
+    pub closed_at: Option<Timestamp>,
+}
@@ -1,10 +1,16 @@
 use crate::*;
 
+use crate::event::*;
 use crate::share::*;
+use crate::token::refund_storage_deposit;
+use near_sdk::StorageUsage;
 
 pub mod api;
 pub mod data;
 pub mod vote;
+#[cfg(any(test, feature = "test-utils"))]
+#[macro_use]
+pub mod test_utils;
 
 pub use self::api::*;
 pub use self::data::*;
@@ -19,115 +25,694 @@ pub struct GuildContract {
 }
 
 impl GuildContract {
+    /// Creates the contract with the executive guild that governs it.
+    pub fn new(
+        exec: &GuildId,
+        guild: &GuildInfo,
+        board_map: &HashMap<AccountId, u16>,
+        member_map: &HashMap<AccountId, u128>,
+    ) -> Self {
+        let mut guilds = Guilds::new(exec);
+        guilds.register(exec, guild, board_map, member_map);
+        Self {
+            votes: Votes::new(),
+            guilds,
+        }
+    }
+
+    /// Returns the balance that is not used for storage, reserved by spends,
+    /// owed as income or held as motion bonds and storage deposits.
+    pub fn get_available_amount(&self) -> Balance {
+        self.guilds.get_available_amount()
+    }
+
+    // ==== open motions ====
+
+    /// Captures the voters of an issued motion and holds the bond of its issuer,
+    /// the storage usage is taken before the motion was issued.
+    fn open_motion(
+        &mut self,
+        kind: MotionKind,
+        scope: &GuildId,
+        id: &MotionId,
+        storage_usage: StorageUsage,
+    ) {
+        self.snapshot(scope, id);
+        self.hold_bond(kind, id, storage_usage);
+    }
+
+    /// Captures the voters of the guild, contract motions are voted by the executive guild.
+    ///
+    /// Note: The member stakes are not copied, they are read at the checkpoint of the snapshot
+    fn snapshot(&mut self, scope: &GuildId, id: &MotionId) {
+        let guild = self.guilds.guild_map.get(scope).expect("missing guild");
+        let board = self
+            .guilds
+            .board_map
+            .get(scope)
+            .expect("missing guild board");
+        let snapshot = MotionSnapshot {
+            count: self.guilds.get_count(scope),
+            ceo: guild.info.ceo_id,
+            board: board.list.keys().collect(),
+            scope: scope.clone(),
+            epoch: self.guilds.checkpoint(),
+        };
+        self.votes.snapshot(id, &snapshot);
+    }
+
+    /// Holds the bond the policy of the kind requires from the issuer of the motion,
+    /// the deposit above the bond prepays the measured storage of the motion with
+    /// the refund policy and the rest of it is refunded.
+    fn hold_bond(&mut self, kind: MotionKind, id: &MotionId, storage_usage: StorageUsage) {
+        let policy = self.votes.get_policy(kind);
+        let amount = policy.bond.map_or(0, |bond| bond.0);
+        let deposit = env::attached_deposit();
+        require!(
+            deposit >= amount,
+            format!("The motion requires a bond of {} yoctoNEAR", amount)
+        );
+        if deposit > amount {
+            let state = self.guilds.state.get().expect("missing guilds state");
+            let cost = env::storage_byte_cost()
+                * Balance::from(env::storage_usage().saturating_sub(storage_usage));
+            let storage = match state.refund {
+                true => (deposit - amount).min(cost),
+                false => 0,
+            };
+            if storage > 0 {
+                self.votes.hold_storage(id, storage);
+                self.guilds.storage_amount += storage;
+            }
+            if deposit - amount > storage {
+                Promise::new(env::predecessor_account_id()).transfer(deposit - amount - storage);
+            }
+        }
+        if amount == 0 {
+            return;
+        }
+
+        // Note: The quorum is measured in voices, the issuer does not count toward it
+        let count = self.votes.get_snapshot(id).count;
+        let quorum = get_target(count.members, policy.quorum).max(1);
+        self.votes.hold_bond(id, amount, quorum);
+        self.guilds.bond_amount += amount;
+    }
+
+    /// Returns the deposit attached to a payable call that did not open a motion.
+    fn refund_deposit(&self) {
+        let deposit = env::attached_deposit();
+        if deposit > 0 {
+            Promise::new(env::predecessor_account_id()).transfer(deposit);
+        }
+    }
+
+    /// Seals the votes of the motion for the reveal window of the policy of the kind.
+    fn seal_motion(&self, kind: MotionKind, motion: &mut MotionInfo) {
+        let reveal = self.votes.get_policy(kind).reveal;
+        require!(
+            reveal.as_nanos() > 0,
+            "The policy does not take sealed votes"
+        );
+        motion.seal(reveal);
+    }
+
+    // ==== cast votes ====
+
+    fn cast_ceo(
+        &mut self,
+        scope: Option<&GuildId>,
+        id: &MotionId,
+        vote: VoteId,
+        sender: AccountId,
+        weight: u128,
+    ) -> MotionVoices {
+        self.votes.vote_ceo(id, vote, sender.clone(), weight);
+        self.cast_proxies(scope, id, &sender)
+    }
+
+    fn cast_board(
+        &mut self,
+        scope: Option<&GuildId>,
+        id: &MotionId,
+        vote: VoteId,
+        sender: AccountId,
+        weight: u128,
+    ) -> MotionVoices {
+        self.votes.vote_board(id, vote, sender.clone(), weight);
+        self.cast_proxies(scope, id, &sender)
+    }
+
+    fn cast_member(
+        &mut self,
+        scope: Option<&GuildId>,
+        id: &MotionId,
+        vote: VoteId,
+        sender: AccountId,
+        weight: u128,
+    ) -> MotionVoices {
+        self.votes.vote_member(id, vote, sender.clone(), weight);
+        self.cast_proxies(scope, id, &sender)
+    }
+
+    /// Returns the guild of a choice motion with the role and weight of the sender.
+    fn get_choice_voter(&self, motion_id: &MotionId) -> (GuildId, BallotRole, u128) {
+        let guild_id = self
+            .guilds
+            .choice_map
+            .get(motion_id)
+            .expect("missing choice motion");
+        self.guilds.assert_unlocked(&guild_id);
+        let sender = env::predecessor_account_id();
+
+        let snapshot = self.votes.get_snapshot(motion_id);
+        let role = if snapshot.is_ceo(&sender) {
+            BallotRole::Ceo
+        } else if snapshot.is_board(&sender) {
+            BallotRole::Board
+        } else if self.guilds.is_member_at(&snapshot, &sender) {
+            BallotRole::Member
+        } else {
+            // Error -> caller is not authorized
+            env::panic_str("unauthorized call");
+        };
+        (
+            guild_id,
+            role,
+            self.guilds.get_weight_at(&snapshot, &sender),
+        )
+    }
+
+    /// Requires the guild of the motion to be unlocked, except for its own lock or unlock motion.
+    fn assert_ballot_unlocked(&self, id: &MotionId) {
+        let guild_id = self.votes.get_snapshot(id).scope;
+        let guild = self.guilds.guild_map.get(&guild_id).expect("missing guild");
+        if guild.vote.as_ref() != Some(id) {
+            self.guilds.assert_unlocked(&guild_id);
+        }
+    }
+
+    /// Adds the voices delegated to the sender for the guild, or globally without a guild.
+    fn cast_proxies(
+        &mut self,
+        scope: Option<&GuildId>,
+        id: &MotionId,
+        sender: &AccountId,
+    ) -> MotionVoices {
+        // Note: Delegators vote with the stake they had when the motion was issued
+        let snapshot = self.votes.get_snapshot(id);
+        let proxies = self
+            .guilds
+            .get_proxies(scope, sender)
+            .into_iter()
+            .filter(|(account, _)| self.guilds.is_member_at(&snapshot, account))
+            .map(|(account, _)| {
+                let weight = self.guilds.get_weight_at(&snapshot, &account);
+                (account, weight)
+            })
+            .collect();
+        self.votes.vote_proxies(id, sender, proxies)
+    }
+
     // ==== lock contract ====
 
-    fn act_lock_contract_board(&mut self, details: &String) -> Option<MotionId> {
+    fn act_lock_contract_board(&mut self, details: &str, sealed: bool) -> Option<MotionId> {
+        let state = self.guilds.state.get().expect("missing guilds state");
         // crate a new threshold motion for the board to lock the contract
-        let id: MotionId = format!("LC:{}", env::block_height()).into();
-        let motion = new_motion_to_lock_contract(details);
+        let id = self.votes.next_id("LC", None);
+        let mut motion = new_motion_to_lock_contract(details);
+        if sealed {
+            self.seal_motion(MotionKind::LockContract, &mut motion);
+        }
 
         // senders voice agrees by default
+        let storage_usage = env::storage_usage();
         self.votes.issue(&id, &motion);
+        self.open_motion(MotionKind::LockContract, &state.exec, &id, storage_usage);
         // returns a threshold vote
-        return Some(id);
+        Some(id)
     }
 
-    fn exec_lock_contract_ceo(&mut self, id: &MotionId, sender: AccountId) -> Option<u64> {
-        self.votes.vote_ceo(&id, get_vote_id_agree(), sender);
-
-        let mut motion = self.votes.motion_map.get(id).expect("missing motion");
-        // Validate the motion state before marking it as executed
-        require!(!motion.executed, "motion already closed");
-        motion.executed = true;
-
+    fn exec_lock_ceo(
+        &mut self,
+        id: &MotionId,
+        sender: AccountId,
+        weight: u128,
+    ) -> Option<Timestamp> {
+        // Note: The CEO does not commit to a sealed motion, it locks right away
+        let motion = self.votes.motion_map.get(id).expect("missing motion");
+        if motion.info.reveal_at.is_none() {
+            self.votes.vote_ceo(id, get_vote_id_agree(), sender, weight);
+        }
         // Save the motion as executed
-        self.votes.motion_map.insert(id, &motion);
+        self.votes.execute(id);
         // And return a 24 hour timeout
-        return Some(env::block_timestamp() + EXPIRE_24H);
+        Some(Timestamp::now() + EXPIRE_24H)
     }
 
-    fn exec_lock_contract_board(
+    fn exec_lock_board(
         &mut self,
+        kind: MotionKind,
         id: &MotionId,
         sender: AccountId,
-        board_count: u64,
-    ) -> Option<u64> {
-        let voices = self.votes.vote_board(id, get_vote_id_agree(), sender);
+        weight: u128,
+        count: &MotionCount,
+    ) -> Option<Timestamp> {
+        let voices = self
+            .votes
+            .vote_board(id, get_vote_id_agree(), sender, weight);
 
-        let mut motion = self.votes.motion_map.get(id).expect("missing motion");
-        // Validate the motion state before marking it as executed
-        require!(!motion.executed, "motion already closed");
-        motion.executed = true;
+        // When the tally passes the policy > return a 24 hour timeout
+        if self.votes.exec_policy(kind, id, &voices, count) {
+            return Some(Timestamp::now() + EXPIRE_24H);
+        }
+        None
+    }
 
-        // Load threshold tally
-        let tally = voices
-            .tally
-            .get(&get_vote_id_agree())
-            .expect("missing tally");
-        // When passed the 80% threshold
-        if tally.board < ((board_count * 8000) / 10000) {
-            // Save the motion as executed
-            self.votes.motion_map.insert(id, &motion);
-            // And return a 24 hour timeout
-            return Some(env::block_timestamp() + EXPIRE_24H);
+    /// Locks the contract for the passed motion, a delayed lock waits in the queue.
+    fn pass_lock_contract(&mut self, mut state: State, id: &MotionId) {
+        // Note: A delayed lock waits in the queue, the contract keeps locking
+        if self.enqueue(MotionKind::LockContract, id) {
+            return;
         }
-        return None;
+        state.time = Some(Timestamp::now() + EXPIRE_24H);
+        state.vote = None;
+        state.lock = LockedFor::Emergency;
+        self.guilds.set_state(&state);
     }
 
     // ==== unlock contract ====
 
-    fn act_unlock_contract(&mut self, details: &String) -> Option<MotionId> {
+    fn act_unlock_contract(&mut self, details: &str) -> Option<MotionId> {
+        let state = self.guilds.state.get().expect("missing guilds state");
         // crate a new threshold motion for the board to unlock the contract
-        let id: MotionId = format!("UC:{}", env::block_height()).into();
+        let id = self.votes.next_id("UC", None);
         let motion = new_motion_to_unlock_contract(details);
         // senders voice agrees by default
+        let storage_usage = env::storage_usage();
         self.votes.issue(&id, &motion);
+        self.open_motion(MotionKind::UnlockContract, &state.exec, &id, storage_usage);
         // returns a threshold vote
-        return Some(id);
+        Some(id)
     }
 
-    fn exec_unlock_contract(
+    fn exec_unlock(
         &mut self,
+        kind: MotionKind,
         id: &MotionId,
         voices: &MotionVoices,
-        board_count: u64,
-        member_count: u64,
-    ) -> Option<u64> {
-        // Load threshold tally
-        let tally = voices
-            .tally
-            .get(&get_vote_id_agree())
-            .expect("missing tally");
-
-        // Set the target thresholds to pass at 80%
-        let board_target = (board_count * 8000) / 10000;
-        let members_target = (member_count * 8000) / 10000;
-
-        // if ceo + (board | members) threshold OR if board & members threshold
-        if (tally.ceo && (tally.board >= board_target || tally.members >= members_target))
-            || (tally.board >= board_target && tally.members >= members_target)
-        {
-            let mut motion = self.votes.motion_map.get(id).expect("missing motion");
-            motion.executed = true;
-            self.votes.motion_map.insert(id, &motion);
-            return Some(env::block_timestamp() + EXPIRE_24H);
+        count: &MotionCount,
+    ) -> Option<Timestamp> {
+        // When the tally passes the policy > return a 24 hour timeout
+        if self.votes.exec_policy(kind, id, voices, count) {
+            return Some(Timestamp::now() + EXPIRE_24H);
+        }
+        None
+    }
+
+    /// Unlocks the contract for the passed motion, a delayed unlock waits in the queue.
+    fn pass_unlock_contract(&mut self, mut state: State, id: &MotionId) {
+        if self.enqueue(MotionKind::UnlockContract, id) {
+            return;
+        }
+        state.time = Some(Timestamp::now() + EXPIRE_24H);
+        state.vote = None;
+        state.lock = LockedFor::None;
+        self.guilds.set_state(&state);
+    }
+
+    // ==== queue contract ====
+
+    /// Queues the passed motion when its policy has a delay, returns false to apply it now.
+    fn enqueue(&mut self, kind: MotionKind, id: &MotionId) -> bool {
+        let delay = self.votes.get_policy(kind).delay;
+        if delay.as_nanos() == 0 {
+            return false;
+        }
+        let queued = QueuedMotion {
+            kind,
+            eta: Timestamp::now() + delay,
+            cancel: None,
+        };
+        self.guilds.queue_map.insert(id, &queued);
+        true
+    }
+
+    /// Drops the queued motion, the contract returns to the state before the motion.
+    fn dequeue(&mut self, id: &MotionId) {
+        let queued = self
+            .guilds
+            .queue_map
+            .remove(id)
+            .expect("missing queued motion");
+        let mut state = self.guilds.state.get().expect("missing guilds state");
+        state.vote = None;
+        if queued.kind == MotionKind::LockContract {
+            state.lock = LockedFor::None;
+        }
+        self.guilds.set_state(&state);
+    }
+
+    /// Returns if the sender is on the executive board, or panics when it is not an executive member.
+    fn assert_exec_voice(&self, state: &State, sender: &AccountId) -> bool {
+        let board = self
+            .guilds
+            .board_map
+            .get(&state.exec)
+            .expect("missing executive board");
+        let members = self
+            .guilds
+            .member_map
+            .get(&state.exec)
+            .expect("missing executive members");
+        let is_board = board.list.get(sender).is_some();
+        require!(
+            is_board || members.list.get(sender).is_some(),
+            "unauthorized call"
+        );
+        is_board
+    }
+
+    // ==== lock guild ====
+
+    fn act_lock_guild_board(&mut self, guild_id: &GuildId, details: String) -> Option<MotionId> {
+        // crate a new threshold motion for the board to lock the guild
+        let id = self.votes.next_id("LG", Some(guild_id));
+        let motion = new_motion_to_lock_guild(details);
+        let storage_usage = env::storage_usage();
+        self.votes.issue(&id, &motion);
+        self.open_motion(MotionKind::LockGuild, guild_id, &id, storage_usage);
+        // returns a threshold vote
+        Some(id)
+    }
+
+    // ==== unlock guild ====
+
+    fn act_unlock_guild_board(&mut self, guild_id: &GuildId, details: String) -> Option<MotionId> {
+        // crate a new threshold motion for the board to unlock the guild
+        let id = self.votes.next_id("UG", Some(guild_id));
+        let motion = new_motion_to_unlock_guild(details);
+        let storage_usage = env::storage_usage();
+        self.votes.issue(&id, &motion);
+        self.open_motion(MotionKind::UnlockGuild, guild_id, &id, storage_usage);
+        // returns a threshold vote
+        Some(id)
+    }
+
+    // ==== guild motion ====
+
+    fn act_guild_motion(&mut self, guild_id: &GuildId, change: GuildChange, details: String) {
+        let mut guild = self.guilds.guild_map.get(guild_id).expect("missing guild");
+        require!(guild.motion.is_none(), "other guild motion is active");
+        self.guilds.assert_change(guild_id, &change);
+        let sender = env::predecessor_account_id();
+
+        // CEO | Board -> vote > threshold + ceo | members
+        let board = self
+            .guilds
+            .board_map
+            .get(guild_id)
+            .expect("missing guild board");
+        if guild.info.ceo_id == sender || board.list.get(&sender).is_some() {
+            // crate a new threshold motion to change the guild
+            let id = self.votes.next_id("GM", Some(guild_id));
+            let motion = new_motion_to_change_guild(details);
+            let storage_usage = env::storage_usage();
+            self.votes.issue(&id, &motion);
+            self.open_motion(MotionKind::ChangeGuild, guild_id, &id, storage_usage);
+
+            guild.motion = Some(GuildMotion { id, change });
+            self.guilds.set_guild(guild_id, &guild);
+            return;
+        }
+        // Error -> caller is not authorized
+        env::panic_str("unauthorized call");
+    }
+
+    // ==== challenge contract ====
+
+    fn act_challenge_contract(&mut self, details: &str, sealed: bool) -> MotionId {
+        // crate a new threshold motion to challenge the executive
+        let id = self.votes.next_id("CC", None);
+        let mut motion = new_motion_to_challenge_contract(details);
+        if sealed {
+            self.seal_motion(MotionKind::ChallengeContract, &mut motion);
+        }
+        self.votes.issue(&id, &motion);
+        id
+    }
+
+    /// Opens the replacement of the executive for the passed challenge.
+    fn pass_challenge_contract(&mut self, mut state: State) {
+        // Note: The replace motion has to be issued before the deadline
+        state.time = Some(Timestamp::now() + EXPIRE_48H);
+        state.vote = None;
+        state.lock = LockedFor::Challenging;
+        self.guilds.set_state(&state);
+    }
+
+    // ==== replace contract ====
+
+    fn act_replace_contract(&mut self, details: &str, sealed: bool) -> MotionId {
+        // crate a new threshold motion to replace the executive
+        let id = self.votes.next_id("RC", None);
+        let mut motion = new_motion_to_replace_contract(details);
+        if sealed {
+            self.seal_motion(MotionKind::ReplaceContract, &mut motion);
         }
+        self.votes.issue(&id, &motion);
+        id
+    }
+
+    /// Installs the candidate of the passed replace motion as the new CEO.
+    fn pass_replace_contract(&mut self, mut state: State) {
+        let ceo_id = state.next.clone().expect("missing ceo candidate");
+        let mut exec = self
+            .guilds
+            .guild_map
+            .get(&state.exec)
+            .expect("missing executive guild");
+        // Note: Membership can change during the vote, validate again
+        let board = self
+            .guilds
+            .board_map
+            .get(&state.exec)
+            .expect("missing executive board");
+        let members = self
+            .guilds
+            .member_map
+            .get(&state.exec)
+            .expect("missing executive members");
+        require!(
+            board.list.get(&ceo_id).is_none(),
+            "The CEO can not be a board member"
+        );
+        require!(
+            members.list.get(&ceo_id).is_some(),
+            "The CEO must be a guild member"
+        );
+        log_guild_event(ArcEventVariant::GuildCeoReplace(vec![GuildCeoLog {
+            guild_id: state.exec.to_string(),
+            ceo_id: ceo_id.to_string(),
+            prev_id: exec.info.ceo_id.to_string(),
+        }]));
+        exec.info.ceo_id = ceo_id;
+        self.guilds.set_guild(&state.exec, &exec);
+
+        state.time = Some(Timestamp::now() + EXPIRE_24H);
+        state.vote = None;
+        state.next = None;
+        state.lock = LockedFor::None;
+        self.guilds.set_state(&state);
+    }
 
-        return None;
+    // ==== policy contract ====
+
+    /// Sets the policy of the passed policy motion.
+    fn pass_policy_contract(&mut self, mut state: State) {
+        let motion = state.policy.clone().expect("missing policy motion");
+        self.votes.set_policy(motion.kind, &motion.policy);
+        log_guild_event(ArcEventVariant::PolicyChange(vec![PolicyChangeLog {
+            kind: format!("{:?}", motion.kind),
+        }]));
+        state.policy = None;
+        self.guilds.set_state(&state);
+    }
+
+    /// Applies the passed contract change.
+    fn pass_change_contract(&mut self, mut state: State) {
+        let motion = state.change.clone().expect("missing contract motion");
+        let log = match motion.change {
+            ContractChange::Guardian { guardian } => {
+                state.guardian = guardian;
+                ContractChangeLog {
+                    change: "guardian".to_string(),
+                    value: state.guardian.as_ref().map(|id| id.to_string()),
+                }
+            }
+            ContractChange::Create { policy } => {
+                state.create = policy;
+                ContractChangeLog {
+                    change: "create".to_string(),
+                    value: Some(format!("{:?}", state.create)),
+                }
+            }
+        };
+        log_guild_event(ArcEventVariant::ContractChange(vec![log]));
+        state.change = None;
+        self.guilds.set_state(&state);
+    }
+
+    // ==== spend contract ====
+
+    /// Transfers the amount of the passed spend, the transfer is settled in a callback.
+    fn pass_spend_contract(&mut self, id: &MotionId, mut spend: Spend) {
+        self.votes.execute(id);
+        spend.status = SpendStatus::Paying;
+        self.guilds.spend_map.insert(id, &spend);
+
+        Promise::new(spend.receiver_id)
+            .transfer(spend.amount.0)
+            .then(Promise::new(env::current_account_id()).function_call(
+                "resolve_spend_contract".to_string(),
+                serde_json::json!({ "id": id }).to_string().into_bytes(),
+                0,
+                GAS_FOR_RESOLVE_SPEND,
+            ));
+    }
+
+    // ==== recheck motion ====
+
+    /// Applies an open motion whose tally passes the policy after a ballot moved.
+    ///
+    /// Note: Elections and choice motions are decided when they are closed.
+    fn recheck_motion(&mut self, id: &MotionId, voices: &MotionVoices) {
+        let snapshot = self.votes.get_snapshot(id);
+        let count = snapshot.count.clone();
+        let state = self.guilds.state.get().expect("missing guilds state");
+
+        // Contract -> lock, unlock, challenge or replace
+        if state.vote.as_ref() == Some(id) {
+            let kind = match state.lock {
+                LockedFor::Locking => MotionKind::LockContract,
+                LockedFor::Emergency => MotionKind::UnlockContract,
+                LockedFor::Challenge => MotionKind::ChallengeContract,
+                LockedFor::Challenging => MotionKind::ReplaceContract,
+                _ => return,
+            };
+            if self.votes.exec_policy(kind, id, voices, &count) {
+                match kind {
+                    MotionKind::LockContract => self.pass_lock_contract(state, id),
+                    MotionKind::UnlockContract => self.pass_unlock_contract(state, id),
+                    MotionKind::ChallengeContract => self.pass_challenge_contract(state),
+                    _ => self.pass_replace_contract(state),
+                }
+            }
+            return;
+        }
+        // Contract -> policy or change
+        if state.policy.as_ref().map(|motion| &motion.id) == Some(id) {
+            if self
+                .votes
+                .exec_policy(MotionKind::PolicyContract, id, voices, &count)
+            {
+                self.pass_policy_contract(state);
+            }
+            return;
+        }
+        if state.change.as_ref().map(|motion| &motion.id) == Some(id) {
+            if self
+                .votes
+                .exec_policy(MotionKind::ChangeContract, id, voices, &count)
+            {
+                self.pass_change_contract(state);
+            }
+            return;
+        }
+        // Contract -> proposal, spend, guild creation or cancel
+        if let Some(mut proposal) = self.guilds.proposal_map.get(id) {
+            if self
+                .votes
+                .exec_policy(MotionKind::ProposalContract, id, voices, &count)
+            {
+                proposal.status = ProposalStatus::Approved;
+                self.guilds.proposal_map.insert(id, &proposal);
+            }
+            return;
+        }
+        if let Some(spend) = self.guilds.spend_map.get(id) {
+            if self
+                .votes
+                .is_passed(MotionKind::SpendContract, voices, &count)
+            {
+                self.pass_spend_contract(id, spend);
+            }
+            return;
+        }
+        if let Some(mut create) = self.guilds.create_map.get(id) {
+            if self
+                .votes
+                .exec_policy(MotionKind::CreateGuild, id, voices, &count)
+            {
+                create.approved = true;
+                self.guilds.create_map.insert(id, &create);
+            }
+            return;
+        }
+        let queued = self
+            .guilds
+            .queue_map
+            .iter()
+            .find(|(_, queued)| queued.cancel.as_ref() == Some(id));
+        if let Some((queued, _)) = queued {
+            if self
+                .votes
+                .exec_policy(MotionKind::CancelContract, id, voices, &count)
+            {
+                self.dequeue(&queued);
+            }
+            return;
+        }
+        // Guild -> lock, unlock or change
+        let guild_id = snapshot.scope;
+        let mut guild = self.guilds.guild_map.get(&guild_id).expect("missing guild");
+        if guild.vote.as_ref() == Some(id) {
+            let (kind, lock) = match guild.lock {
+                LockedFor::Locking => (MotionKind::LockGuild, LockedFor::Emergency),
+                _ => (MotionKind::UnlockGuild, LockedFor::None),
+            };
+            if self.votes.exec_policy(kind, id, voices, &count) {
+                guild.time = Some(Timestamp::now() + EXPIRE_24H);
+                guild.vote = None;
+                guild.lock = lock;
+                self.guilds.set_guild(&guild_id, &guild);
+            }
+            return;
+        }
+        if let Some(motion) = guild.motion.clone().filter(|motion| &motion.id == id) {
+            if self
+                .votes
+                .exec_policy(MotionKind::ChangeGuild, id, voices, &count)
+            {
+                self.guilds.apply_change(&guild_id, &motion.change);
+                guild.motion = None;
+                self.guilds.set_guild(&guild_id, &guild);
+            }
+        }
     }
 }
 
 impl ContractLocking for GuildContract {
     // ==== lock contract ====
 
-    fn act_lock_contract(&mut self, details: Option<String>) {
+    fn act_lock_contract(&mut self, details: Option<String>, sealed: bool) {
         let mut state = self.guilds.state.get().expect("missing guilds state");
         require!(state.lock == LockedFor::None, "contract is not unlocked");
         require!(state.vote.is_none(), "other state motion is active");
         let sender = env::predecessor_account_id();
 
         if let Some(timeout) = state.time {
-            require!(env::block_timestamp() > timeout, "timeout still active");
+            require!(timeout.is_past(), "timeout still active");
         }
 
         // CEO -> insta > locked emergency
@@ -137,10 +722,11 @@ impl ContractLocking for GuildContract {
             .get(&state.exec)
             .expect("missing executive guild");
         if exec.info.ceo_id == sender {
+            self.refund_deposit();
             state.time = None;
             state.vote = None;
             state.lock = LockedFor::Emergency;
-            self.guilds.state.set(&state);
+            self.guilds.set_state(&state);
             return;
         }
         // Board -> vote > threshold locking
@@ -152,9 +738,9 @@ impl ContractLocking for GuildContract {
         if let Some(_member) = board.list.get(&sender) {
             let details = details.expect("details needed");
             state.time = None;
-            state.vote = self.act_lock_contract_board(&details);
+            state.vote = self.act_lock_contract_board(&details, sealed);
             state.lock = LockedFor::Locking;
-            self.guilds.state.set(&state);
+            self.guilds.set_state(&state);
             return;
         }
         // Error -> caller is not an executive
@@ -164,36 +750,35 @@ impl ContractLocking for GuildContract {
     fn vote_lock_contract(&mut self) {
         let mut state = self.guilds.state.get().expect("missing guilds state");
         require!(state.lock == LockedFor::Locking, "contract is not locking");
-        let motion = state.vote.expect("missing motion id");
+        let motion = state.vote.clone().expect("missing motion id");
         let sender = env::predecessor_account_id();
+        let snapshot = self.votes.get_snapshot(&motion);
 
         // CEO -> insta > locked emergency
-        let exec = self
-            .guilds
-            .guild_map
-            .get(&state.exec)
-            .expect("missing executive guild");
-        if exec.info.ceo_id == sender {
-            state.time = self.exec_lock_contract_ceo(&motion, sender);
+        if snapshot.is_ceo(&sender) {
+            // Note: The CEO does not wait for a lock that passed into the queue
+            state.time = match self.guilds.queue_map.remove(&motion) {
+                Some(_) => Some(Timestamp::now() + EXPIRE_24H),
+                None => {
+                    let weight = self.guilds.get_weight_at(&snapshot, &sender);
+                    self.exec_lock_ceo(&motion, sender, weight)
+                }
+            };
             state.vote = None;
             state.lock = LockedFor::Emergency;
-            self.guilds.state.set(&state);
+            self.guilds.set_state(&state);
             return;
         }
         // Board -> vote > threshold locking
-        let board = self
-            .guilds
-            .board_map
-            .get(&state.exec)
-            .expect("missing executive board");
-        if let Some(_member) = board.list.get(&sender) {
-            let board_count = board.list.len();
+        if snapshot.is_board(&sender) {
+            let count = snapshot.count.clone();
+            let weight = self.guilds.get_weight_at(&snapshot, &sender);
             // When vote tally is over the threshold > locked emergency
-            if let Some(time) = self.exec_lock_contract_board(&motion, sender, board_count) {
-                state.time = Some(time);
-                state.vote = None;
-                state.lock = LockedFor::Emergency;
-                self.guilds.state.set(&state);
+            if self
+                .exec_lock_board(MotionKind::LockContract, &motion, sender, weight, &count)
+                .is_some()
+            {
+                self.pass_lock_contract(state, &motion);
             }
             return;
         }
@@ -209,7 +794,7 @@ impl ContractLocking for GuildContract {
         require!(state.vote.is_none(), "unlock motion is active");
 
         if let Some(timeout) = state.time {
-            require!(env::block_timestamp() > timeout, "timeout still active");
+            require!(timeout.is_past(), "timeout still active");
             state.time = None;
         }
         let sender = env::predecessor_account_id();
@@ -224,7 +809,7 @@ impl ContractLocking for GuildContract {
             state.time = None;
             state.vote = self.act_unlock_contract(&details);
             state.lock = LockedFor::Emergency;
-            self.guilds.state.set(&state);
+            self.guilds.set_state(&state);
             return;
         }
         // Board -> vote > threshold + ceo | members
@@ -237,7 +822,7 @@ impl ContractLocking for GuildContract {
             state.time = None;
             state.vote = self.act_unlock_contract(&details);
             state.lock = LockedFor::Emergency;
-            self.guilds.state.set(&state);
+            self.guilds.set_state(&state);
             return;
         }
         // Error -> caller is not authorized
@@ -245,93 +830,2128 @@ impl ContractLocking for GuildContract {
     }
 
     fn vote_unlock_contract(&mut self) {
-        let mut state = self.guilds.state.get().expect("missing guilds state");
+        let state = self.guilds.state.get().expect("missing guilds state");
         require!(state.lock == LockedFor::Emergency, "contract is not locked");
         require!(state.vote.is_some(), "unlock motion is mot active");
 
-        let motion = state.vote.expect("missing motion id");
+        let motion = state.vote.clone().expect("missing motion id");
         let sender = env::predecessor_account_id();
 
-        let board = self
-            .guilds
-            .board_map
-            .get(&state.exec)
-            .expect("missing executive board");
-        let board_count = board.list.len();
-        let member_count = self.guilds.account_map.len();
+        // Note: Eligibility and thresholds are measured when the motion was issued
+        let snapshot = self.votes.get_snapshot(&motion);
+        let count = snapshot.count.clone();
+        let weight = self.guilds.get_weight_at(&snapshot, &sender);
 
         // CEO -> vote > ceo support
-        let exec = self
-            .guilds
-            .guild_map
-            .get(&state.exec)
-            .expect("missing executive guild");
-        if exec.info.ceo_id == sender {
-            let voices = self.votes.vote_ceo(&motion, get_vote_id_agree(), sender);
-            if let Some(time) =
-                self.exec_unlock_contract(&motion, &voices, board_count, member_count)
+        if snapshot.is_ceo(&sender) {
+            let voices = self.cast_ceo(
+                Some(&state.exec),
+                &motion,
+                get_vote_id_agree(),
+                sender,
+                weight,
+            );
+            if self
+                .exec_unlock(MotionKind::UnlockContract, &motion, &voices, &count)
+                .is_some()
             {
-                state.time = Some(time);
-                state.vote = None;
-                state.lock = LockedFor::None;
-                self.guilds.state.set(&state);
+                self.pass_unlock_contract(state, &motion);
             }
             return;
         }
         // Board -> vote > threshold + ceo | members
-        if let Some(_member) = board.list.get(&sender) {
-            let voices = self.votes.vote_board(&motion, get_vote_id_agree(), sender);
-            if let Some(time) =
-                self.exec_unlock_contract(&motion, &voices, board_count, member_count)
+        if snapshot.is_board(&sender) {
+            let voices = self.cast_board(
+                Some(&state.exec),
+                &motion,
+                get_vote_id_agree(),
+                sender,
+                weight,
+            );
+            if self
+                .exec_unlock(MotionKind::UnlockContract, &motion, &voices, &count)
+                .is_some()
             {
-                state.time = Some(time);
-                state.vote = None;
-                state.lock = LockedFor::None;
-                self.guilds.state.set(&state);
+                self.pass_unlock_contract(state, &motion);
             }
             return;
         }
-        // Member Accounts -> Vote -> threshold + ceo | board
-        if let Some(member_set) = self.guilds.account_map.get(&sender) {
-            if !member_set.store.is_empty() {
-                let voices = self.votes.vote_member(&motion, get_vote_id_agree(), sender);
-                if let Some(time) =
-                    self.exec_unlock_contract(&motion, &voices, board_count, member_count)
-                {
-                    state.time = Some(time);
-                    state.vote = None;
-                    state.lock = LockedFor::None;
-                    self.guilds.state.set(&state);
-                }
-                return;
+        // Executive Members -> vote > threshold + ceo | board
+        if self.guilds.is_member_at(&snapshot, &sender) {
+            let voices = self.cast_member(
+                Some(&state.exec),
+                &motion,
+                get_vote_id_agree(),
+                sender,
+                weight,
+            );
+            if self
+                .exec_unlock(MotionKind::UnlockContract, &motion, &voices, &count)
+                .is_some()
+            {
+                self.pass_unlock_contract(state, &motion);
             }
+            return;
         }
         // Error -> caller is not authorized
         env::panic_str("unauthorized call");
     }
+
+    // ==== abort contract ====
+
+    fn abort_lock_contract(&mut self) {
+        let mut state = self.guilds.state.get().expect("missing guilds state");
+        require!(
+            state.lock == LockedFor::Locking || state.lock == LockedFor::Emergency,
+            "contract is not locking"
+        );
+        let id = state.vote.clone().expect("missing motion id");
+        let motion = self.votes.motion_map.get(&id).expect("missing motion");
+        require!(
+            motion.get_status() == MotionStatus::Expired,
+            "motion has not expired"
+        );
+
+        // Anyone -> abort > the expired lock or unlock motion, a locking contract is unlocked again
+        state.vote = None;
+        if state.lock == LockedFor::Locking {
+            state.lock = LockedFor::None;
+        }
+        self.guilds.set_state(&state);
+    }
 }
 
-// impl ContractChallenging for CoreContract {
-//     fn act_challenge_contract_exec(&mut self, details: String) {
-//         todo!()
-//     }
+impl LockGuild for GuildContract {
+    // ==== lock guild ====
+
+    fn act_lock_guild(&mut self, guild_id: GuildId, details: Option<String>) {
+        let mut guild = self.guilds.guild_map.get(&guild_id).expect("missing guild");
+        require!(guild.lock == LockedFor::None, "guild is not unlocked");
+        require!(guild.vote.is_none(), "other guild motion is active");
+        let sender = env::predecessor_account_id();
+
+        if let Some(timeout) = guild.time {
+            require!(timeout.is_past(), "timeout still active");
+        }
+
+        // CEO -> insta > locked emergency
+        if guild.info.ceo_id == sender {
+            self.refund_deposit();
+            guild.time = None;
+            guild.vote = None;
+            guild.lock = LockedFor::Emergency;
+            self.guilds.set_guild(&guild_id, &guild);
+            return;
+        }
+        // Board -> vote > threshold locking
+        let board = self
+            .guilds
+            .board_map
+            .get(&guild_id)
+            .expect("missing guild board");
+        if let Some(_member) = board.list.get(&sender) {
+            let details = details.expect("details needed");
+            guild.time = None;
+            guild.vote = self.act_lock_guild_board(&guild_id, details);
+            guild.lock = LockedFor::Locking;
+            self.guilds.set_guild(&guild_id, &guild);
+            return;
+        }
+        // Error -> caller is not an executive
+        env::panic_str("unauthorized call");
+    }
 
-//     fn vote_challenge_contract_exec(&mut self) {
-//         todo!()
-//     }
+    fn vote_lock_guild(&mut self, guild_id: GuildId) {
+        let mut guild = self.guilds.guild_map.get(&guild_id).expect("missing guild");
+        require!(guild.lock == LockedFor::Locking, "guild is not locking");
+        let motion = guild.vote.clone().expect("missing motion id");
+        let sender = env::predecessor_account_id();
 
-//     fn act_replace_contract_exec(&mut self, details: String) {
-//         todo!()
-//     }
+        let snapshot = self.votes.get_snapshot(&motion);
 
-//     fn vote_replace_contract_exec(&mut self) {
-//         todo!()
-//     }
-// }
+        // CEO -> insta > locked emergency
+        if snapshot.is_ceo(&sender) {
+            let weight = self.guilds.get_weight_at(&snapshot, &sender);
+            guild.time = self.exec_lock_ceo(&motion, sender, weight);
+            guild.vote = None;
+            guild.lock = LockedFor::Emergency;
+            self.guilds.set_guild(&guild_id, &guild);
+            return;
+        }
+        // Board -> vote > threshold locking
+        if snapshot.is_board(&sender) {
+            let count = snapshot.count.clone();
+            let weight = self.guilds.get_weight_at(&snapshot, &sender);
+            // When vote tally is over the threshold > locked emergency
+            if let Some(time) =
+                self.exec_lock_board(MotionKind::LockGuild, &motion, sender, weight, &count)
+            {
+                guild.time = Some(time);
+                guild.vote = None;
+                guild.lock = LockedFor::Emergency;
+                self.guilds.set_guild(&guild_id, &guild);
+            }
+            return;
+        }
+        // Error -> caller is not an executive
+        env::panic_str("unauthorized call");
+    }
+
+    // ==== unlock guild ====
+
+    fn act_unlock_guild(&mut self, guild_id: GuildId, details: String) {
+        let mut guild = self.guilds.guild_map.get(&guild_id).expect("missing guild");
+        require!(guild.lock == LockedFor::Emergency, "guild is not locked");
+        require!(guild.vote.is_none(), "unlock motion is active");
+
+        if let Some(timeout) = guild.time {
+            require!(timeout.is_past(), "timeout still active");
+        }
+        let sender = env::predecessor_account_id();
+
+        // CEO | Board -> vote > threshold + ceo | members
+        let board = self
+            .guilds
+            .board_map
+            .get(&guild_id)
+            .expect("missing guild board");
+        if guild.info.ceo_id == sender || board.list.get(&sender).is_some() {
+            guild.time = None;
+            guild.vote = self.act_unlock_guild_board(&guild_id, details);
+            self.guilds.set_guild(&guild_id, &guild);
+            return;
+        }
+        // Error -> caller is not authorized
+        env::panic_str("unauthorized call");
+    }
+
+    fn vote_unlock_guild(&mut self, guild_id: GuildId) {
+        let mut guild = self.guilds.guild_map.get(&guild_id).expect("missing guild");
+        require!(guild.lock == LockedFor::Emergency, "guild is not locked");
+        let motion = guild.vote.clone().expect("missing motion id");
+        let sender = env::predecessor_account_id();
+
+        let snapshot = self.votes.get_snapshot(&motion);
+        let count = snapshot.count.clone();
+        let weight = self.guilds.get_weight_at(&snapshot, &sender);
+
+        let voices = if snapshot.is_ceo(&sender) {
+            // CEO -> vote > ceo support
+            self.cast_ceo(
+                Some(&guild_id),
+                &motion,
+                get_vote_id_agree(),
+                sender,
+                weight,
+            )
+        } else if snapshot.is_board(&sender) {
+            // Board -> vote > threshold + ceo | members
+            self.cast_board(
+                Some(&guild_id),
+                &motion,
+                get_vote_id_agree(),
+                sender,
+                weight,
+            )
+        } else if self.guilds.is_member_at(&snapshot, &sender) {
+            // Members -> vote > threshold + ceo | board
+            self.cast_member(
+                Some(&guild_id),
+                &motion,
+                get_vote_id_agree(),
+                sender,
+                weight,
+            )
+        } else {
+            // Error -> caller is not authorized
+            env::panic_str("unauthorized call");
+        };
+
+        if let Some(time) = self.exec_unlock(MotionKind::UnlockGuild, &motion, &voices, &count) {
+            guild.time = Some(time);
+            guild.vote = None;
+            guild.lock = LockedFor::None;
+            self.guilds.set_guild(&guild_id, &guild);
+        }
+    }
+
+    // ==== abort guild ====
+
+    fn abort_lock_guild(&mut self, guild_id: GuildId) {
+        let mut guild = self.guilds.guild_map.get(&guild_id).expect("missing guild");
+        let id = guild.vote.clone().expect("missing motion id");
+        let motion = self.votes.motion_map.get(&id).expect("missing motion");
+        require!(
+            motion.get_status() == MotionStatus::Expired,
+            "motion has not expired"
+        );
+
+        // Anyone -> abort > the expired lock or unlock motion, a locking guild is unlocked again
+        guild.vote = None;
+        if guild.lock == LockedFor::Locking {
+            guild.lock = LockedFor::None;
+        }
+        self.guilds.set_guild(&guild_id, &guild);
+    }
+}
+
+impl GuildMembership for GuildContract {
+    fn act_add_guild_member(
+        &mut self,
+        guild_id: GuildId,
+        account_id: AccountId,
+        value: U128,
+        details: String,
+    ) {
+        let change = GuildChange::AddMember {
+            account_id,
+            value: value.0,
+        };
+        self.act_guild_motion(&guild_id, change, details);
+    }
+
+    fn act_remove_guild_member(
+        &mut self,
+        guild_id: GuildId,
+        account_id: AccountId,
+        details: String,
+    ) {
+        let change = GuildChange::RemoveMember { account_id };
+        self.act_guild_motion(&guild_id, change, details);
+    }
+
+    fn act_stake_guild_member(
+        &mut self,
+        guild_id: GuildId,
+        account_id: AccountId,
+        value: U128,
+        details: String,
+    ) {
+        let change = GuildChange::StakeMember {
+            account_id,
+            value: value.0,
+        };
+        self.act_guild_motion(&guild_id, change, details);
+    }
+
+    fn act_share_board_member(
+        &mut self,
+        guild_id: GuildId,
+        account_id: AccountId,
+        share: u16,
+        details: String,
+    ) {
+        let change = GuildChange::ShareBoard { account_id, share };
+        self.act_guild_motion(&guild_id, change, details);
+    }
+
+    fn act_remove_board_member(
+        &mut self,
+        guild_id: GuildId,
+        account_id: AccountId,
+        details: String,
+    ) {
+        let change = GuildChange::RemoveBoard { account_id };
+        self.act_guild_motion(&guild_id, change, details);
+    }
+
+    fn act_update_guild_metadata(
+        &mut self,
+        guild_id: GuildId,
+        metadata: GuildMetadata,
+        details: String,
+    ) {
+        let change = GuildChange::UpdateMetadata { metadata };
+        self.act_guild_motion(&guild_id, change, details);
+    }
+
+    fn vote_guild_motion(&mut self, guild_id: GuildId) {
+        let mut guild = self.guilds.guild_map.get(&guild_id).expect("missing guild");
+        let motion = guild.motion.clone().expect("missing guild motion");
+        self.guilds.assert_unlocked(&guild_id);
+        let sender = env::predecessor_account_id();
+
+        let snapshot = self.votes.get_snapshot(&motion.id);
+        let count = snapshot.count.clone();
+        let weight = self.guilds.get_weight_at(&snapshot, &sender);
+
+        let voices = if snapshot.is_ceo(&sender) {
+            // CEO -> vote > ceo support
+            self.cast_ceo(
+                Some(&guild_id),
+                &motion.id,
+                get_vote_id_agree(),
+                sender,
+                weight,
+            )
+        } else if snapshot.is_board(&sender) {
+            // Board -> vote > threshold + ceo | members
+            self.cast_board(
+                Some(&guild_id),
+                &motion.id,
+                get_vote_id_agree(),
+                sender,
+                weight,
+            )
+        } else if self.guilds.is_member_at(&snapshot, &sender) {
+            // Members -> vote > threshold + ceo | board
+            self.cast_member(
+                Some(&guild_id),
+                &motion.id,
+                get_vote_id_agree(),
+                sender,
+                weight,
+            )
+        } else {
+            // Error -> caller is not authorized
+            env::panic_str("unauthorized call");
+        };
+
+        // When vote tally is over the threshold > apply the change
+        if self
+            .votes
+            .exec_policy(MotionKind::ChangeGuild, &motion.id, &voices, &count)
+        {
+            self.guilds.apply_change(&guild_id, &motion.change);
+
+            guild.motion = None;
+            self.guilds.set_guild(&guild_id, &guild);
+        }
+    }
+}
+
+impl GuildElection for GuildContract {
+    fn act_board_election(
+        &mut self,
+        guild_id: GuildId,
+        seats: u64,
+        share: u16,
+        candidates: Vec<AccountId>,
+        details: String,
+    ) {
+        let mut guild = self.guilds.guild_map.get(&guild_id).expect("missing guild");
+        require!(guild.election.is_none(), "other board election is active");
+        let sender = env::predecessor_account_id();
+
+        // Members -> nominate > candidates for the open seats
+        let members = self
+            .guilds
+            .member_map
+            .get(&guild_id)
+            .expect("missing guild members");
+        require!(members.list.get(&sender).is_some(), "unauthorized call");
+
+        let election = BoardElection {
+            id: self.votes.next_id("BE", Some(&guild_id)),
+            seats,
+            share,
+            candidates,
+        };
+        self.guilds.assert_election(&guild_id, &election);
+
+        // crate a new plurality motion to elect the board seats
+        let motion = new_motion_to_elect_board(details, &election.candidates);
+        let storage_usage = env::storage_usage();
+        self.votes.issue(&election.id, &motion);
+        self.open_motion(
+            MotionKind::ElectionGuild,
+            &guild_id,
+            &election.id,
+            storage_usage,
+        );
+
+        guild.election = Some(election);
+        self.guilds.set_guild(&guild_id, &guild);
+    }
+
+    fn vote_board_election(&mut self, guild_id: GuildId, candidate: AccountId) {
+        let guild = self.guilds.guild_map.get(&guild_id).expect("missing guild");
+        let election = guild.election.expect("missing board election");
+        self.guilds.assert_unlocked(&guild_id);
+        let sender = env::predecessor_account_id();
+
+        // Members -> vote > one voice per member
+        let snapshot = self.votes.get_snapshot(&election.id);
+        require!(
+            self.guilds.is_member_at(&snapshot, &sender),
+            "unauthorized call"
+        );
+        let weight = self.guilds.get_weight_at(&snapshot, &sender);
+
+        let index = election
+            .candidates
+            .iter()
+            .position(|account| account == &candidate)
+            .expect("missing board candidate");
+        self.cast_member(
+            Some(&guild_id),
+            &election.id,
+            get_vote_id_option(index),
+            sender,
+            weight,
+        );
+    }
+
+    fn close_board_election(&mut self, guild_id: GuildId) {
+        let mut guild = self.guilds.guild_map.get(&guild_id).expect("missing guild");
+        let election = guild.election.clone().expect("missing board election");
+
+        let mut motion = self
+            .votes
+            .motion_map
+            .get(&election.id)
+            .expect("missing motion");
+        require!(!motion.executed, "motion already closed");
+        let voices = self
+            .votes
+            .voices_map
+            .get(&election.id)
+            .expect("missing motion voices");
+
+        // Closes when expired or when all the members of the snapshot voted
+        let snapshot = self.votes.get_snapshot(&election.id);
+        require!(
+            motion.info.expires_at <= Timestamp::now()
+                || voices.votes.len() >= snapshot.count.members,
+            "The board election is still open"
+        );
+        let members = self
+            .guilds
+            .member_map
+            .get(&guild_id)
+            .expect("missing guild members");
+
+        // Seats no candidate when the voices, or their stake, miss the policy quorum
+        let policy = self.votes.get_policy(MotionKind::ElectionGuild);
+        let turnout = MotionTally {
+            ceo: false,
+            board: 0,
+            members: voices.tally.values().map(|tally| tally.members).sum(),
+            weight: voices.tally.values().map(|tally| tally.weight).sum(),
+        };
+        let quorum = policy.is_quorum(&turnout, &snapshot.count);
+
+        // Rank the candidates by their voices or stake, ties go to the earliest nomination
+        let board = self
+            .guilds
+            .board_map
+            .get(&guild_id)
+            .expect("missing guild board");
+        let mut ranked: Vec<(u128, usize)> = election
+            .candidates
+            .iter()
+            .enumerate()
+            .filter(|(_, account)| {
+                // Note: Membership can change during the vote, skip invalid candidates
+                *account != &guild.info.ceo_id
+                    && board.list.get(account).is_none()
+                    && members.list.get(account).is_some()
+            })
+            .map(|(index, _)| {
+                let tally = voices
+                    .tally
+                    .get(&get_vote_id_option(index))
+                    .expect("missing tally");
+                match policy.weighted {
+                    true => (tally.weight, index),
+                    false => (tally.members as u128, index),
+                }
+            })
+            .filter(|(count, _)| quorum && *count > 0)
+            .collect();
+        ranked.sort_by(|a, b| b.0.cmp(&a.0).then(a.1.cmp(&b.1)));
+
+        let winners: Vec<AccountId> = ranked
+            .iter()
+            .take(election.seats as usize)
+            .map(|(_, index)| election.candidates[*index].clone())
+            .collect();
+        self.guilds.seat_board(&guild_id, &winners, election.share);
+
+        motion.executed = true;
+        self.votes.motion_map.insert(&election.id, &motion);
+
+        guild.election = None;
+        self.guilds.set_guild(&guild_id, &guild);
+    }
+}
+
+impl ContractPolicy for GuildContract {
+    fn act_policy_contract(&mut self, kind: MotionKind, policy: VotePolicy, details: String) {
+        let mut state = self.guilds.state.get().expect("missing guilds state");
+        require!(state.lock == LockedFor::None, "contract is not unlocked");
+        require!(state.policy.is_none(), "other policy motion is active");
+        policy.assert_valid(kind);
+        let sender = env::predecessor_account_id();
+
+        // CEO | Board -> vote > threshold board & members
+        let exec = self
+            .guilds
+            .guild_map
+            .get(&state.exec)
+            .expect("missing executive guild");
+        let board = self
+            .guilds
+            .board_map
+            .get(&state.exec)
+            .expect("missing executive board");
+        if exec.info.ceo_id == sender || board.list.get(&sender).is_some() {
+            // crate a new threshold motion to change the policy
+            let id = self.votes.next_id("PC", None);
+            let motion = new_motion_to_change_policy(&details);
+            let storage_usage = env::storage_usage();
+            self.votes.issue(&id, &motion);
+            self.open_motion(MotionKind::PolicyContract, &state.exec, &id, storage_usage);
+
+            state.policy = Some(PolicyMotion { id, kind, policy });
+            self.guilds.set_state(&state);
+            return;
+        }
+        // Error -> caller is not authorized
+        env::panic_str("unauthorized call");
+    }
+
+    fn vote_policy_contract(&mut self) {
+        let state = self.guilds.state.get().expect("missing guilds state");
+        require!(state.lock == LockedFor::None, "contract is not unlocked");
+        let motion = state.policy.clone().expect("missing policy motion");
+        let sender = env::predecessor_account_id();
+
+        let snapshot = self.votes.get_snapshot(&motion.id);
+        let count = snapshot.count.clone();
+        let weight = self.guilds.get_weight_at(&snapshot, &sender);
+
+        let voices = if snapshot.is_ceo(&sender) {
+            // CEO -> vote > ceo support
+            self.cast_ceo(
+                Some(&state.exec),
+                &motion.id,
+                get_vote_id_agree(),
+                sender,
+                weight,
+            )
+        } else if snapshot.is_board(&sender) {
+            // Board -> vote > threshold board & members
+            self.cast_board(
+                Some(&state.exec),
+                &motion.id,
+                get_vote_id_agree(),
+                sender,
+                weight,
+            )
+        } else if self.guilds.is_member_at(&snapshot, &sender) {
+            // Members -> vote > threshold board & members
+            self.cast_member(
+                Some(&state.exec),
+                &motion.id,
+                get_vote_id_agree(),
+                sender,
+                weight,
+            )
+        } else {
+            // Error -> caller is not authorized
+            env::panic_str("unauthorized call");
+        };
+
+        // When vote tally is over the threshold > apply the policy
+        if self
+            .votes
+            .exec_policy(MotionKind::PolicyContract, &motion.id, &voices, &count)
+        {
+            self.pass_policy_contract(state);
+        }
+    }
+
+    fn act_change_contract(&mut self, change: ContractChange, details: String) {
+        let mut state = self.guilds.state.get().expect("missing guilds state");
+        require!(state.lock == LockedFor::None, "contract is not unlocked");
+        require!(state.change.is_none(), "other contract motion is active");
+        let sender = env::predecessor_account_id();
+
+        // CEO | Board -> vote > threshold board & members
+        let exec = self
+            .guilds
+            .guild_map
+            .get(&state.exec)
+            .expect("missing executive guild");
+        let board = self
+            .guilds
+            .board_map
+            .get(&state.exec)
+            .expect("missing executive board");
+        if exec.info.ceo_id == sender || board.list.get(&sender).is_some() {
+            // crate a new threshold motion to change the contract
+            let id = self.votes.next_id("MC", None);
+            let motion = new_motion_to_change_contract(&details);
+            let storage_usage = env::storage_usage();
+            self.votes.issue(&id, &motion);
+            self.open_motion(MotionKind::ChangeContract, &state.exec, &id, storage_usage);
+
+            state.change = Some(ContractMotion { id, change });
+            self.guilds.set_state(&state);
+            return;
+        }
+        // Error -> caller is not authorized
+        env::panic_str("unauthorized call");
+    }
+
+    fn vote_change_contract(&mut self) {
+        let state = self.guilds.state.get().expect("missing guilds state");
+        require!(state.lock == LockedFor::None, "contract is not unlocked");
+        let motion = state.change.clone().expect("missing contract motion");
+        let sender = env::predecessor_account_id();
+
+        let snapshot = self.votes.get_snapshot(&motion.id);
+        let count = snapshot.count.clone();
+        let weight = self.guilds.get_weight_at(&snapshot, &sender);
+
+        let voices = if snapshot.is_ceo(&sender) {
+            // CEO -> vote > ceo support
+            self.cast_ceo(
+                Some(&state.exec),
+                &motion.id,
+                get_vote_id_agree(),
+                sender,
+                weight,
+            )
+        } else if snapshot.is_board(&sender) {
+            // Board -> vote > threshold board & members
+            self.cast_board(
+                Some(&state.exec),
+                &motion.id,
+                get_vote_id_agree(),
+                sender,
+                weight,
+            )
+        } else if self.guilds.is_member_at(&snapshot, &sender) {
+            // Members -> vote > threshold board & members
+            self.cast_member(
+                Some(&state.exec),
+                &motion.id,
+                get_vote_id_agree(),
+                sender,
+                weight,
+            )
+        } else {
+            // Error -> caller is not authorized
+            env::panic_str("unauthorized call");
+        };
+
+        // When vote tally is over the threshold > apply the change
+        if self
+            .votes
+            .exec_policy(MotionKind::ChangeContract, &motion.id, &voices, &count)
+        {
+            self.pass_change_contract(state);
+        }
+    }
+}
+
+impl ContractChallenging for GuildContract {
+    // ==== challenge contract ====
+
+    fn act_challenge_contract_exec(&mut self, details: String, sealed: bool) {
+        let mut state = self.guilds.state.get().expect("missing guilds state");
+        require!(state.lock == LockedFor::None, "contract is not unlocked");
+        require!(state.vote.is_none(), "other state motion is active");
+        let sender = env::predecessor_account_id();
+
+        if let Some(timeout) = state.time {
+            require!(timeout.is_past(), "timeout still active");
+        }
+
+        // CEO -> can not challenge itself
+        let exec = self
+            .guilds
+            .guild_map
+            .get(&state.exec)
+            .expect("missing executive guild");
+        require!(exec.info.ceo_id != sender, "unauthorized call");
+
+        let board = self
+            .guilds
+            .board_map
+            .get(&state.exec)
+            .expect("missing executive board");
+        let members = self
+            .guilds
+            .member_map
+            .get(&state.exec)
+            .expect("missing executive members");
+        let count = self.guilds.get_count(&state.exec);
+        let weight = self.guilds.get_weight(&state.exec, &sender);
+
+        // Board | Members -> vote > threshold board | members
+        let is_board = board.list.get(&sender).is_some();
+        require!(
+            is_board || members.list.get(&sender).is_some(),
+            "unauthorized call"
+        );
+
+        let storage_usage = env::storage_usage();
+        let id = self.act_challenge_contract(&details, sealed);
+        self.open_motion(
+            MotionKind::ChallengeContract,
+            &state.exec,
+            &id,
+            storage_usage,
+        );
+        state.time = None;
+        state.vote = Some(id.clone());
+        state.lock = LockedFor::Challenge;
+        // Note: The sender commits to a sealed motion like any other voter
+        if sealed {
+            self.guilds.set_state(&state);
+            return;
+        }
+
+        // senders voice agrees by default
+        let voices = match is_board {
+            true => self.cast_board(Some(&state.exec), &id, get_vote_id_agree(), sender, weight),
+            false => self.cast_member(Some(&state.exec), &id, get_vote_id_agree(), sender, weight),
+        };
+        // When vote tally is over the threshold > challenging
+        if self
+            .votes
+            .exec_policy(MotionKind::ChallengeContract, &id, &voices, &count)
+        {
+            self.pass_challenge_contract(state);
+            return;
+        }
+        self.guilds.set_state(&state);
+    }
+
+    fn vote_challenge_contract_exec(&mut self) {
+        let state = self.guilds.state.get().expect("missing guilds state");
+        require!(
+            state.lock == LockedFor::Challenge,
+            "contract is not challenged"
+        );
+        let motion = state.vote.clone().expect("missing motion id");
+        let sender = env::predecessor_account_id();
+
+        let snapshot = self.votes.get_snapshot(&motion);
+        let count = snapshot.count.clone();
+        let weight = self.guilds.get_weight_at(&snapshot, &sender);
+
+        // CEO -> can not vote on its own challenge
+        let exec = self
+            .guilds
+            .guild_map
+            .get(&state.exec)
+            .expect("missing executive guild");
+        require!(exec.info.ceo_id != sender, "unauthorized call");
+
+        let voices = if snapshot.is_board(&sender) {
+            // Board -> vote > threshold board | members
+            self.cast_board(
+                Some(&state.exec),
+                &motion,
+                get_vote_id_agree(),
+                sender,
+                weight,
+            )
+        } else if self.guilds.is_member_at(&snapshot, &sender) {
+            // Members -> vote > threshold board | members
+            self.cast_member(
+                Some(&state.exec),
+                &motion,
+                get_vote_id_agree(),
+                sender,
+                weight,
+            )
+        } else {
+            // Error -> caller is not authorized
+            env::panic_str("unauthorized call");
+        };
+
+        // When vote tally is over the threshold > challenging
+        if self
+            .votes
+            .exec_policy(MotionKind::ChallengeContract, &motion, &voices, &count)
+        {
+            self.pass_challenge_contract(state);
+        }
+    }
+
+    fn abort_challenge_contract_exec(&mut self) {
+        let mut state = self.guilds.state.get().expect("missing guilds state");
+        require!(
+            state.lock == LockedFor::Challenge || state.lock == LockedFor::Challenging,
+            "contract is not challenged"
+        );
+
+        match &state.vote {
+            // Anyone -> abort > once the challenge or replace motion expired
+            Some(motion) => {
+                let motion = self.votes.motion_map.get(motion).expect("missing motion");
+                require!(
+                    motion.get_status() == MotionStatus::Expired,
+                    "motion has not expired"
+                );
+            }
+            // Anyone -> abort > once the replace deadline of the passed challenge passed
+            None => {
+                let deadline = state.time.expect("missing replace deadline");
+                require!(deadline.is_past(), "The replace deadline has not passed");
+            }
+        }
+
+        // Note: The expired motion is released for finalize_motion
+        state.time = None;
+        state.vote = None;
+        state.next = None;
+        state.lock = LockedFor::None;
+        self.guilds.set_state(&state);
+    }
+
+    // ==== replace contract ====
+
+    fn act_replace_contract_exec(&mut self, ceo_id: AccountId, details: String, sealed: bool) {
+        let mut state = self.guilds.state.get().expect("missing guilds state");
+        require!(
+            state.lock == LockedFor::Challenging,
+            "contract is not challenging"
+        );
+        require!(state.vote.is_none(), "replace motion is active");
+        let sender = env::predecessor_account_id();
+
+        if let Some(deadline) = state.time {
+            require!(!deadline.is_past(), "The replace deadline has passed");
+        }
+
+        let exec = self
+            .guilds
+            .guild_map
+            .get(&state.exec)
+            .expect("missing executive guild");
+        require!(exec.info.ceo_id != sender, "unauthorized call");
+        require!(exec.info.ceo_id != ceo_id, "The CEO can not replace itself");
+
+        let board = self
+            .guilds
+            .board_map
+            .get(&state.exec)
+            .expect("missing executive board");
+        let members = self
+            .guilds
+            .member_map
+            .get(&state.exec)
+            .expect("missing executive members");
+        require!(
+            board.list.get(&ceo_id).is_none(),
+            "The CEO can not be a board member"
+        );
+        require!(
+            members.list.get(&ceo_id).is_some(),
+            "The CEO must be a guild member"
+        );
+
+        // Board -> vote > threshold board & members
+        require!(board.list.get(&sender).is_some(), "unauthorized call");
+        let count = self.guilds.get_count(&state.exec);
+        let weight = self.guilds.get_weight(&state.exec, &sender);
+
+        let storage_usage = env::storage_usage();
+        let id = self.act_replace_contract(&details, sealed);
+        self.open_motion(MotionKind::ReplaceContract, &state.exec, &id, storage_usage);
+        state.time = None;
+        state.vote = Some(id.clone());
+        state.next = Some(ceo_id);
+        // Note: The sender commits to a sealed motion like any other voter
+        if sealed {
+            self.guilds.set_state(&state);
+            return;
+        }
+
+        // senders voice agrees by default
+        let voices = self.cast_board(Some(&state.exec), &id, get_vote_id_agree(), sender, weight);
+        // When vote tally is over the threshold > install the new CEO
+        if self
+            .votes
+            .exec_policy(MotionKind::ReplaceContract, &id, &voices, &count)
+        {
+            self.pass_replace_contract(state);
+            return;
+        }
+        self.guilds.set_state(&state);
+    }
+
+    fn vote_replace_contract_exec(&mut self) {
+        let state = self.guilds.state.get().expect("missing guilds state");
+        require!(
+            state.lock == LockedFor::Challenging,
+            "contract is not challenging"
+        );
+        let motion = state.vote.clone().expect("missing motion id");
+        let sender = env::predecessor_account_id();
+
+        let snapshot = self.votes.get_snapshot(&motion);
+        let count = snapshot.count.clone();
+        let weight = self.guilds.get_weight_at(&snapshot, &sender);
+
+        // CEO -> can not vote on its own replacement
+        let exec = self
+            .guilds
+            .guild_map
+            .get(&state.exec)
+            .expect("missing executive guild");
+        require!(exec.info.ceo_id != sender, "unauthorized call");
+
+        let voices = if snapshot.is_board(&sender) {
+            // Board -> vote > threshold board & members
+            self.cast_board(
+                Some(&state.exec),
+                &motion,
+                get_vote_id_agree(),
+                sender,
+                weight,
+            )
+        } else if self.guilds.is_member_at(&snapshot, &sender) {
+            // Members -> vote > threshold board & members
+            self.cast_member(
+                Some(&state.exec),
+                &motion,
+                get_vote_id_agree(),
+                sender,
+                weight,
+            )
+        } else {
+            // Error -> caller is not authorized
+            env::panic_str("unauthorized call");
+        };
+
+        // When vote tally is over the threshold > install the new CEO
+        if self
+            .votes
+            .exec_policy(MotionKind::ReplaceContract, &motion, &voices, &count)
+        {
+            self.pass_replace_contract(state);
+        }
+    }
+}
+
+impl ContractSealing for GuildContract {
+    fn commit_contract_motion(&mut self, hash: String) {
+        let state = self.guilds.state.get().expect("missing guilds state");
+        let motion = state.vote.clone().expect("missing motion id");
+        let sender = env::predecessor_account_id();
+        let snapshot = self.votes.get_snapshot(&motion);
+        let weight = self.guilds.get_weight_at(&snapshot, &sender);
+
+        let role = match state.lock {
+            // Board -> commit > threshold locking
+            LockedFor::Locking if snapshot.is_board(&sender) => BallotRole::Board,
+            // Board | Members -> commit > threshold board & members
+            LockedFor::Challenge | LockedFor::Challenging if snapshot.is_ceo(&sender) => {
+                env::panic_str("unauthorized call")
+            }
+            LockedFor::Challenge | LockedFor::Challenging if snapshot.is_board(&sender) => {
+                BallotRole::Board
+            }
+            LockedFor::Challenge | LockedFor::Challenging
+                if self.guilds.is_member_at(&snapshot, &sender) =>
+            {
+                BallotRole::Member
+            }
+            LockedFor::Locking | LockedFor::Challenge | LockedFor::Challenging => {
+                env::panic_str("unauthorized call")
+            }
+            _ => env::panic_str("contract motion is not active"),
+        };
+        self.votes.commit(&motion, hash, sender, role, weight);
+    }
+
+    fn reveal_contract_motion(&mut self, salt: String) {
+        let state = self.guilds.state.get().expect("missing guilds state");
+        let motion = state.vote.clone().expect("missing motion id");
+        let sender = env::predecessor_account_id();
+        let count = self.votes.get_snapshot(&motion).count;
+
+        // Committer -> reveal > the vote, the voice counts with its committed role
+        let voices = self
+            .votes
+            .reveal(&motion, vec![get_vote_id_agree()], &salt, sender.clone());
+        match state.lock {
+            LockedFor::Locking => {
+                if self
+                    .votes
+                    .exec_policy(MotionKind::LockContract, &motion, &voices, &count)
+                {
+                    self.pass_lock_contract(state, &motion);
+                }
+            }
+            LockedFor::Challenge => {
+                let voices = self.cast_proxies(Some(&state.exec), &motion, &sender);
+                if self
+                    .votes
+                    .exec_policy(MotionKind::ChallengeContract, &motion, &voices, &count)
+                {
+                    self.pass_challenge_contract(state);
+                }
+            }
+            LockedFor::Challenging => {
+                let voices = self.cast_proxies(Some(&state.exec), &motion, &sender);
+                if self
+                    .votes
+                    .exec_policy(MotionKind::ReplaceContract, &motion, &voices, &count)
+                {
+                    self.pass_replace_contract(state);
+                }
+            }
+            _ => env::panic_str("contract motion is not active"),
+        }
+    }
+}
+
+impl ContractProposal for GuildContract {
+    fn act_proposal_contract(&mut self, action: ProposalAction, details: String) {
+        let state = self.guilds.state.get().expect("missing guilds state");
+        require!(state.lock == LockedFor::None, "contract is not unlocked");
+        action.assert_valid();
+        require!(
+            action.deposit.0 <= self.guilds.get_available_amount(),
+            "Proposal deposit exceeds the available amount"
+        );
+        let sender = env::predecessor_account_id();
+
+        // CEO | Board -> vote > threshold + ceo | members
+        let exec = self
+            .guilds
+            .guild_map
+            .get(&state.exec)
+            .expect("missing executive guild");
+        let board = self
+            .guilds
+            .board_map
+            .get(&state.exec)
+            .expect("missing executive board");
+        if exec.info.ceo_id == sender || board.list.get(&sender).is_some() {
+            // crate a new threshold motion to execute the action
+            let id = self.votes.next_id("XC", None);
+            let motion = new_motion_to_execute_proposal(&details);
+            let storage_usage = env::storage_usage();
+            self.votes.issue(&id, &motion);
+            self.open_motion(
+                MotionKind::ProposalContract,
+                &state.exec,
+                &id,
+                storage_usage,
+            );
+
+            // reserve the deposit of the action while the motion is open
+            self.guilds.locked_amount += action.deposit.0;
+            let proposal = Proposal {
+                action,
+                status: ProposalStatus::Voting,
+            };
+            self.guilds.proposal_map.insert(&id, &proposal);
+            return;
+        }
+        // Error -> caller is not authorized
+        env::panic_str("unauthorized call");
+    }
+
+    fn vote_proposal_contract(&mut self, id: MotionId) {
+        let state = self.guilds.state.get().expect("missing guilds state");
+        require!(state.lock == LockedFor::None, "contract is not unlocked");
+        let mut proposal = self.guilds.proposal_map.get(&id).expect("missing proposal");
+        require!(
+            proposal.status == ProposalStatus::Voting,
+            "proposal is not voting"
+        );
+        let sender = env::predecessor_account_id();
+
+        let snapshot = self.votes.get_snapshot(&id);
+        let count = snapshot.count.clone();
+        let weight = self.guilds.get_weight_at(&snapshot, &sender);
+
+        let voices = if snapshot.is_ceo(&sender) {
+            // CEO -> vote > ceo support
+            self.cast_ceo(Some(&state.exec), &id, get_vote_id_agree(), sender, weight)
+        } else if snapshot.is_board(&sender) {
+            // Board -> vote > threshold + ceo | members
+            self.cast_board(Some(&state.exec), &id, get_vote_id_agree(), sender, weight)
+        } else if self.guilds.is_member_at(&snapshot, &sender) {
+            // Members -> vote > threshold + ceo | board
+            self.cast_member(Some(&state.exec), &id, get_vote_id_agree(), sender, weight)
+        } else {
+            // Error -> caller is not authorized
+            env::panic_str("unauthorized call");
+        };
+
+        // When vote tally is over the threshold > approve the action
+        // Note: The approved motion is closed, it can not expire before the action runs
+        if self
+            .votes
+            .exec_policy(MotionKind::ProposalContract, &id, &voices, &count)
+        {
+            proposal.status = ProposalStatus::Approved;
+            self.guilds.proposal_map.insert(&id, &proposal);
+        }
+    }
+
+    fn exec_proposal_contract(&mut self, id: MotionId) -> Promise {
+        let state = self.guilds.state.get().expect("missing guilds state");
+        require!(state.lock == LockedFor::None, "contract is not unlocked");
+        let mut proposal = self.guilds.proposal_map.get(&id).expect("missing proposal");
+        require!(
+            proposal.status == ProposalStatus::Approved,
+            "proposal is not approved"
+        );
+
+        let action = proposal.action.clone();
+        require!(
+            env::prepaid_gas() >= Gas(action.gas.0) + GAS_FOR_RESOLVE_PROPOSAL,
+            "Not enough gas attached to execute the proposal"
+        );
+
+        // Anyone -> execute > the action as the contract
+        proposal.status = ProposalStatus::Executing;
+        self.guilds.proposal_map.insert(&id, &proposal);
+
+        Promise::new(action.receiver_id)
+            .function_call(
+                action.method_name,
+                action.args.into_bytes(),
+                action.deposit.0,
+                Gas(action.gas.0),
+            )
+            .then(Promise::new(env::current_account_id()).function_call(
+                "resolve_proposal_contract".to_string(),
+                serde_json::json!({ "id": id }).to_string().into_bytes(),
+                0,
+                GAS_FOR_RESOLVE_PROPOSAL,
+            ))
+    }
+
+    fn resolve_proposal_contract(&mut self, id: MotionId) -> bool {
+        require!(
+            env::predecessor_account_id() == env::current_account_id(),
+            "Method resolve_proposal_contract is private"
+        );
+        let mut proposal = self.guilds.proposal_map.get(&id).expect("missing proposal");
+        require!(
+            proposal.status == ProposalStatus::Executing,
+            "proposal is not executing"
+        );
+
+        // Record the result of the action, a failed call returns its deposit
+        let succeeded = matches!(env::promise_result(0), PromiseResult::Successful(_));
+        self.guilds.locked_amount -= proposal.action.deposit.0;
+        proposal.status = match succeeded {
+            true => ProposalStatus::Succeeded,
+            false => ProposalStatus::Failed,
+        };
+        self.guilds.proposal_map.insert(&id, &proposal);
+
+        log_guild_event(ArcEventVariant::ProposalExecute(vec![ProposalExecuteLog {
+            proposal_id: id.to_string(),
+            receiver_id: proposal.action.receiver_id.to_string(),
+            method_name: proposal.action.method_name,
+            status: match succeeded {
+                true => "succeeded".to_string(),
+                false => "failed".to_string(),
+            },
+        }]));
+        succeeded
+    }
+}
+
+impl ContractQueue for GuildContract {
+    fn exec_queued_contract(&mut self, id: MotionId) {
+        let queued = self
+            .guilds
+            .queue_map
+            .get(&id)
+            .expect("missing queued motion");
+        require!(
+            queued.eta <= Timestamp::now(),
+            "The queued motion is still delayed"
+        );
+
+        // Anyone -> execute > the queued motion after its delay
+        self.guilds.queue_map.remove(&id);
+        let mut state = self.guilds.state.get().expect("missing guilds state");
+        state.time = Some(Timestamp::now() + EXPIRE_24H);
+        state.vote = None;
+        state.lock = match queued.kind {
+            MotionKind::LockContract => LockedFor::Emergency,
+            MotionKind::UnlockContract => LockedFor::None,
+            _ => env::panic_str("unsupported queued motion"),
+        };
+        self.guilds.set_state(&state);
+    }
+
+    fn cancel_queued_contract(&mut self, id: MotionId) {
+        let state = self.guilds.state.get().expect("missing guilds state");
+
+        // Guardian -> cancel > the queued motion during its delay
+        require!(
+            state.guardian == Some(env::predecessor_account_id()),
+            "unauthorized call"
+        );
+        self.dequeue(&id);
+    }
+
+    fn act_cancel_queued_contract(&mut self, id: MotionId, details: String) {
+        let state = self.guilds.state.get().expect("missing guilds state");
+        let mut queued = self
+            .guilds
+            .queue_map
+            .get(&id)
+            .expect("missing queued motion");
+        require!(queued.cancel.is_none(), "other cancel motion is active");
+        require!(
+            Timestamp::now() < queued.eta,
+            "The queued motion is ready for execution"
+        );
+        let sender = env::predecessor_account_id();
+        let is_board = self.assert_exec_voice(&state, &sender);
+        let count = self.guilds.get_count(&state.exec);
+        let weight = self.guilds.get_weight(&state.exec, &sender);
+
+        // Board | Members -> vote > threshold board | members, until the queued motion is due
+        let cancel = self.votes.next_id("QC", None);
+        let motion = new_motion_to_cancel_contract(&details, queued.eta);
+        let storage_usage = env::storage_usage();
+        self.votes.issue(&cancel, &motion);
+        self.open_motion(
+            MotionKind::CancelContract,
+            &state.exec,
+            &cancel,
+            storage_usage,
+        );
+        queued.cancel = Some(cancel.clone());
+        self.guilds.queue_map.insert(&id, &queued);
+
+        // senders voice agrees by default
+        let voices = match is_board {
+            true => self.cast_board(
+                Some(&state.exec),
+                &cancel,
+                get_vote_id_agree(),
+                sender,
+                weight,
+            ),
+            false => self.cast_member(
+                Some(&state.exec),
+                &cancel,
+                get_vote_id_agree(),
+                sender,
+                weight,
+            ),
+        };
+        if self
+            .votes
+            .exec_policy(MotionKind::CancelContract, &cancel, &voices, &count)
+        {
+            self.dequeue(&id);
+        }
+    }
+
+    fn vote_cancel_queued_contract(&mut self, id: MotionId) {
+        let state = self.guilds.state.get().expect("missing guilds state");
+        let queued = self
+            .guilds
+            .queue_map
+            .get(&id)
+            .expect("missing queued motion");
+        let cancel = queued.cancel.expect("missing cancel motion");
+        let sender = env::predecessor_account_id();
+        let snapshot = self.votes.get_snapshot(&cancel);
+        let is_board = snapshot.is_board(&sender);
+        require!(
+            is_board || self.guilds.is_member_at(&snapshot, &sender),
+            "unauthorized call"
+        );
+        let count = snapshot.count.clone();
+        let weight = self.guilds.get_weight_at(&snapshot, &sender);
+
+        // Board | Members -> vote > threshold board | members
+        let voices = match is_board {
+            true => self.cast_board(
+                Some(&state.exec),
+                &cancel,
+                get_vote_id_agree(),
+                sender,
+                weight,
+            ),
+            false => self.cast_member(
+                Some(&state.exec),
+                &cancel,
+                get_vote_id_agree(),
+                sender,
+                weight,
+            ),
+        };
+        if self
+            .votes
+            .exec_policy(MotionKind::CancelContract, &cancel, &voices, &count)
+        {
+            self.dequeue(&id);
+        }
+    }
+}
+
+impl ContractTreasury for GuildContract {
+    fn act_spend_contract(&mut self, receiver_id: AccountId, amount: U128, details: String) {
+        let state = self.guilds.state.get().expect("missing guilds state");
+        require!(state.lock == LockedFor::None, "contract is not unlocked");
+        require!(amount.0 > 0, "Spend amount must be greater than zero");
+        require!(
+            amount.0 <= self.guilds.get_available_amount(),
+            "Spend amount exceeds the available amount"
+        );
+        let sender = env::predecessor_account_id();
+
+        // CEO | Board -> vote > threshold + ceo | members
+        let exec = self
+            .guilds
+            .guild_map
+            .get(&state.exec)
+            .expect("missing executive guild");
+        let board = self
+            .guilds
+            .board_map
+            .get(&state.exec)
+            .expect("missing executive board");
+        if exec.info.ceo_id == sender || board.list.get(&sender).is_some() {
+            // crate a new threshold motion to transfer the amount
+            let id = self.votes.next_id("SC", None);
+            let motion = new_motion_to_spend_treasury(&details);
+            let storage_usage = env::storage_usage();
+            self.votes.issue(&id, &motion);
+            self.open_motion(MotionKind::SpendContract, &state.exec, &id, storage_usage);
+
+            // reserve the amount while the motion is open
+            self.guilds.locked_amount += amount.0;
+            let spend = Spend {
+                receiver_id,
+                amount,
+                status: SpendStatus::Voting,
+                proposer_id: sender,
+                created_at: Timestamp::now(),
+                closed_at: None,
+            };
+            self.guilds.spend_map.insert(&id, &spend);
+            return;
+        }
+        // Error -> caller is not authorized
+        env::panic_str("unauthorized call");
+    }
+
+    fn vote_spend_contract(&mut self, id: MotionId) {
+        let state = self.guilds.state.get().expect("missing guilds state");
+        require!(state.lock == LockedFor::None, "contract is not unlocked");
+        let spend = self.guilds.spend_map.get(&id).expect("missing spend");
+        require!(spend.status == SpendStatus::Voting, "spend is not voting");
+        let sender = env::predecessor_account_id();
+
+        let snapshot = self.votes.get_snapshot(&id);
+        let count = snapshot.count.clone();
+        let weight = self.guilds.get_weight_at(&snapshot, &sender);
+
+        let voices = if snapshot.is_ceo(&sender) {
+            // CEO -> vote > ceo support
+            self.cast_ceo(Some(&state.exec), &id, get_vote_id_agree(), sender, weight)
+        } else if snapshot.is_board(&sender) {
+            // Board -> vote > threshold + ceo | members
+            self.cast_board(Some(&state.exec), &id, get_vote_id_agree(), sender, weight)
+        } else if self.guilds.is_member_at(&snapshot, &sender) {
+            // Members -> vote > threshold + ceo | board
+            self.cast_member(Some(&state.exec), &id, get_vote_id_agree(), sender, weight)
+        } else {
+            // Error -> caller is not authorized
+            env::panic_str("unauthorized call");
+        };
+
+        // When vote tally is over the threshold > transfer the amount
+        if self
+            .votes
+            .is_passed(MotionKind::SpendContract, &voices, &count)
+        {
+            self.pass_spend_contract(&id, spend);
+        }
+    }
+
+    fn resolve_spend_contract(&mut self, id: MotionId) -> bool {
+        require!(
+            env::predecessor_account_id() == env::current_account_id(),
+            "Method resolve_spend_contract is private"
+        );
+        let mut spend = self.guilds.spend_map.get(&id).expect("missing spend");
+        require!(spend.status == SpendStatus::Paying, "spend is not paying");
+
+        // Release the reservation only when the transfer succeeded
+        let succeeded = matches!(env::promise_result(0), PromiseResult::Successful(_));
+        if succeeded {
+            self.guilds.locked_amount -= spend.amount.0;
+            spend.status = SpendStatus::Paid;
+            spend.closed_at = Some(Timestamp::now());
+        } else {
+            spend.status = SpendStatus::Failed;
+        }
+        self.guilds.spend_map.insert(&id, &spend);
+
+        log_guild_event(ArcEventVariant::SpendPay(vec![SpendPayLog {
+            spend_id: id.to_string(),
+            receiver_id: spend.receiver_id.to_string(),
+            amount: spend.amount.0.to_string(),
+            status: match succeeded {
+                true => "paid".to_string(),
+                false => "failed".to_string(),
+            },
+        }]));
+        succeeded
+    }
+
+    fn release_spend_contract(&mut self, id: MotionId) {
+        let mut spend = self.guilds.spend_map.get(&id).expect("missing spend");
+        match spend.status {
+            SpendStatus::Voting => {
+                let motion = self.votes.motion_map.get(&id).expect("missing motion");
+                require!(
+                    motion.get_status() == MotionStatus::Expired,
+                    "motion has not expired"
+                );
+            }
+            // Note: The amount of a failed transfer is back in the contract
+            SpendStatus::Failed => {}
+            _ => env::panic_str("spend is not voting"),
+        }
+
+        // Anyone -> release > the reservation of an expired motion or a failed transfer
+        self.guilds.locked_amount -= spend.amount.0;
+        spend.status = SpendStatus::Released;
+        spend.closed_at = Some(Timestamp::now());
+        self.guilds.spend_map.insert(&id, &spend);
+    }
+}
+
+impl GuildChoice for GuildContract {
+    fn act_choice_motion(
+        &mut self,
+        guild_id: GuildId,
+        title: String,
+        options: Vec<VoteInfo>,
+        method: ChoiceMethod,
+        sealed: bool,
+        details: String,
+    ) {
+        self.guilds.assert_unlocked(&guild_id);
+        for option in options.iter() {
+            option.assert_valid();
+        }
+
+        // Members -> issue > a motion to choose between the options
+        let members = self
+            .guilds
+            .member_map
+            .get(&guild_id)
+            .expect("missing guild members");
+        require!(
+            members.list.get(&env::predecessor_account_id()).is_some(),
+            "unauthorized call"
+        );
+
+        let id = self.votes.next_id("OM", Some(&guild_id));
+        let mut motion = new_motion_with_options(title, details, options);
+        if sealed {
+            self.seal_motion(MotionKind::ChoiceGuild, &mut motion);
+        }
+        let storage_usage = env::storage_usage();
+        self.votes.issue_choice(&id, &motion, method);
+        self.open_motion(MotionKind::ChoiceGuild, &guild_id, &id, storage_usage);
+        self.guilds.choice_map.insert(&id, &guild_id);
+    }
+
+    fn vote_choice_motion(&mut self, motion_id: MotionId, ranking: Vec<VoteId>) {
+        let (guild_id, role, weight) = self.get_choice_voter(&motion_id);
+        let sender = env::predecessor_account_id();
+
+        // Ceo | Board | Members -> rank > the options, the winner is picked on finalize
+        self.votes
+            .rank(&motion_id, ranking, sender.clone(), role, weight);
+        self.cast_proxies(Some(&guild_id), &motion_id, &sender);
+    }
+
+    fn commit_choice_motion(&mut self, motion_id: MotionId, hash: String) {
+        let (_, role, weight) = self.get_choice_voter(&motion_id);
+
+        // Ceo | Board | Members -> commit > a sealed ranking
+        self.votes.commit(
+            &motion_id,
+            hash,
+            env::predecessor_account_id(),
+            role,
+            weight,
+        );
+    }
+
+    fn reveal_choice_motion(&mut self, motion_id: MotionId, ranking: Vec<VoteId>, salt: String) {
+        let guild_id = self
+            .guilds
+            .choice_map
+            .get(&motion_id)
+            .expect("missing choice motion");
+        self.guilds.assert_unlocked(&guild_id);
+        let sender = env::predecessor_account_id();
+
+        // Committer -> reveal > the ranking, the voice counts with its committed role
+        self.votes
+            .reveal(&motion_id, ranking, &salt, sender.clone());
+        self.cast_proxies(Some(&guild_id), &motion_id, &sender);
+    }
+}
+
+impl GuildDelegation for GuildContract {
+    fn delegate_vote(&mut self, guild_id: Option<GuildId>, delegate_id: AccountId) {
+        // Member -> delegates > its voice to another member
+        self.guilds.delegate(
+            &env::predecessor_account_id(),
+            guild_id.as_ref(),
+            &delegate_id,
+        );
+    }
+
+    fn revoke_delegation(&mut self, guild_id: Option<GuildId>) {
+        // Member -> revokes > its delegation
+        self.guilds
+            .revoke(&env::predecessor_account_id(), guild_id.as_ref());
+    }
+}
+
+impl MotionBallot for GuildContract {
+    fn unvote_motion(&mut self, motion_id: MotionId) {
+        self.assert_ballot_unlocked(&motion_id);
+
+        // Voter -> retracts > its vote from an open motion
+        self.votes.unvote(&motion_id, env::predecessor_account_id());
+    }
+
+    fn revote_motion(&mut self, motion_id: MotionId, vote_id: VoteId) {
+        self.assert_ballot_unlocked(&motion_id);
+
+        // Voter -> moves > its vote to another option of an open motion
+        let voices = self
+            .votes
+            .revote(&motion_id, vote_id, env::predecessor_account_id());
+        self.recheck_motion(&motion_id, &voices);
+    }
+}
+
+impl MotionFinalize for GuildContract {
+    fn finalize_motion(&mut self, motion_id: MotionId) -> MotionResult {
+        let storage_usage = env::storage_usage();
+        let mut state = self.guilds.state.get().expect("missing guilds state");
+
+        // Note: Motions that hold a lock or a reservation are closed through their own calls
+        require!(
+            state.vote.as_ref() != Some(&motion_id),
+            "The motion is still active in the contract state"
+        );
+        if let Some(spend) = self.guilds.spend_map.get(&motion_id) {
+            require!(
+                spend.status != SpendStatus::Voting && spend.status != SpendStatus::Failed,
+                "The spend has to be released first"
+            );
+            require!(
+                spend.status != SpendStatus::Paying,
+                "The spend transfer is still pending"
+            );
+        }
+        if let Some(proposal) = self.guilds.proposal_map.get(&motion_id) {
+            require!(
+                proposal.status != ProposalStatus::Approved
+                    && proposal.status != ProposalStatus::Executing,
+                "The proposal has to be executed first"
+            );
+        }
+        // Note: Only the guild of the motion can hold it
+        let motion = self
+            .votes
+            .motion_map
+            .get(&motion_id)
+            .expect("missing motion");
+        // An approved creation can register its guild until the motion expires
+        if let Some(create) = self.guilds.create_map.get(&motion_id) {
+            require!(
+                !create.approved || motion.info.expires_at <= Timestamp::now(),
+                "The guild has to be registered first"
+            );
+        }
+        let snapshot = self.votes.get_snapshot(&motion_id);
+        let guild_id = snapshot.scope;
+        let mut guild = self.guilds.guild_map.get(&guild_id).expect("missing guild");
+        require!(
+            guild.vote.as_ref() != Some(&motion_id),
+            "The motion is still active in the guild state"
+        );
+        require!(
+            guild.election.as_ref().map(|election| &election.id) != Some(&motion_id),
+            "The board election has to be closed first"
+        );
+        // An expired guild motion frees the guild for a new one
+        if guild.motion.as_ref().map(|motion| &motion.id) == Some(&motion_id) {
+            guild.motion = None;
+            self.guilds.set_guild(&guild_id, &guild);
+        }
+
+        let result = self.votes.finalize(&motion_id);
+        self.guilds.release(snapshot.epoch);
+        self.guilds.choice_map.remove(&motion_id);
+        // A closed creation can no longer register its guild
+        self.guilds.create_map.remove(&motion_id);
+        // An expired proposal releases the reservation of its deposit
+        if let Some(mut proposal) = self.guilds.proposal_map.get(&motion_id) {
+            if proposal.status == ProposalStatus::Voting {
+                self.guilds.locked_amount -= proposal.action.deposit.0;
+                proposal.status = ProposalStatus::Expired;
+                self.guilds.proposal_map.insert(&motion_id, &proposal);
+            }
+        }
+        // A returned bond goes back to the issuer, a kept bond stays in the treasury
+        if let Some(bond) = &result.bond {
+            self.guilds.bond_amount -= bond.amount.0;
+            if bond.status == BondStatus::Returned {
+                Promise::new(result.issuer.clone()).transfer(bond.amount.0);
+            }
+        }
+        if state.policy.as_ref().map(|policy| &policy.id) == Some(&motion_id) {
+            state.policy = None;
+            self.guilds.set_state(&state);
+        }
+        if state.change.as_ref().map(|change| &change.id) == Some(&motion_id) {
+            state.change = None;
+            self.guilds.set_state(&state);
+        }
+
+        // Anyone -> finalize > the storage the issuer prepaid is refunded as it is released
+        let released = storage_usage.saturating_sub(env::storage_usage());
+        self.guilds.storage_amount -= motion.storage;
+        let amount = (env::storage_byte_cost() * Balance::from(released)).min(motion.storage);
+        if amount > 0 {
+            Promise::new(result.issuer.clone()).transfer(amount);
+        }
+        result
+    }
+
+    fn set_refund_policy_contract(&mut self, refund: bool) {
+        let mut state = self.guilds.state.get().expect("missing guilds state");
+        require!(state.lock == LockedFor::None, "contract is not unlocked");
+
+        // CEO -> sets > if finalized motions refund their issuer
+        let exec = self
+            .guilds
+            .guild_map
+            .get(&state.exec)
+            .expect("missing executive guild");
+        require!(
+            exec.info.ceo_id == env::predecessor_account_id(),
+            "unauthorized call"
+        );
+
+        state.refund = refund;
+        self.guilds.set_state(&state);
+    }
+}
+
+impl GuildIncome for GuildContract {
+    fn deposit_guild_income(&mut self, guild_id: GuildId) {
+        let amount = env::attached_deposit();
+        require!(amount > 0, "Requires an attached deposit");
+        self.guilds.assert_unlocked(&guild_id);
+
+        // Anyone -> deposit > split by the guild shares
+        self.guilds.distribute(&guild_id, amount);
+    }
+
+    fn claim_guild_income(&mut self, guild_id: GuildId) -> Promise {
+        // Note: A locked guild does not pay out
+        self.guilds.assert_unlocked(&guild_id);
+        let sender = env::predecessor_account_id();
+        let key = (guild_id.clone(), sender.clone());
+        let amount = self.guilds.income_map.get(&key).unwrap_or(0);
+        require!(amount > 0, "no income to claim");
+
+        // Member -> claim > the accrued balance
+        self.guilds.income_map.remove(&key);
+        self.guilds.income_amount -= amount;
+
+        Promise::new(sender.clone()).transfer(amount).then(
+            Promise::new(env::current_account_id()).function_call(
+                "resolve_claim_guild_income".to_string(),
+                serde_json::json!({
+                    "guild_id": guild_id,
+                    "account_id": sender,
+                    "amount": U128(amount),
+                })
+                .to_string()
+                .into_bytes(),
+                0,
+                GAS_FOR_RESOLVE_CLAIM,
+            ),
+        )
+    }
+
+    fn resolve_claim_guild_income(
+        &mut self,
+        guild_id: GuildId,
+        account_id: AccountId,
+        amount: U128,
+    ) -> bool {
+        require!(
+            env::predecessor_account_id() == env::current_account_id(),
+            "Method resolve_claim_guild_income is private"
+        );
+
+        // Restore the balance when the transfer failed
+        let succeeded = matches!(env::promise_result(0), PromiseResult::Successful(_));
+        if !succeeded {
+            let key = (guild_id, account_id);
+            let balance = self.guilds.income_map.get(&key).unwrap_or(0);
+            self.guilds.income_map.insert(&key, &(balance + amount.0));
+            self.guilds.income_amount += amount.0;
+        }
+        succeeded
+    }
+}
+
+impl GuildRegistry for GuildContract {
+    fn arc_create_guild(
+        &mut self,
+        guild_id: GuildId,
+        guild: GuildInfo,
+        board_map: HashMap<AccountId, u16>,
+        member_map: HashMap<AccountId, U128>,
+        motion_id: Option<MotionId>,
+    ) {
+        require_min_one_yocto();
+        let storage_usage = env::storage_usage();
+        let state = self.guilds.state.get().expect("missing guilds state");
+        require!(state.lock == LockedFor::None, "contract is not unlocked");
+
+        if state.create == CreatePolicy::Motion {
+            // Creator -> registers > the guild approved by the motion
+            let id = motion_id.expect("Guild creation requires an approved motion");
+            let create = self
+                .guilds
+                .create_map
+                .get(&id)
+                .expect("missing guild creation");
+            require!(create.approved, "guild creation is not approved");
+            require!(
+                create.guild_id == guild_id,
+                "The guild id does not match the approved creation"
+            );
+            require!(
+                create.creator_id == env::predecessor_account_id(),
+                "unauthorized call"
+            );
+            self.guilds.create_map.remove(&id);
+        }
+
+        let member_map = member_map
+            .into_iter()
+            .map(|(account, value)| (account, value.0))
+            .collect();
+        self.guilds
+            .register(&guild_id, &guild, &board_map, &member_map);
+
+        refund_storage_deposit(env::storage_usage() - storage_usage);
+    }
+
+    fn act_create_guild_contract(
+        &mut self,
+        guild_id: GuildId,
+        creator_id: AccountId,
+        details: String,
+    ) {
+        let state = self.guilds.state.get().expect("missing guilds state");
+        require!(state.lock == LockedFor::None, "contract is not unlocked");
+        require!(
+            self.guilds.guild_map.get(&guild_id).is_none(),
+            "The provided guild id is already in use"
+        );
+        let sender = env::predecessor_account_id();
+
+        // CEO | Board -> vote > threshold + ceo | members
+        let exec = self
+            .guilds
+            .guild_map
+            .get(&state.exec)
+            .expect("missing executive guild");
+        let board = self
+            .guilds
+            .board_map
+            .get(&state.exec)
+            .expect("missing executive board");
+        if exec.info.ceo_id == sender || board.list.get(&sender).is_some() {
+            // crate a new threshold motion to approve the creation
+            let id = self.votes.next_id("CG", None);
+            let motion = new_motion_to_create_guild(&details);
+            let storage_usage = env::storage_usage();
+            self.votes.issue(&id, &motion);
+            self.open_motion(MotionKind::CreateGuild, &state.exec, &id, storage_usage);
+
+            let create = GuildCreate {
+                guild_id,
+                creator_id,
+                approved: false,
+            };
+            self.guilds.create_map.insert(&id, &create);
+            return;
+        }
+        // Error -> caller is not authorized
+        env::panic_str("unauthorized call");
+    }
+
+    fn vote_create_guild_contract(&mut self, id: MotionId) {
+        let state = self.guilds.state.get().expect("missing guilds state");
+        require!(state.lock == LockedFor::None, "contract is not unlocked");
+        let mut create = self
+            .guilds
+            .create_map
+            .get(&id)
+            .expect("missing guild creation");
+        let sender = env::predecessor_account_id();
+
+        let snapshot = self.votes.get_snapshot(&id);
+        let count = snapshot.count.clone();
+        let weight = self.guilds.get_weight_at(&snapshot, &sender);
+
+        let voices = if snapshot.is_ceo(&sender) {
+            // CEO -> vote > ceo support
+            self.cast_ceo(Some(&state.exec), &id, get_vote_id_agree(), sender, weight)
+        } else if snapshot.is_board(&sender) {
+            // Board -> vote > threshold + ceo | members
+            self.cast_board(Some(&state.exec), &id, get_vote_id_agree(), sender, weight)
+        } else if self.guilds.is_member_at(&snapshot, &sender) {
+            // Members -> vote > threshold + ceo | board
+            self.cast_member(Some(&state.exec), &id, get_vote_id_agree(), sender, weight)
+        } else {
+            // Error -> caller is not authorized
+            env::panic_str("unauthorized call");
+        };
+
+        // When vote tally is over the threshold > approve the creation
+        if self
+            .votes
+            .exec_policy(MotionKind::CreateGuild, &id, &voices, &count)
+        {
+            create.approved = true;
+            self.guilds.create_map.insert(&id, &create);
+        }
+    }
+}
+
+impl GuildViews for GuildContract {
+    /// Returns the contract lock state, its timeout and the active motions.
+    fn guild_contract_state(&self) -> State {
+        self.guilds.state.get().expect("missing guilds state")
+    }
+
+    fn guild_metadata(&self, guild_id: GuildId) -> Option<GuildMetadata> {
+        self.guilds.metadata_map.get(&guild_id)
+    }
+
+    fn guild_info(&self, guild_id: GuildId) -> Option<GuildInfo> {
+        self.guilds.guild_map.get(&guild_id).map(|guild| guild.info)
+    }
+
+    fn guild_list(
+        &self,
+        from_index: Option<U128>,
+        limit: Option<u64>,
+    ) -> Vec<(GuildId, GuildInfo)> {
+        let start = u128::from(from_index.unwrap_or(U128(0)));
+        self.guilds
+            .guild_map
+            .iter()
+            .skip(start as usize)
+            .take(limit.unwrap_or(50) as usize)
+            .map(|(id, guild)| (id, guild.info))
+            .collect()
+    }
+
+    fn guild_members(
+        &self,
+        guild_id: GuildId,
+        from_index: Option<U128>,
+        limit: Option<u64>,
+    ) -> Vec<(AccountId, U128)> {
+        let start = u128::from(from_index.unwrap_or(U128(0)));
+        self.guilds
+            .member_map
+            .get(&guild_id)
+            .map_or(vec![], |members| {
+                members
+                    .list
+                    .iter()
+                    .skip(start as usize)
+                    .take(limit.unwrap_or(50) as usize)
+                    .map(|(account, value)| (account, U128(value)))
+                    .collect()
+            })
+    }
+
+    fn guild_board(
+        &self,
+        guild_id: GuildId,
+        from_index: Option<U128>,
+        limit: Option<u64>,
+    ) -> Vec<(AccountId, u16)> {
+        let start = u128::from(from_index.unwrap_or(U128(0)));
+        self.guilds
+            .board_map
+            .get(&guild_id)
+            .map_or(vec![], |board| {
+                board
+                    .list
+                    .iter()
+                    .skip(start as usize)
+                    .take(limit.unwrap_or(50) as usize)
+                    .collect()
+            })
+    }
+
+    fn guild_member_set(
+        &self,
+        account_id: AccountId,
+        from_index: Option<U128>,
+        limit: Option<u64>,
+    ) -> Option<JsonMemberSet> {
+        let start = u128::from(from_index.unwrap_or(U128(0)));
+        let member_set = self.guilds.account_map.get(&account_id)?;
+        Some(JsonMemberSet {
+            value: U128(member_set.value),
+            guilds: member_set
+                .store
+                .iter()
+                .skip(start as usize)
+                .take(limit.unwrap_or(50) as usize)
+                .collect(),
+        })
+    }
+
+    /// Returns the latest motion issued for the guild.
+    fn guild_latest_motion(&self, guild_id: GuildId) -> Option<MotionId> {
+        self.votes.latest_map.get(&guild_id)
+    }
+
+    /// Returns the motions of the guild that are still open for votes, none for an unknown guild.
+    fn guild_active_motions(&self, guild_id: GuildId) -> Vec<MotionId> {
+        let guild = match self.guilds.guild_map.get(&guild_id) {
+            Some(guild) => guild,
+            None => return vec![],
+        };
+        vec![
+            guild.vote,
+            guild.motion.map(|motion| motion.id),
+            guild.election.map(|election| election.id),
+        ]
+        .into_iter()
+        .flatten()
+        .filter(|id| {
+            self.votes
+                .motion_map
+                .get(id)
+                .is_some_and(|motion| !motion.executed && motion.info.expires_at > Timestamp::now())
+        })
+        .collect()
+    }
+
+    fn guild_motion(&self, motion_id: MotionId) -> Option<JsonMotion> {
+        self.votes.get_motion(&motion_id)
+    }
+
+    fn guild_motions(
+        &self,
+        status: Option<MotionStatus>,
+        from_index: Option<U128>,
+        limit: Option<u64>,
+    ) -> Vec<JsonMotion> {
+        self.votes.get_motions(status, from_index, limit)
+    }
+
+    fn guild_motion_vote(&self, motion_id: MotionId, account_id: AccountId) -> Option<VoteId> {
+        self.votes.get_vote(&motion_id, &account_id)
+    }
+
+    fn guild_motion_result(&self, motion_id: MotionId) -> Option<MotionResult> {
+        self.votes.get_result(&motion_id)
+    }
+
+    fn guild_queued_motions(&self) -> Vec<(MotionId, QueuedMotion)> {
+        self.guilds.queue_map.iter().collect()
+    }
+
+    fn guild_treasury_locked(&self) -> U128 {
+        U128(self.guilds.locked_amount)
+    }
+
+    fn guild_income_balance(&self, guild_id: GuildId, account_id: AccountId) -> U128 {
+        U128(
+            self.guilds
+                .income_map
+                .get(&(guild_id, account_id))
+                .unwrap_or(0),
+        )
+    }
+
+    fn guild_delegation(&self, account_id: AccountId) -> Option<Delegation> {
+        self.guilds.delegate_map.get(&account_id)
+    }
+
+    fn guild_delegators(&self, account_id: AccountId) -> Vec<AccountId> {
+        self.guilds
+            .delegator_map
+            .get(&account_id)
+            .unwrap_or_default()
+    }
+
+    fn guild_treasury_spend(&self, motion_id: MotionId) -> Option<Spend> {
+        self.guilds.spend_map.get(&motion_id)
+    }
+
+    fn guild_treasury_spends(
+        &self,
+        from_index: Option<U128>,
+        limit: Option<u64>,
+    ) -> Vec<(MotionId, Spend)> {
+        let start = u128::from(from_index.unwrap_or(U128(0)));
+        self.guilds
+            .spend_map
+            .iter()
+            .skip(start as usize)
+            .take(limit.unwrap_or(50) as usize)
+            .collect()
+    }
+}
 
 #[cfg(test)]
 mod tests {
     mod api;
+    mod ballot;
+    mod bond;
+    mod challenge;
+    mod choice;
+    mod create;
     mod data;
+    mod delegation;
+    mod election;
+    mod events;
+    mod finalize;
+    mod guild;
+    mod income;
     mod locks;
+    mod members;
+    mod motions;
+    mod policy;
+    mod proposal;
+    mod queue;
+    mod snapshot;
+    mod treasury;
+    mod views;
 }
@@ -0,0 +1,134 @@
+use crate::guild::*;
+
+use near_sdk::test_utils::VMContextBuilder;
+use near_sdk::{testing_env, RuntimeFeesConfig, VMConfig};
+
+#[macro_export]
+macro_rules! get_account_alice {
+    () => {
+        "alice.near".parse::<AccountId>().unwrap()
+    };
+}
+#[macro_export]
+macro_rules! get_account_bob {
+    () => {
+        "bob.near".parse::<AccountId>().unwrap()
+    };
+}
+#[macro_export]
+macro_rules! get_account_eve {
+    () => {
+        "eve.near".parse::<AccountId>().unwrap()
+    };
+}
+#[macro_export]
+macro_rules! get_account_guild {
+    () => {
+        "guild.near".parse::<AccountId>().unwrap()
+    };
+}
+#[macro_export]
+macro_rules! get_account_nitya {
+    () => {
+        "nitya.near".parse::<AccountId>().unwrap()
+    };
+}
+#[macro_export]
+macro_rules! get_account_nodra {
+    () => {
+        "nodra.near".parse::<AccountId>().unwrap()
+    };
+}
+#[macro_export]
+macro_rules! get_account_vecno {
+    () => {
+        "vecno.near".parse::<AccountId>().unwrap()
+    };
+}
+#[macro_export]
+macro_rules! get_guild_id {
+    () => {
+        "G:01".parse::<GuildId>().unwrap()
+    };
+}
+
+// ==== Context ====
+
+fn get_context(account: AccountId) -> VMContextBuilder {
+    let mut builder = VMContextBuilder::new();
+    builder
+        .current_account_id(get_account_guild!())
+        .predecessor_account_id(account);
+    builder
+}
+
+pub fn set_caller(account: AccountId) {
+    set_caller_at(account, 0);
+}
+
+pub fn set_caller_at(account: AccountId, timestamp: u64) {
+    let context = get_context(account).block_timestamp(timestamp).build();
+    testing_env!(context);
+}
+
+pub fn set_deposit(account: AccountId, deposit: Balance) {
+    let context = get_context(account).attached_deposit(deposit).build();
+    testing_env!(context);
+}
+
+/// Moves the block time past the expiry of the contract and guild motions.
+pub fn set_expired() {
+    set_caller_at(get_account_guild!(), EXPIRE_48H.as_nanos());
+}
+
+/// Sets the result of the promise resolved by the next contract callback.
+pub fn set_result(result: PromiseResult) {
+    let context = get_context(get_account_guild!()).build();
+    testing_env!(
+        context,
+        VMConfig::test(),
+        RuntimeFeesConfig::test(),
+        Default::default(),
+        vec![result]
+    );
+}
+
+// ==== Contract ====
+
+pub fn get_guild_info(board_size: u64, members_size: u64) -> GuildInfo {
+    GuildInfo {
+        ceo_id: get_account_vecno!(),
+        ceo_share: 1000,
+        board_size,
+        board_share: 4000,
+        members_size,
+        members_share: 5000,
+    }
+}
+
+/// Registers the "G:01" guild with vecno as CEO, nitya and nodra on the
+/// board and bob, nitya, nodra and vecno as members.
+pub fn get_contract() -> GuildContract {
+    get_contract_with(
+        &get_guild_info(2, 5),
+        &[(get_account_nitya!(), 5000), (get_account_nodra!(), 5000)],
+        &[
+            get_account_bob!(),
+            get_account_nitya!(),
+            get_account_nodra!(),
+            get_account_vecno!(),
+        ],
+    )
+}
+
+/// Registers the "G:01" guild with the given board shares and members, each
+/// member holding a stake of 100.
+pub fn get_contract_with(
+    guild: &GuildInfo,
+    board: &[(AccountId, u16)],
+    members: &[AccountId],
+) -> GuildContract {
+    let board_map: HashMap<AccountId, u16> = board.iter().cloned().collect();
+    let member_map: HashMap<AccountId, u128> = members.iter().map(|id| (id.clone(), 100)).collect();
+    GuildContract::new(&get_guild_id!(), guild, &board_map, &member_map)
+}
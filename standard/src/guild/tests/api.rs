@@ -52,11 +52,11 @@ fn guilds_register() {
     let guild = &get_guild_vecno!();
 
     let mut member_map = HashMap::new();
-    member_map.insert(nodra.clone(), 123000321 as u128);
-    member_map.insert(vecno.clone(), 321000123 as u128);
+    member_map.insert(nodra.clone(), 123000321_u128);
+    member_map.insert(vecno.clone(), 321000123_u128);
 
     let mut board_map = HashMap::new();
-    board_map.insert(nodra.clone(), 5050 as u16);
+    board_map.insert(nodra.clone(), 5050_u16);
 
     let mut data = Guilds::new(id);
     data.register(id, guild, &board_map, &member_map);
@@ -123,6 +123,39 @@ fn guilds_register() {
     );
 }
 
+#[test]
+fn guilds_get_count() {
+    let ida = &"G:01".to_string().into();
+    let idb = &"G:02".to_string().into();
+    let nitya = get_account_nitya!();
+    let nodra = get_account_nodra!();
+    let vecno = get_account_vecno!();
+
+    let mut member_map = HashMap::new();
+    member_map.insert(nodra.clone(), 300_u128);
+    member_map.insert(vecno.clone(), 100_u128);
+    let mut board_map = HashMap::new();
+    board_map.insert(nodra.clone(), 5000_u16);
+
+    let mut data = Guilds::new(ida);
+    data.register(ida, &get_guild_vecno!(), &board_map, &member_map);
+
+    let mut member_map = HashMap::new();
+    member_map.insert(nitya.clone(), 50_u128);
+    member_map.insert(vecno.clone(), 20_u128);
+    data.register(idb, &get_guild_vecno!(), &HashMap::new(), &member_map);
+
+    let count = data.get_count(idb);
+    assert_eq!(0, count.board);
+    assert_eq!(2, count.members);
+    assert_eq!(70, count.value);
+
+    assert_eq!(20, data.get_weight(idb, &vecno));
+    assert_eq!(0, data.get_weight(idb, &nodra));
+    assert_eq!(120, data.get_contract_weight(&vecno));
+    assert_eq!(0, data.get_contract_weight(&"other.near".parse().unwrap()));
+}
+
 #[test]
 #[should_panic(expected = "Need at least one guild members or more")]
 fn guilds_register_member_min() {
@@ -132,10 +165,10 @@ fn guilds_register_member_min() {
 
     let member_map = HashMap::new();
     let mut board_map = HashMap::new();
-    board_map.insert(nodra.clone(), 5050 as u16);
+    board_map.insert(nodra.clone(), 5050_u16);
 
     let mut data = Guilds::new(id);
-    data.register(id, &guild, &board_map, &member_map);
+    data.register(id, guild, &board_map, &member_map);
 }
 
 #[test]
@@ -150,10 +183,10 @@ fn guilds_register_board_max() {
     guild.members_size = 1;
 
     let mut member_map = HashMap::new();
-    member_map.insert(vecno.clone(), 123000321 as u128);
+    member_map.insert(vecno.clone(), 123000321_u128);
 
     let mut board_map = HashMap::new();
-    board_map.insert(nodra.clone(), 5050 as u16);
+    board_map.insert(nodra.clone(), 5050_u16);
 
     let mut data = Guilds::new(id);
     data.register(id, &guild, &board_map, &member_map);
@@ -171,8 +204,8 @@ fn guilds_register_members_max() {
     guild.members_size = 1;
 
     let mut member_map = HashMap::new();
-    member_map.insert(nodra.clone(), 123000321 as u128);
-    member_map.insert(vecno.clone(), 321000123 as u128);
+    member_map.insert(nodra.clone(), 123000321_u128);
+    member_map.insert(vecno.clone(), 321000123_u128);
 
     let board_map = HashMap::new();
 
@@ -189,11 +222,11 @@ fn guilds_register_member_ceo() {
     let guild = &get_guild_vecno!();
 
     let mut member_map = HashMap::new();
-    member_map.insert(nodra.clone(), 123000321 as u128);
-    member_map.insert(vecno.clone(), 321000123 as u128);
+    member_map.insert(nodra.clone(), 123000321_u128);
+    member_map.insert(vecno.clone(), 321000123_u128);
 
     let mut board_map = HashMap::new();
-    board_map.insert(vecno.clone(), 5050 as u16);
+    board_map.insert(vecno.clone(), 5050_u16);
 
     let mut data = Guilds::new(id);
     data.register(id, guild, &board_map, &member_map);
@@ -207,10 +240,10 @@ fn guilds_register_board_ceo() {
     let guild = &get_guild_vecno!();
 
     let mut member_map = HashMap::new();
-    member_map.insert(nodra.clone(), 123000321 as u128);
+    member_map.insert(nodra.clone(), 123000321_u128);
 
     let mut board_map = HashMap::new();
-    board_map.insert(nodra.clone(), 5050 as u16);
+    board_map.insert(nodra.clone(), 5050_u16);
 
     let mut data = Guilds::new(id);
     data.register(id, guild, &board_map, &member_map);
@@ -225,11 +258,11 @@ fn guilds_register_guild_id() {
     let guild = &get_guild_vecno!();
 
     let mut member_map = HashMap::new();
-    member_map.insert(nodra.clone(), 123000321 as u128);
-    member_map.insert(vecno.clone(), 321000123 as u128);
+    member_map.insert(nodra.clone(), 123000321_u128);
+    member_map.insert(vecno.clone(), 321000123_u128);
 
     let mut board_map = HashMap::new();
-    board_map.insert(nodra.clone(), 5050 as u16);
+    board_map.insert(nodra.clone(), 5050_u16);
 
     let mut data = Guilds::new(id);
     data.register(id, guild, &board_map, &member_map);
@@ -245,10 +278,10 @@ fn guilds_register_board_member() {
     let guild = &get_guild_vecno!();
 
     let mut member_map = HashMap::new();
-    member_map.insert(vecno.clone(), 321000123 as u128);
+    member_map.insert(vecno.clone(), 321000123_u128);
 
     let mut board_map = HashMap::new();
-    board_map.insert(nodra.clone(), 5050 as u16);
+    board_map.insert(nodra.clone(), 5050_u16);
 
     let mut data = Guilds::new(id);
     data.register(id, guild, &board_map, &member_map);
@@ -267,13 +300,13 @@ fn guilds_register_total_points() {
     guild.members_size = 3;
 
     let mut member_map = HashMap::new();
-    member_map.insert(nitya.clone(), 123000321 as u128);
-    member_map.insert(nodra.clone(), 321000123 as u128);
-    member_map.insert(vecno.clone(), 321000123 as u128);
+    member_map.insert(nitya.clone(), 123000321_u128);
+    member_map.insert(nodra.clone(), 321000123_u128);
+    member_map.insert(vecno.clone(), 321000123_u128);
 
     let mut board_map = HashMap::new();
-    board_map.insert(nitya.clone(), 5000 as u16);
-    board_map.insert(nodra.clone(), 5001 as u16);
+    board_map.insert(nitya.clone(), 5000_u16);
+    board_map.insert(nodra.clone(), 5001_u16);
 
     let mut data = Guilds::new(id);
     data.register(id, &guild, &board_map, &member_map);
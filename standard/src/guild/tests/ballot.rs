@@ -0,0 +1,183 @@
+use crate::guild::*;
+
+use crate::guild::test_utils::*;
+
+/// Seats nodra alone on the board with alice, bob, nodra and vecno as members.
+fn get_contract_nodra() -> GuildContract {
+    get_contract_with(
+        &get_guild_info(2, 5),
+        &[(get_account_nodra!(), 5000)],
+        &[
+            get_account_alice!(),
+            get_account_bob!(),
+            get_account_nodra!(),
+            get_account_vecno!(),
+        ],
+    )
+}
+
+// ==== unvote_motion ====
+
+#[test]
+fn unvote_motion() {
+    let mut contract = get_contract_nodra();
+    set_caller(get_account_nodra!());
+    contract.act_add_guild_member(
+        get_guild_id!(),
+        "eve.near".parse().unwrap(),
+        U128(50),
+        "add".to_string(),
+    );
+    contract.vote_guild_motion(get_guild_id!());
+
+    let id: MotionId = "GM:1".parse().unwrap();
+    contract.unvote_motion(id.clone());
+    assert!(contract
+        .guild_motion_vote(id.clone(), get_account_nodra!())
+        .is_none());
+    let motion = contract.guild_motion(id.clone()).unwrap();
+    let tally = motion.tally.get(&get_vote_id_agree()).unwrap();
+    assert_eq!(0, tally.board);
+    assert_eq!(U128(0), tally.weight);
+
+    // Note: The voice can be cast again on the open motion
+    contract.vote_guild_motion(get_guild_id!());
+    assert_eq!(
+        Some(get_vote_id_agree()),
+        contract.guild_motion_vote(id, get_account_nodra!())
+    );
+}
+
+#[test]
+#[should_panic(expected = "The account has not voted on the motion")]
+fn unvote_motion_not_voted() {
+    let mut contract = get_contract_nodra();
+    set_caller(get_account_nodra!());
+    contract.act_add_guild_member(
+        get_guild_id!(),
+        "eve.near".parse().unwrap(),
+        U128(50),
+        "add".to_string(),
+    );
+    set_caller(get_account_bob!());
+    contract.unvote_motion("GM:1".parse().unwrap());
+}
+
+#[test]
+#[should_panic(expected = "The guild is locked")]
+fn unvote_motion_locked() {
+    let mut contract = get_contract_nodra();
+    set_caller(get_account_nodra!());
+    contract.act_add_guild_member(
+        get_guild_id!(),
+        "eve.near".parse().unwrap(),
+        U128(50),
+        "add".to_string(),
+    );
+    contract.vote_guild_motion(get_guild_id!());
+    set_caller(get_account_vecno!());
+    contract.act_lock_guild(get_guild_id!(), None);
+
+    set_caller(get_account_nodra!());
+    contract.unvote_motion("GM:1".parse().unwrap());
+}
+
+// ==== revote_motion ====
+
+#[test]
+fn revote_motion_election() {
+    let mut contract = get_contract_nodra();
+    set_caller(get_account_vecno!());
+    contract.act_board_election(
+        get_guild_id!(),
+        1,
+        5000,
+        vec![get_account_bob!(), get_account_alice!()],
+        "election".to_string(),
+    );
+    let id: MotionId = "BE:1".parse().unwrap();
+
+    set_caller(get_account_bob!());
+    contract.vote_board_election(get_guild_id!(), get_account_alice!());
+    contract.revote_motion(id.clone(), get_vote_id_option(0));
+    assert_eq!(
+        Some(get_vote_id_option(0)),
+        contract.guild_motion_vote(id.clone(), get_account_bob!())
+    );
+
+    let motion = contract.guild_motion(id).unwrap();
+    assert_eq!(1, motion.tally.get(&get_vote_id_option(0)).unwrap().members);
+    assert_eq!(0, motion.tally.get(&get_vote_id_option(1)).unwrap().members);
+}
+
+#[test]
+#[should_panic(expected = "missing motion vote")]
+fn revote_motion_unknown_option() {
+    let mut contract = get_contract_nodra();
+    set_caller(get_account_nodra!());
+    contract.act_add_guild_member(
+        get_guild_id!(),
+        "eve.near".parse().unwrap(),
+        U128(50),
+        "add".to_string(),
+    );
+    contract.vote_guild_motion(get_guild_id!());
+    contract.revote_motion("GM:1".parse().unwrap(), get_vote_id_no());
+}
+
+#[test]
+#[should_panic(expected = "The guild is locked")]
+fn revote_motion_locked() {
+    let mut contract = get_contract_nodra();
+    set_caller(get_account_nodra!());
+    contract.act_add_guild_member(
+        get_guild_id!(),
+        "eve.near".parse().unwrap(),
+        U128(50),
+        "add".to_string(),
+    );
+    contract.vote_guild_motion(get_guild_id!());
+    set_caller(get_account_vecno!());
+    contract.act_lock_guild(get_guild_id!(), None);
+
+    set_caller(get_account_nodra!());
+    contract.revote_motion("GM:1".parse().unwrap(), get_vote_id_agree());
+}
+
+#[test]
+fn revote_motion_policy() {
+    let mut contract = get_contract_nodra();
+    set_caller(get_account_nodra!());
+    contract.act_add_guild_member(
+        get_guild_id!(),
+        "eve.near".parse().unwrap(),
+        U128(50),
+        "add".to_string(),
+    );
+    contract.vote_guild_motion(get_guild_id!());
+    assert!(contract
+        .guilds
+        .guild_map
+        .get(&get_guild_id!())
+        .unwrap()
+        .motion
+        .is_some());
+
+    // Note: The motion passes on the revote once the policy is lowered
+    let policy = VotePolicy {
+        threshold: 2000,
+        roles: vec![VoteRoles {
+            ceo: false,
+            board: true,
+            members: true,
+        }],
+        ..contract.votes.get_policy(MotionKind::ChangeGuild)
+    };
+    contract.votes.set_policy(MotionKind::ChangeGuild, &policy);
+    contract.revote_motion("GM:1".parse().unwrap(), get_vote_id_agree());
+
+    let guild = contract.guilds.guild_map.get(&get_guild_id!()).unwrap();
+    assert!(guild.motion.is_none());
+    let members = contract.guilds.member_map.get(&get_guild_id!()).unwrap();
+    assert_eq!(Some(50), members.list.get(&"eve.near".parse().unwrap()));
+}
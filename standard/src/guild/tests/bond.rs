@@ -0,0 +1,158 @@
+use crate::guild::*;
+
+use crate::guild::test_utils::*;
+use near_sdk::test_utils::get_created_receipts;
+use near_sdk::{Balance, ONE_NEAR};
+
+macro_rules! spend_id {
+    () => {
+        "SC:1".parse::<MotionId>().unwrap()
+    };
+}
+
+const BOND: Balance = ONE_NEAR;
+
+fn get_contract_bonded(quorum: u16) -> GuildContract {
+    let mut contract = get_contract();
+    for kind in [MotionKind::SpendContract, MotionKind::ChangeGuild] {
+        let policy = VotePolicy {
+            quorum,
+            bond: Some(U128(BOND)),
+            ..get_default_policy(kind)
+        };
+        contract.votes.set_policy(kind, &policy);
+    }
+    contract
+}
+
+fn open_guild_motion(contract: &mut GuildContract) -> MotionId {
+    set_deposit(get_account_nodra!(), BOND);
+    contract.act_add_guild_member(
+        get_guild_id!(),
+        get_account_alice!(),
+        U128(50),
+        "add".to_string(),
+    );
+    set_deposit(get_account_nodra!(), 0);
+    contract.vote_guild_motion(get_guild_id!());
+    contract.guild_latest_motion(get_guild_id!()).unwrap()
+}
+
+// ==== motion bonds ====
+
+#[test]
+fn bond_held() {
+    let mut contract = get_contract_bonded(0);
+    set_deposit(get_account_nodra!(), 0);
+    let available = contract.get_available_amount();
+    let id = open_guild_motion(&mut contract);
+
+    let motion = contract.guild_motion(id).unwrap();
+    let bond = motion.bond.unwrap();
+    assert_eq!((U128(BOND), 1), (bond.amount, bond.quorum));
+    assert_eq!(BondStatus::Held, bond.status);
+    assert_eq!(BOND, contract.guilds.bond_amount);
+    assert!(contract.get_available_amount() <= available - BOND);
+}
+
+#[test]
+fn bond_excess_refund() {
+    let mut contract = get_contract_bonded(0);
+    set_deposit(get_account_nodra!(), 3 * BOND);
+    contract.act_spend_contract(get_account_bob!(), U128(10 * ONE_NEAR), "spend".to_string());
+
+    let receipts = get_created_receipts();
+    assert_eq!(1, receipts.len());
+    assert_eq!(get_account_nodra!(), receipts[0].receiver_id);
+    assert_eq!(BOND, contract.guilds.bond_amount);
+}
+
+#[test]
+fn bond_returned_on_pass() {
+    let mut contract = get_contract_bonded(0);
+    set_deposit(get_account_nodra!(), BOND);
+    contract.act_spend_contract(get_account_bob!(), U128(10 * ONE_NEAR), "spend".to_string());
+    for account in [
+        get_account_vecno!(),
+        get_account_nodra!(),
+        get_account_nitya!(),
+    ] {
+        set_deposit(account, 0);
+        contract.vote_spend_contract(spend_id!());
+    }
+    set_result(PromiseResult::Successful(vec![]));
+    contract.resolve_spend_contract(spend_id!());
+    set_deposit(get_account_bob!(), 0);
+    let result = contract.finalize_motion(spend_id!());
+
+    assert_eq!(BondStatus::Returned, result.bond.unwrap().status);
+    assert_eq!(0, contract.guilds.bond_amount);
+    let receipts = get_created_receipts();
+    assert_eq!(1, receipts.len());
+    assert_eq!(get_account_nodra!(), receipts[0].receiver_id);
+}
+
+#[test]
+fn bond_returned_on_quorum() {
+    let mut contract = get_contract_bonded(5000);
+    let id = open_guild_motion(&mut contract);
+    for account in [get_account_bob!(), get_account_vecno!()] {
+        set_deposit(account, 0);
+        contract.vote_guild_motion(get_guild_id!());
+    }
+    set_expired();
+    let result = contract.finalize_motion(id);
+
+    assert_eq!(MotionStatus::Expired, result.status);
+    let bond = result.bond.unwrap();
+    assert_eq!((2, BondStatus::Returned), (bond.quorum, bond.status));
+    assert_eq!(get_account_nodra!(), get_created_receipts()[0].receiver_id);
+}
+
+#[test]
+fn bond_kept() {
+    let mut contract = get_contract_bonded(5000);
+    let id = open_guild_motion(&mut contract);
+    // Note: The issuer does not count toward the quorum of the bond
+    set_deposit(get_account_bob!(), 0);
+    contract.vote_guild_motion(get_guild_id!());
+    set_expired();
+    let available = contract.get_available_amount();
+    let result = contract.finalize_motion(id);
+
+    assert_eq!(BondStatus::Kept, result.bond.unwrap().status);
+    assert!(get_created_receipts().is_empty());
+    assert_eq!(0, contract.guilds.bond_amount);
+    assert!(contract.get_available_amount() >= available + BOND);
+}
+
+#[test]
+fn bond_unset() {
+    let mut contract = get_contract_bonded(0);
+    set_deposit(get_account_nodra!(), 0);
+    contract.act_lock_guild(get_guild_id!(), Some("lock".to_string()));
+    let id = contract.guild_latest_motion(get_guild_id!()).unwrap();
+    assert!(contract.guild_motion(id).unwrap().bond.is_none());
+    assert_eq!(0, contract.guilds.bond_amount);
+}
+
+#[test]
+fn bond_ceo_lock_refund() {
+    let mut contract = get_contract_bonded(0);
+    // Note: The CEO locks without a motion, the deposit is returned
+    set_deposit(get_account_vecno!(), BOND);
+    contract.act_lock_contract(None, false);
+
+    let receipts = get_created_receipts();
+    assert_eq!(1, receipts.len());
+    assert_eq!(get_account_vecno!(), receipts[0].receiver_id);
+    assert_eq!(0, contract.guilds.bond_amount);
+}
+
+#[test]
+#[should_panic(expected = "The motion requires a bond of 1000000000000000000000000 yoctoNEAR")]
+fn bond_missing() {
+    let mut contract = get_contract_bonded(0);
+    set_deposit(get_account_nodra!(), BOND - 1);
+    contract.act_spend_contract(get_account_bob!(), U128(10 * ONE_NEAR), "spend".to_string());
+}
@@ -0,0 +1,468 @@
+use crate::guild::*;
+
+use crate::guild::test_utils::*;
+
+/// Adds alice to the members of the default guild.
+fn get_contract_alice() -> GuildContract {
+    get_contract_with(
+        &get_guild_info(2, 5),
+        &[(get_account_nitya!(), 5000), (get_account_nodra!(), 5000)],
+        &[
+            get_account_alice!(),
+            get_account_bob!(),
+            get_account_nitya!(),
+            get_account_nodra!(),
+            get_account_vecno!(),
+        ],
+    )
+}
+
+fn get_contract_challenging() -> GuildContract {
+    let mut contract = get_contract_alice();
+    set_caller(get_account_nodra!());
+    contract.act_challenge_contract_exec("challenge".to_string(), false);
+    set_caller(get_account_nitya!());
+    contract.vote_challenge_contract_exec();
+    contract
+}
+
+// ==== act_challenge_contract_exec ====
+
+#[test]
+fn act_challenge_contract_as_board() {
+    let mut contract = get_contract_alice();
+    set_caller(get_account_nodra!());
+    contract.act_challenge_contract_exec("challenge".to_string(), false);
+
+    let state = contract.guilds.state.get().expect("missing state");
+    assert_eq!(LockedFor::Challenge, state.lock);
+    let id = state.vote.expect("missing motion id");
+
+    let voices = contract.votes.voices_map.get(&id).expect("missing voices");
+    let tally = voices
+        .tally
+        .get(&get_vote_id_agree())
+        .expect("missing tally");
+    assert!(!tally.ceo);
+    assert_eq!(1, tally.board);
+    assert_eq!(1, tally.members);
+}
+
+#[test]
+fn act_challenge_contract_as_member() {
+    let mut contract = get_contract_alice();
+    set_caller(get_account_bob!());
+    contract.act_challenge_contract_exec("challenge".to_string(), false);
+
+    let state = contract.guilds.state.get().expect("missing state");
+    assert_eq!(LockedFor::Challenge, state.lock);
+    let id = state.vote.expect("missing motion id");
+
+    let voices = contract.votes.voices_map.get(&id).expect("missing voices");
+    let tally = voices
+        .tally
+        .get(&get_vote_id_agree())
+        .expect("missing tally");
+    assert_eq!(0, tally.board);
+    assert_eq!(1, tally.members);
+}
+
+#[test]
+#[should_panic(expected = "unauthorized call")]
+fn act_challenge_contract_as_ceo() {
+    let mut contract = get_contract_alice();
+    set_caller(get_account_vecno!());
+    contract.act_challenge_contract_exec("challenge".to_string(), false);
+}
+
+#[test]
+#[should_panic(expected = "unauthorized call")]
+fn act_challenge_contract_not_authorized() {
+    let mut contract = get_contract_alice();
+    set_caller("other.near".parse::<AccountId>().unwrap());
+    contract.act_challenge_contract_exec("challenge".to_string(), false);
+}
+
+#[test]
+#[should_panic(expected = "contract is not unlocked")]
+fn act_challenge_contract_active_lock() {
+    let mut contract = get_contract_alice();
+    set_caller(get_account_vecno!());
+    contract.act_lock_contract(None, false);
+    set_caller(get_account_bob!());
+    contract.act_challenge_contract_exec("challenge".to_string(), false);
+}
+
+// ==== vote_challenge_contract_exec ====
+
+#[test]
+fn vote_challenge_contract_as_board() {
+    let contract = get_contract_challenging();
+
+    let state = contract.guilds.state.get().expect("missing state");
+    assert_eq!(LockedFor::Challenging, state.lock);
+    assert!(state.vote.is_none());
+    assert_eq!(
+        Some(Timestamp::from_nanos(EXPIRE_48H.as_nanos())),
+        state.time
+    );
+}
+
+#[test]
+fn vote_challenge_contract_no_tally() {
+    let mut contract = get_contract_alice();
+    set_caller(get_account_nodra!());
+    contract.act_challenge_contract_exec("challenge".to_string(), false);
+    set_caller(get_account_bob!());
+    contract.vote_challenge_contract_exec();
+
+    let state = contract.guilds.state.get().expect("missing state");
+    assert_eq!(LockedFor::Challenge, state.lock);
+    assert!(state.vote.is_some());
+}
+
+#[test]
+#[should_panic(expected = "unauthorized call")]
+fn vote_challenge_contract_as_ceo() {
+    let mut contract = get_contract_alice();
+    set_caller(get_account_nodra!());
+    contract.act_challenge_contract_exec("challenge".to_string(), false);
+    set_caller(get_account_vecno!());
+    contract.vote_challenge_contract_exec();
+}
+
+#[test]
+#[should_panic(expected = "The account has already voted on the motion")]
+fn vote_challenge_contract_twice() {
+    let mut contract = get_contract_alice();
+    set_caller(get_account_nodra!());
+    contract.act_challenge_contract_exec("challenge".to_string(), false);
+    contract.vote_challenge_contract_exec();
+}
+
+#[test]
+#[should_panic(expected = "contract is not challenged")]
+fn vote_challenge_contract_not_challenged() {
+    let mut contract = get_contract_alice();
+    set_caller(get_account_bob!());
+    contract.vote_challenge_contract_exec();
+}
+
+// ==== abort_challenge_contract_exec ====
+
+#[test]
+fn abort_challenge_contract_expired() {
+    let mut contract = get_contract_alice();
+    set_caller(get_account_nodra!());
+    contract.act_challenge_contract_exec("challenge".to_string(), false);
+    let id = contract.guild_contract_state().vote.unwrap();
+
+    set_caller_at(get_account_bob!(), EXPIRE_48H.as_nanos());
+    contract.abort_challenge_contract_exec();
+
+    let state = contract.guilds.state.get().expect("missing state");
+    assert_eq!(LockedFor::None, state.lock);
+    assert!(state.vote.is_none());
+    // Note: The expired motion is released for finalize
+    let result = contract.finalize_motion(id);
+    assert_eq!(MotionStatus::Expired, result.status);
+}
+
+#[test]
+#[should_panic(expected = "motion has not expired")]
+fn abort_challenge_contract_open() {
+    let mut contract = get_contract_alice();
+    set_caller(get_account_nodra!());
+    contract.act_challenge_contract_exec("challenge".to_string(), false);
+    contract.abort_challenge_contract_exec();
+}
+
+#[test]
+fn abort_replace_contract_expired() {
+    let mut contract = get_contract_challenging();
+    set_caller(get_account_nodra!());
+    contract.act_replace_contract_exec(get_account_alice!(), "replace".to_string(), false);
+
+    set_caller_at(get_account_bob!(), EXPIRE_48H.as_nanos());
+    contract.abort_challenge_contract_exec();
+
+    let state = contract.guilds.state.get().expect("missing state");
+    assert_eq!(LockedFor::None, state.lock);
+    assert!(state.vote.is_none());
+    assert!(state.next.is_none());
+}
+
+#[test]
+fn abort_challenging_deadline() {
+    let mut contract = get_contract_challenging();
+    set_caller_at(get_account_bob!(), EXPIRE_48H.as_nanos() + 1);
+    contract.abort_challenge_contract_exec();
+
+    let state = contract.guilds.state.get().expect("missing state");
+    assert_eq!(LockedFor::None, state.lock);
+    assert!(state.time.is_none());
+}
+
+#[test]
+#[should_panic(expected = "The replace deadline has not passed")]
+fn abort_challenging_open() {
+    let mut contract = get_contract_challenging();
+    set_caller(get_account_nitya!());
+    contract.abort_challenge_contract_exec();
+}
+
+// ==== act_replace_contract_exec ====
+
+#[test]
+fn act_replace_contract_as_board() {
+    let mut contract = get_contract_challenging();
+    set_caller(get_account_nodra!());
+    contract.act_replace_contract_exec(get_account_alice!(), "replace".to_string(), false);
+
+    let state = contract.guilds.state.get().expect("missing state");
+    assert_eq!(LockedFor::Challenging, state.lock);
+    assert!(state.vote.is_some());
+    assert_eq!(Some(get_account_alice!()), state.next);
+}
+
+#[test]
+#[should_panic(expected = "unauthorized call")]
+fn act_replace_contract_as_member() {
+    let mut contract = get_contract_challenging();
+    set_caller(get_account_bob!());
+    contract.act_replace_contract_exec(get_account_alice!(), "replace".to_string(), false);
+}
+
+#[test]
+#[should_panic(expected = "The CEO can not be a board member")]
+fn act_replace_contract_board_ceo() {
+    let mut contract = get_contract_challenging();
+    set_caller(get_account_nodra!());
+    contract.act_replace_contract_exec(get_account_nitya!(), "replace".to_string(), false);
+}
+
+#[test]
+#[should_panic(expected = "The CEO must be a guild member")]
+fn act_replace_contract_member_ceo() {
+    let mut contract = get_contract_challenging();
+    set_caller(get_account_nodra!());
+    contract.act_replace_contract_exec(
+        "other.near".parse::<AccountId>().unwrap(),
+        "replace".to_string(),
+        false,
+    );
+}
+
+#[test]
+#[should_panic(expected = "The replace deadline has passed")]
+fn act_replace_contract_deadline() {
+    let mut contract = get_contract_challenging();
+    set_caller_at(get_account_nodra!(), EXPIRE_48H.as_nanos() + 1);
+    contract.act_replace_contract_exec(get_account_alice!(), "replace".to_string(), false);
+}
+
+#[test]
+#[should_panic(expected = "contract is not challenging")]
+fn act_replace_contract_not_challenging() {
+    let mut contract = get_contract_alice();
+    set_caller(get_account_nodra!());
+    contract.act_replace_contract_exec(get_account_alice!(), "replace".to_string(), false);
+}
+
+// ==== vote_replace_contract_exec ====
+
+#[test]
+fn vote_replace_contract_as_members() {
+    let mut contract = get_contract_challenging();
+    set_caller(get_account_nodra!());
+    contract.act_replace_contract_exec(get_account_alice!(), "replace".to_string(), false);
+    set_caller(get_account_nitya!());
+    contract.vote_replace_contract_exec();
+    set_caller(get_account_alice!());
+    contract.vote_replace_contract_exec();
+
+    let state = contract.guilds.state.get().expect("missing state");
+    assert_eq!(LockedFor::Challenging, state.lock);
+
+    set_caller(get_account_bob!());
+    contract.vote_replace_contract_exec();
+
+    let state = contract.guilds.state.get().expect("missing state");
+    assert_eq!(LockedFor::None, state.lock);
+    assert!(state.vote.is_none());
+    assert!(state.next.is_none());
+    assert!(state.time.is_some());
+
+    let exec = contract
+        .guilds
+        .guild_map
+        .get(&state.exec)
+        .expect("missing executive guild");
+    assert_eq!(get_account_alice!(), exec.info.ceo_id);
+}
+
+#[test]
+#[should_panic(expected = "unauthorized call")]
+fn vote_replace_contract_as_ceo() {
+    let mut contract = get_contract_challenging();
+    set_caller(get_account_nodra!());
+    contract.act_replace_contract_exec(get_account_alice!(), "replace".to_string(), false);
+    set_caller(get_account_vecno!());
+    contract.vote_replace_contract_exec();
+}
+
+// ==== commit_contract_motion ====
+
+fn commit_agree(contract: &mut GuildContract, account: AccountId) {
+    let hash = get_commitment_hash(&account, &[get_vote_id_agree()], "salt");
+    set_caller(account);
+    contract.commit_contract_motion(hash);
+}
+
+fn reveal_agree(contract: &mut GuildContract, account: AccountId) {
+    let state = contract.guilds.state.get().expect("missing state");
+    let id = state.vote.expect("missing motion id");
+    let motion = contract.votes.motion_map.get(&id).unwrap();
+    set_caller_at(account, motion.info.reveal_at.unwrap().as_nanos());
+    contract.reveal_contract_motion("salt".to_string());
+}
+
+#[test]
+fn act_challenge_contract_sealed() {
+    let mut contract = get_contract_alice();
+    set_caller(get_account_nodra!());
+    contract.act_challenge_contract_exec("challenge".to_string(), true);
+
+    // Note: The sender commits to a sealed motion like any other voter
+    let state = contract.guilds.state.get().expect("missing state");
+    assert_eq!(LockedFor::Challenge, state.lock);
+    let id = state.vote.expect("missing motion id");
+    let motion = contract.votes.motion_map.get(&id).unwrap();
+    assert_eq!(
+        Some(motion.info.starts_at + EXPIRE_24H),
+        motion.info.reveal_at
+    );
+    let voices = contract.votes.voices_map.get(&id).unwrap();
+    assert_eq!(0, voices.tally.get(&get_vote_id_agree()).unwrap().members);
+}
+
+#[test]
+#[should_panic(expected = "The policy does not take sealed votes")]
+fn act_challenge_contract_sealed_policy() {
+    let mut contract = get_contract_alice();
+    let policy = VotePolicy {
+        reveal: Duration::from_nanos(0),
+        ..contract.votes.get_policy(MotionKind::ChallengeContract)
+    };
+    contract
+        .votes
+        .set_policy(MotionKind::ChallengeContract, &policy);
+    set_caller(get_account_nodra!());
+    contract.act_challenge_contract_exec("challenge".to_string(), true);
+}
+
+#[test]
+#[should_panic(expected = "The motion takes sealed votes, commit the vote first")]
+fn vote_challenge_contract_sealed() {
+    let mut contract = get_contract_alice();
+    set_caller(get_account_nodra!());
+    contract.act_challenge_contract_exec("challenge".to_string(), true);
+    set_caller(get_account_nitya!());
+    contract.vote_challenge_contract_exec();
+}
+
+#[test]
+fn reveal_challenge_contract() {
+    let mut contract = get_contract_alice();
+    set_caller(get_account_nodra!());
+    contract.act_challenge_contract_exec("challenge".to_string(), true);
+    commit_agree(&mut contract, get_account_nodra!());
+    commit_agree(&mut contract, get_account_nitya!());
+
+    reveal_agree(&mut contract, get_account_nodra!());
+    let state = contract.guilds.state.get().expect("missing state");
+    assert_eq!(LockedFor::Challenge, state.lock);
+
+    reveal_agree(&mut contract, get_account_nitya!());
+    let state = contract.guilds.state.get().expect("missing state");
+    assert_eq!(LockedFor::Challenging, state.lock);
+    assert!(state.vote.is_none());
+}
+
+#[test]
+#[should_panic(expected = "The reveal phase of the motion has not started")]
+fn reveal_challenge_contract_early() {
+    let mut contract = get_contract_alice();
+    set_caller(get_account_nodra!());
+    contract.act_challenge_contract_exec("challenge".to_string(), true);
+    commit_agree(&mut contract, get_account_nodra!());
+    contract.reveal_contract_motion("salt".to_string());
+}
+
+#[test]
+#[should_panic(expected = "unauthorized call")]
+fn commit_challenge_contract_as_ceo() {
+    let mut contract = get_contract_alice();
+    set_caller(get_account_nodra!());
+    contract.act_challenge_contract_exec("challenge".to_string(), true);
+    commit_agree(&mut contract, get_account_vecno!());
+}
+
+#[test]
+fn reveal_replace_contract() {
+    let mut contract = get_contract_challenging();
+    set_caller(get_account_nodra!());
+    contract.act_replace_contract_exec(get_account_alice!(), "replace".to_string(), true);
+    for account in [
+        get_account_nodra!(),
+        get_account_nitya!(),
+        get_account_alice!(),
+        get_account_bob!(),
+    ] {
+        commit_agree(&mut contract, account);
+    }
+    for account in [
+        get_account_nodra!(),
+        get_account_nitya!(),
+        get_account_alice!(),
+        get_account_bob!(),
+    ] {
+        reveal_agree(&mut contract, account);
+    }
+
+    let state = contract.guilds.state.get().expect("missing state");
+    assert_eq!(LockedFor::None, state.lock);
+    let exec = contract
+        .guilds
+        .guild_map
+        .get(&state.exec)
+        .expect("missing executive guild");
+    assert_eq!(get_account_alice!(), exec.info.ceo_id);
+}
+
+#[test]
+fn reveal_lock_contract() {
+    let mut contract = get_contract_alice();
+    set_caller(get_account_nodra!());
+    contract.act_lock_contract(Some("lock".to_string()), true);
+    commit_agree(&mut contract, get_account_nodra!());
+    commit_agree(&mut contract, get_account_nitya!());
+    reveal_agree(&mut contract, get_account_nodra!());
+    reveal_agree(&mut contract, get_account_nitya!());
+
+    let state = contract.guilds.state.get().expect("missing state");
+    assert_eq!(LockedFor::Emergency, state.lock);
+}
+
+#[test]
+fn vote_lock_contract_sealed_ceo() {
+    let mut contract = get_contract_alice();
+    set_caller(get_account_nodra!());
+    contract.act_lock_contract(Some("lock".to_string()), true);
+
+    // Note: The CEO does not commit to a sealed motion, it locks right away
+    set_caller(get_account_vecno!());
+    contract.vote_lock_contract();
+    let state = contract.guilds.state.get().expect("missing state");
+    assert_eq!(LockedFor::Emergency, state.lock);
+}
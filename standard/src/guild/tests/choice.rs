@@ -0,0 +1,356 @@
+use crate::guild::*;
+
+use crate::guild::test_utils::*;
+
+/// Adds alice to the members of the default guild.
+fn get_contract_alice() -> GuildContract {
+    get_contract_with(
+        &get_guild_info(2, 5),
+        &[(get_account_nitya!(), 5000), (get_account_nodra!(), 5000)],
+        &[
+            get_account_alice!(),
+            get_account_bob!(),
+            get_account_nitya!(),
+            get_account_nodra!(),
+            get_account_vecno!(),
+        ],
+    )
+}
+
+fn set_reveal(account: AccountId) {
+    set_caller_at(account, EXPIRE_24H.as_nanos());
+}
+
+fn get_options(count: usize) -> Vec<VoteInfo> {
+    (0..count)
+        .map(|index| VoteInfo {
+            title: format!("option {}", index),
+            details: None,
+            reference: None,
+            reference_hash: None,
+        })
+        .collect()
+}
+
+fn get_ranking(options: &[usize]) -> Vec<VoteId> {
+    options
+        .iter()
+        .map(|index| get_vote_id_option(*index))
+        .collect()
+}
+
+fn act_choice(contract: &mut GuildContract, count: usize, method: ChoiceMethod) -> MotionId {
+    act_motion(contract, count, method, false)
+}
+
+fn act_sealed(contract: &mut GuildContract, count: usize, method: ChoiceMethod) -> MotionId {
+    act_motion(contract, count, method, true)
+}
+
+fn act_motion(
+    contract: &mut GuildContract,
+    count: usize,
+    method: ChoiceMethod,
+    sealed: bool,
+) -> MotionId {
+    set_caller(get_account_bob!());
+    contract.act_choice_motion(
+        get_guild_id!(),
+        "Choice".to_string(),
+        get_options(count),
+        method,
+        sealed,
+        "choose".to_string(),
+    );
+    contract.guild_latest_motion(get_guild_id!()).unwrap()
+}
+
+fn commit_choice(
+    contract: &mut GuildContract,
+    id: &MotionId,
+    account: AccountId,
+    ranking: &[usize],
+) {
+    let hash = get_commitment_hash(&account, &get_ranking(ranking), "salt");
+    set_caller(account);
+    contract.commit_choice_motion(id.clone(), hash);
+}
+
+fn reveal_choice(
+    contract: &mut GuildContract,
+    id: &MotionId,
+    account: AccountId,
+    ranking: &[usize],
+) {
+    set_reveal(account);
+    contract.reveal_choice_motion(id.clone(), get_ranking(ranking), "salt".to_string());
+}
+
+fn vote_choice(contract: &mut GuildContract, id: &MotionId, account: AccountId, ranking: &[usize]) {
+    set_caller(account);
+    contract.vote_choice_motion(id.clone(), get_ranking(ranking));
+}
+
+// ==== act_choice_motion ====
+
+#[test]
+fn act_choice_motion() {
+    let mut contract = get_contract_alice();
+    let id = act_choice(&mut contract, 3, ChoiceMethod::Plurality);
+    assert_eq!("OM:1".parse::<MotionId>().unwrap(), id);
+
+    let motion = contract.guild_motion(id).unwrap();
+    assert_eq!(Some(ChoiceMethod::Plurality), motion.method);
+    assert_eq!(3, motion.tally.len());
+    assert!(motion.tally.contains_key(&get_vote_id_option(2)));
+}
+
+#[test]
+#[should_panic(expected = "The motion requires at least two options to choose from")]
+fn act_choice_motion_single_option() {
+    let mut contract = get_contract_alice();
+    act_choice(&mut contract, 1, ChoiceMethod::Plurality);
+}
+
+#[test]
+#[should_panic(expected = "unauthorized call")]
+fn act_choice_motion_not_member() {
+    let mut contract = get_contract_alice();
+    set_caller(get_account_eve!());
+    contract.act_choice_motion(
+        get_guild_id!(),
+        "Choice".to_string(),
+        get_options(2),
+        ChoiceMethod::Plurality,
+        false,
+        "choose".to_string(),
+    );
+}
+
+// ==== vote_choice_motion ====
+
+#[test]
+fn vote_choice_motion_plurality() {
+    let mut contract = get_contract_alice();
+    let id = act_choice(&mut contract, 3, ChoiceMethod::Plurality);
+    vote_choice(&mut contract, &id, get_account_vecno!(), &[1]);
+    vote_choice(&mut contract, &id, get_account_nodra!(), &[1]);
+    vote_choice(&mut contract, &id, get_account_bob!(), &[0]);
+
+    set_expired();
+    let result = contract.finalize_motion(id.clone());
+    assert_eq!(Some(get_vote_id_option(1)), result.winner);
+    assert_eq!(3, result.voters);
+    assert!(contract.guilds.choice_map.get(&id).is_none());
+}
+
+#[test]
+fn vote_choice_motion_ranked_choice() {
+    let mut contract = get_contract_alice();
+    set_caller(get_account_alice!());
+    contract.delegate_vote(Some(get_guild_id!()), get_account_nitya!());
+
+    let id = act_choice(&mut contract, 3, ChoiceMethod::RankedChoice);
+    vote_choice(&mut contract, &id, get_account_vecno!(), &[0, 1]);
+    vote_choice(&mut contract, &id, get_account_nodra!(), &[0, 1]);
+    vote_choice(&mut contract, &id, get_account_bob!(), &[1, 0]);
+    // Note: The delegated voice of alice takes the ranking of nitya
+    vote_choice(&mut contract, &id, get_account_nitya!(), &[2, 1]);
+
+    let motion = contract.guild_motion(id.clone()).unwrap();
+    let tally = motion.tally.get(&get_vote_id_option(2)).unwrap();
+    assert_eq!(2, tally.members);
+
+    // Note: The option 1 is eliminated and its ballot moves to option 0
+    set_expired();
+    let result = contract.finalize_motion(id);
+    assert_eq!(Some(get_vote_id_option(0)), result.winner);
+}
+
+#[test]
+fn revote_motion_ranked_choice() {
+    let mut contract = get_contract_alice();
+    set_caller(get_account_alice!());
+    contract.delegate_vote(Some(get_guild_id!()), get_account_vecno!());
+
+    let id = act_choice(&mut contract, 3, ChoiceMethod::RankedChoice);
+    vote_choice(&mut contract, &id, get_account_vecno!(), &[0, 2, 1]);
+    set_caller(get_account_vecno!());
+    contract.revote_motion(id.clone(), get_vote_id_option(2));
+
+    // Note: The revote moves the option to the front of the ranking
+    let voices = contract.votes.voices_map.get(&id).unwrap();
+    for account in [get_account_vecno!(), get_account_alice!()] {
+        let ballot = voices.votes.get(&account).unwrap();
+        assert_eq!(get_ranking(&[2, 0, 1]), ballot.ranking);
+    }
+    let motion = contract.guild_motion(id).unwrap();
+    assert_eq!(2, motion.tally.get(&get_vote_id_option(2)).unwrap().members);
+    assert_eq!(0, motion.tally.get(&get_vote_id_option(0)).unwrap().members);
+}
+
+#[test]
+fn vote_choice_motion_tie() {
+    let mut contract = get_contract_alice();
+    let id = act_choice(&mut contract, 2, ChoiceMethod::Plurality);
+    vote_choice(&mut contract, &id, get_account_vecno!(), &[0]);
+    vote_choice(&mut contract, &id, get_account_bob!(), &[1]);
+
+    set_expired();
+    let result = contract.finalize_motion(id);
+    assert!(result.winner.is_none());
+}
+
+#[test]
+fn vote_choice_motion_quorum() {
+    let mut contract = get_contract_alice();
+    let policy = VotePolicy {
+        quorum: 5000,
+        ..contract.votes.get_policy(MotionKind::ChoiceGuild)
+    };
+    contract.votes.set_policy(MotionKind::ChoiceGuild, &policy);
+    let id = act_choice(&mut contract, 2, ChoiceMethod::Plurality);
+    vote_choice(&mut contract, &id, get_account_vecno!(), &[1]);
+    vote_choice(&mut contract, &id, get_account_bob!(), &[1]);
+
+    // Note: Two out of five voices miss the quorum
+    set_expired();
+    let result = contract.finalize_motion(id);
+    assert!(result.winner.is_none());
+}
+
+#[test]
+fn vote_choice_motion_weighted() {
+    let mut contract = get_contract_alice();
+    let policy = VotePolicy {
+        weighted: true,
+        ..contract.votes.get_policy(MotionKind::ChoiceGuild)
+    };
+    contract.votes.set_policy(MotionKind::ChoiceGuild, &policy);
+    contract.guilds.apply_change(
+        &get_guild_id!(),
+        &GuildChange::StakeMember {
+            account_id: get_account_bob!(),
+            value: 500,
+        },
+    );
+    let id = act_choice(&mut contract, 2, ChoiceMethod::Plurality);
+    vote_choice(&mut contract, &id, get_account_vecno!(), &[1]);
+    vote_choice(&mut contract, &id, get_account_nodra!(), &[1]);
+    vote_choice(&mut contract, &id, get_account_bob!(), &[0]);
+
+    // Note: The stake of bob outweighs the two other voices
+    set_expired();
+    let result = contract.finalize_motion(id);
+    assert_eq!(Some(get_vote_id_option(0)), result.winner);
+}
+
+#[test]
+#[should_panic(expected = "A plurality motion takes a single option")]
+fn vote_choice_motion_plurality_ranking() {
+    let mut contract = get_contract_alice();
+    let id = act_choice(&mut contract, 3, ChoiceMethod::Plurality);
+    vote_choice(&mut contract, &id, get_account_bob!(), &[0, 1]);
+}
+
+#[test]
+#[should_panic(expected = "The ranking has duplicated options")]
+fn vote_choice_motion_duplicated() {
+    let mut contract = get_contract_alice();
+    let id = act_choice(&mut contract, 3, ChoiceMethod::RankedChoice);
+    vote_choice(&mut contract, &id, get_account_bob!(), &[0, 0]);
+}
+
+#[test]
+#[should_panic(expected = "missing motion vote")]
+fn vote_choice_motion_unknown_option() {
+    let mut contract = get_contract_alice();
+    let id = act_choice(&mut contract, 2, ChoiceMethod::RankedChoice);
+    vote_choice(&mut contract, &id, get_account_bob!(), &[0, 2]);
+}
+
+#[test]
+#[should_panic(expected = "unauthorized call")]
+fn vote_choice_motion_not_member() {
+    let mut contract = get_contract_alice();
+    let id = act_choice(&mut contract, 2, ChoiceMethod::Plurality);
+    vote_choice(&mut contract, &id, get_account_eve!(), &[0]);
+}
+
+// ==== sealed choice motions ====
+
+#[test]
+fn reveal_choice_motion() {
+    let mut contract = get_contract_alice();
+    let id = act_sealed(&mut contract, 3, ChoiceMethod::RankedChoice);
+    commit_choice(&mut contract, &id, get_account_vecno!(), &[2, 0]);
+    commit_choice(&mut contract, &id, get_account_nodra!(), &[2]);
+    commit_choice(&mut contract, &id, get_account_bob!(), &[1]);
+
+    // Note: The tally stays empty until the votes are revealed
+    let motion = contract.guild_motion(id.clone()).unwrap();
+    assert_eq!(3, motion.commits);
+    let tally = motion.tally.get(&get_vote_id_option(2)).unwrap();
+    assert_eq!(0, tally.members);
+    assert!(contract
+        .guild_motion_vote(id.clone(), get_account_vecno!())
+        .is_none());
+
+    reveal_choice(&mut contract, &id, get_account_vecno!(), &[2, 0]);
+    reveal_choice(&mut contract, &id, get_account_nodra!(), &[2]);
+    let motion = contract.guild_motion(id.clone()).unwrap();
+    assert_eq!(1, motion.commits);
+    let tally = motion.tally.get(&get_vote_id_option(2)).unwrap();
+    assert_eq!((true, 1, 2), (tally.ceo, tally.board, tally.members));
+
+    // Note: Only revealed votes count, unrevealed commitments are reported
+    set_expired();
+    let result = contract.finalize_motion(id);
+    assert_eq!(Some(get_vote_id_option(2)), result.winner);
+    assert_eq!(2, result.voters);
+    assert_eq!(vec![get_account_bob!()], result.unrevealed);
+}
+
+#[test]
+#[should_panic(expected = "The motion takes sealed votes, commit the vote first")]
+fn vote_choice_motion_sealed() {
+    let mut contract = get_contract_alice();
+    let id = act_sealed(&mut contract, 2, ChoiceMethod::Plurality);
+    vote_choice(&mut contract, &id, get_account_bob!(), &[0]);
+}
+
+#[test]
+#[should_panic(expected = "The revealed vote does not match the commitment")]
+fn reveal_choice_motion_mismatch() {
+    let mut contract = get_contract_alice();
+    let id = act_sealed(&mut contract, 2, ChoiceMethod::Plurality);
+    commit_choice(&mut contract, &id, get_account_bob!(), &[0]);
+    reveal_choice(&mut contract, &id, get_account_bob!(), &[1]);
+}
+
+#[test]
+#[should_panic(expected = "The reveal phase of the motion has not started")]
+fn reveal_choice_motion_early() {
+    let mut contract = get_contract_alice();
+    let id = act_sealed(&mut contract, 2, ChoiceMethod::Plurality);
+    commit_choice(&mut contract, &id, get_account_bob!(), &[0]);
+    contract.reveal_choice_motion(id, get_ranking(&[0]), "salt".to_string());
+}
+
+#[test]
+#[should_panic(expected = "The commit phase of the motion has ended")]
+fn commit_choice_motion_late() {
+    let mut contract = get_contract_alice();
+    let id = act_sealed(&mut contract, 2, ChoiceMethod::Plurality);
+    set_reveal(get_account_bob!());
+    let hash = get_commitment_hash(&get_account_bob!(), &get_ranking(&[0]), "salt");
+    contract.commit_choice_motion(id, hash);
+}
+
+#[test]
+#[should_panic(expected = "The motion does not take sealed votes")]
+fn commit_choice_motion_open() {
+    let mut contract = get_contract_alice();
+    let id = act_choice(&mut contract, 2, ChoiceMethod::Plurality);
+    commit_choice(&mut contract, &id, get_account_bob!(), &[0]);
+}
@@ -0,0 +1,225 @@
+use crate::guild::*;
+
+use crate::guild::test_utils::*;
+use near_sdk::ONE_NEAR;
+
+macro_rules! new_guild_id {
+    () => {
+        "G:02".parse::<GuildId>().unwrap()
+    };
+}
+macro_rules! create_id {
+    () => {
+        "CG:1".parse::<MotionId>().unwrap()
+    };
+}
+
+/// Seats nodra alone on the board with nodra and vecno as the only members.
+fn get_contract_nodra() -> GuildContract {
+    get_contract_with(
+        &get_guild_info(1, 2),
+        &[(get_account_nodra!(), 5000)],
+        &[get_account_vecno!(), get_account_nodra!()],
+    )
+}
+
+fn create_guild(contract: &mut GuildContract, motion_id: Option<MotionId>) {
+    let guild = GuildInfo {
+        ceo_id: get_account_bob!(),
+        ceo_share: 1000,
+        board_size: 1,
+        board_share: 4000,
+        members_size: 2,
+        members_share: 5000,
+    };
+
+    let mut member_map = HashMap::new();
+    member_map.insert(get_account_bob!(), U128(100));
+    member_map.insert(get_account_nitya!(), U128(100));
+
+    let mut board_map = HashMap::new();
+    board_map.insert(get_account_nitya!(), 5000_u16);
+
+    contract.arc_create_guild(new_guild_id!(), guild, board_map, member_map, motion_id);
+}
+
+fn approve_creation(contract: &mut GuildContract) {
+    set_deposit(get_account_nodra!(), 0);
+    contract.act_create_guild_contract(new_guild_id!(), get_account_bob!(), "create".to_string());
+    set_deposit(get_account_vecno!(), 0);
+    contract.vote_create_guild_contract(create_id!());
+    set_deposit(get_account_nodra!(), 0);
+    contract.vote_create_guild_contract(create_id!());
+}
+
+fn set_create_policy(contract: &mut GuildContract, policy: CreatePolicy) {
+    set_deposit(get_account_vecno!(), 0);
+    let change = ContractChange::Create { policy };
+    contract.act_change_contract(change, "create".to_string());
+    for account in [get_account_vecno!(), get_account_nodra!()] {
+        set_deposit(account, 0);
+        contract.vote_change_contract();
+    }
+}
+
+// ==== arc_create_guild ====
+
+#[test]
+fn arc_create_guild_open() {
+    let mut contract = get_contract_nodra();
+    set_create_policy(&mut contract, CreatePolicy::Open);
+    assert_eq!(CreatePolicy::Open, contract.guild_contract_state().create);
+
+    set_deposit(get_account_bob!(), ONE_NEAR);
+    create_guild(&mut contract, None);
+    let guild = contract.guilds.guild_map.get(&new_guild_id!()).unwrap();
+    assert_eq!(get_account_bob!(), guild.info.ceo_id);
+}
+
+#[test]
+#[should_panic(expected = "Guild creation requires an approved motion")]
+fn arc_create_guild_needs_motion() {
+    let mut contract = get_contract_nodra();
+    set_deposit(get_account_bob!(), ONE_NEAR);
+    create_guild(&mut contract, None);
+}
+
+#[test]
+#[should_panic(expected = "requires attached deposit of at least 1 yocto")]
+fn arc_create_guild_no_deposit() {
+    let mut contract = get_contract_nodra();
+    set_create_policy(&mut contract, CreatePolicy::Open);
+    create_guild(&mut contract, None);
+}
+
+#[test]
+#[should_panic(expected = "Must attach")]
+fn arc_create_guild_storage_cost() {
+    let mut contract = get_contract_nodra();
+    set_create_policy(&mut contract, CreatePolicy::Open);
+    set_deposit(get_account_bob!(), 1);
+    create_guild(&mut contract, None);
+}
+
+#[test]
+fn arc_create_guild_motion() {
+    let mut contract = get_contract_nodra();
+    approve_creation(&mut contract);
+    assert!(
+        contract
+            .guilds
+            .create_map
+            .get(&create_id!())
+            .unwrap()
+            .approved
+    );
+
+    set_deposit(get_account_bob!(), ONE_NEAR);
+    create_guild(&mut contract, Some(create_id!()));
+    assert!(contract.guilds.guild_map.get(&new_guild_id!()).is_some());
+    assert!(contract.guilds.create_map.get(&create_id!()).is_none());
+}
+
+#[test]
+#[should_panic(expected = "guild creation is not approved")]
+fn arc_create_guild_motion_voting() {
+    let mut contract = get_contract_nodra();
+    set_deposit(get_account_nodra!(), 0);
+    contract.act_create_guild_contract(new_guild_id!(), get_account_bob!(), "create".to_string());
+    set_deposit(get_account_bob!(), ONE_NEAR);
+    create_guild(&mut contract, Some(create_id!()));
+}
+
+#[test]
+#[should_panic(expected = "unauthorized call")]
+fn arc_create_guild_motion_other_creator() {
+    let mut contract = get_contract_nodra();
+    approve_creation(&mut contract);
+    set_deposit(get_account_nitya!(), ONE_NEAR);
+    create_guild(&mut contract, Some(create_id!()));
+}
+
+// ==== finalize_motion ====
+
+#[test]
+fn finalize_motion_create_expired() {
+    let mut contract = get_contract_nodra();
+    set_deposit(get_account_nodra!(), 0);
+    contract.act_create_guild_contract(new_guild_id!(), get_account_bob!(), "create".to_string());
+    set_expired();
+    contract.finalize_motion(create_id!());
+    assert!(contract.guilds.create_map.get(&create_id!()).is_none());
+}
+
+#[test]
+#[should_panic(expected = "The guild has to be registered first")]
+fn finalize_motion_create_approved() {
+    let mut contract = get_contract_nodra();
+    approve_creation(&mut contract);
+    contract.finalize_motion(create_id!());
+}
+
+#[test]
+#[should_panic(expected = "missing guild creation")]
+fn finalize_motion_create_approved_expired() {
+    let mut contract = get_contract_nodra();
+    approve_creation(&mut contract);
+    set_expired();
+    contract.finalize_motion(create_id!());
+    assert!(contract.guilds.create_map.get(&create_id!()).is_none());
+
+    set_deposit(get_account_bob!(), ONE_NEAR);
+    create_guild(&mut contract, Some(create_id!()));
+}
+
+// ==== act_create_guild_contract ====
+
+#[test]
+#[should_panic(expected = "unauthorized call")]
+fn act_create_guild_contract_not_authorized() {
+    let mut contract = get_contract_nodra();
+    set_deposit(get_account_bob!(), 0);
+    contract.act_create_guild_contract(new_guild_id!(), get_account_bob!(), "create".to_string());
+}
+
+#[test]
+#[should_panic(expected = "The provided guild id is already in use")]
+fn act_create_guild_contract_in_use() {
+    let mut contract = get_contract_nodra();
+    set_deposit(get_account_nodra!(), 0);
+    contract.act_create_guild_contract(get_guild_id!(), get_account_bob!(), "create".to_string());
+}
+
+// ==== act_change_contract ====
+
+#[test]
+fn act_change_contract_create() {
+    let mut contract = get_contract_nodra();
+    set_create_policy(&mut contract, CreatePolicy::Open);
+    let state = contract.guild_contract_state();
+    assert_eq!(CreatePolicy::Open, state.create);
+    assert!(state.change.is_none());
+}
+
+#[test]
+fn act_change_contract_create_voting() {
+    let mut contract = get_contract_nodra();
+    set_deposit(get_account_vecno!(), 0);
+    let change = ContractChange::Create {
+        policy: CreatePolicy::Open,
+    };
+    contract.act_change_contract(change, "create".to_string());
+    contract.vote_change_contract();
+    assert_eq!(CreatePolicy::Motion, contract.guild_contract_state().create);
+}
+
+#[test]
+#[should_panic(expected = "unauthorized call")]
+fn act_change_contract_create_not_authorized() {
+    let mut contract = get_contract_nodra();
+    set_deposit(get_account_bob!(), 0);
+    let change = ContractChange::Create {
+        policy: CreatePolicy::Open,
+    };
+    contract.act_change_contract(change, "create".to_string());
+}
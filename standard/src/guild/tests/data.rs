@@ -159,6 +159,66 @@ fn guild_info_assert_total_shares_members() {
     data.assert_valid();
 }
 
+// ==== Guild Metadata ====
+
+fn get_metadata() -> GuildMetadata {
+    GuildMetadata {
+        name: "Guild".to_string(),
+        description: Some("A guild".to_string()),
+        icon: None,
+        media: Some("https://guild.near/media.png".to_string()),
+        media_hash: Some("a".repeat(64)),
+        reference: None,
+        reference_hash: None,
+    }
+}
+
+#[test]
+fn guild_metadata_assert_new() {
+    get_metadata().assert_valid();
+}
+
+#[test]
+#[should_panic(expected = "Guild metadata requires a name")]
+fn guild_metadata_assert_name() {
+    let data = GuildMetadata {
+        name: "".to_string(),
+        ..get_metadata()
+    };
+    data.assert_valid();
+}
+
+#[test]
+#[should_panic(expected = "Maximum name length is 28 characters")]
+fn guild_metadata_assert_max_name() {
+    let data = GuildMetadata {
+        name: "a".repeat(29),
+        ..get_metadata()
+    };
+    data.assert_valid();
+}
+
+#[test]
+#[should_panic(expected = "Media hash is required to verify media integrity")]
+fn guild_metadata_assert_media_hash() {
+    let data = GuildMetadata {
+        media_hash: None,
+        ..get_metadata()
+    };
+    data.assert_valid();
+}
+
+#[test]
+#[should_panic(expected = "Reference hash has to be hex encoded string (64 bytes)")]
+fn guild_metadata_assert_reference_hash() {
+    let data = GuildMetadata {
+        reference: Some("https://guild.near/reference.json".to_string()),
+        reference_hash: Some("a".repeat(32)),
+        ..get_metadata()
+    };
+    data.assert_valid();
+}
+
 // ==== Guild Board ====
 
 #[test]
@@ -0,0 +1,191 @@
+use crate::guild::*;
+
+use crate::guild::test_utils::*;
+
+/// Seats nodra alone on the board with alice, bob, nodra and vecno as members.
+fn get_contract_nodra() -> GuildContract {
+    get_contract_with(
+        &get_guild_info(2, 5),
+        &[(get_account_nodra!(), 5000)],
+        &[
+            get_account_alice!(),
+            get_account_bob!(),
+            get_account_nodra!(),
+            get_account_vecno!(),
+        ],
+    )
+}
+
+fn act_add_eve(contract: &mut GuildContract) -> MotionId {
+    set_caller(get_account_nodra!());
+    contract.act_add_guild_member(
+        get_guild_id!(),
+        get_account_eve!(),
+        U128(50),
+        "add".to_string(),
+    );
+    "GM:1".parse().unwrap()
+}
+
+// ==== delegate_vote ====
+
+#[test]
+fn delegate_vote_guild_motion() {
+    let mut contract = get_contract_nodra();
+    for account in [
+        get_account_alice!(),
+        get_account_bob!(),
+        get_account_nodra!(),
+    ] {
+        set_caller(account);
+        contract.delegate_vote(Some(get_guild_id!()), get_account_vecno!());
+    }
+    act_add_eve(&mut contract);
+
+    // Note: The CEO carries the delegated member voices over the threshold
+    set_caller(get_account_vecno!());
+    contract.vote_guild_motion(get_guild_id!());
+    let members = contract
+        .guilds
+        .member_map
+        .get(&get_guild_id!())
+        .expect("missing guild members");
+    assert_eq!(Some(50), members.list.get(&get_account_eve!()));
+}
+
+#[test]
+fn delegate_vote_direct_override() {
+    let mut contract = get_contract_nodra();
+    set_caller(get_account_alice!());
+    contract.delegate_vote(None, get_account_vecno!());
+    let id = act_add_eve(&mut contract);
+
+    set_caller(get_account_vecno!());
+    contract.vote_guild_motion(get_guild_id!());
+    assert_eq!(
+        Some(get_vote_id_agree()),
+        contract.guild_motion_vote(id.clone(), get_account_alice!())
+    );
+
+    // Note: The direct vote replaces the delegated voice, the tally stays the same
+    set_caller(get_account_alice!());
+    contract.vote_guild_motion(get_guild_id!());
+    let motion = contract.guild_motion(id.clone()).unwrap();
+    let tally = motion.tally.get(&get_vote_id_agree()).unwrap();
+    assert_eq!((2, U128(200)), (tally.members, tally.weight));
+
+    // Note: The own vote is kept when the delegate retracts
+    set_caller(get_account_vecno!());
+    contract.unvote_motion(id.clone());
+    assert_eq!(
+        Some(get_vote_id_agree()),
+        contract.guild_motion_vote(id, get_account_alice!())
+    );
+}
+
+#[test]
+fn delegate_vote_unvote() {
+    let mut contract = get_contract_nodra();
+    set_caller(get_account_alice!());
+    contract.delegate_vote(Some(get_guild_id!()), get_account_vecno!());
+    let id = act_add_eve(&mut contract);
+
+    set_caller(get_account_vecno!());
+    contract.vote_guild_motion(get_guild_id!());
+    contract.unvote_motion(id.clone());
+    assert!(contract
+        .guild_motion_vote(id.clone(), get_account_alice!())
+        .is_none());
+    let motion = contract.guild_motion(id).unwrap();
+    let tally = motion.tally.get(&get_vote_id_agree()).unwrap();
+    assert_eq!((0, U128(0)), (tally.members, tally.weight));
+}
+
+#[test]
+fn delegate_vote_guild_overrides_global() {
+    let mut contract = get_contract_nodra();
+    set_caller(get_account_alice!());
+    contract.delegate_vote(None, get_account_bob!());
+    contract.delegate_vote(Some(get_guild_id!()), get_account_vecno!());
+
+    let delegation = contract.guild_delegation(get_account_alice!()).unwrap();
+    assert_eq!(Some(get_account_bob!()), delegation.global);
+    assert_eq!(
+        Some(&get_account_vecno!()),
+        delegation.get_delegate(Some(&get_guild_id!()))
+    );
+    assert_eq!(
+        vec![get_account_alice!()],
+        contract.guild_delegators(get_account_bob!())
+    );
+    assert_eq!(
+        vec![get_account_alice!()],
+        contract.guild_delegators(get_account_vecno!())
+    );
+
+    // Note: Only the guild delegate receives the voice for the guild motion
+    let id = act_add_eve(&mut contract);
+    set_caller(get_account_bob!());
+    contract.vote_guild_motion(get_guild_id!());
+    assert!(contract
+        .guild_motion_vote(id.clone(), get_account_alice!())
+        .is_none());
+    set_caller(get_account_vecno!());
+    contract.vote_guild_motion(get_guild_id!());
+    assert_eq!(
+        Some(get_vote_id_agree()),
+        contract.guild_motion_vote(id, get_account_alice!())
+    );
+}
+
+#[test]
+#[should_panic(expected = "Can not delegate to the same account")]
+fn delegate_vote_self() {
+    let mut contract = get_contract_nodra();
+    set_caller(get_account_alice!());
+    contract.delegate_vote(Some(get_guild_id!()), get_account_alice!());
+}
+
+#[test]
+#[should_panic(expected = "Delegation requires both accounts to be guild members")]
+fn delegate_vote_not_member() {
+    let mut contract = get_contract_nodra();
+    set_caller(get_account_alice!());
+    contract.delegate_vote(Some(get_guild_id!()), get_account_eve!());
+}
+
+// ==== revoke_delegation ====
+
+#[test]
+fn revoke_delegation() {
+    let mut contract = get_contract_nodra();
+    set_caller(get_account_alice!());
+    contract.delegate_vote(None, get_account_vecno!());
+    contract.delegate_vote(Some(get_guild_id!()), get_account_vecno!());
+
+    // Note: The delegator stays listed while a delegation remains
+    contract.revoke_delegation(None);
+    assert_eq!(
+        vec![get_account_alice!()],
+        contract.guild_delegators(get_account_vecno!())
+    );
+
+    contract.revoke_delegation(Some(get_guild_id!()));
+    assert!(contract.guild_delegation(get_account_alice!()).is_none());
+    assert!(contract.guild_delegators(get_account_vecno!()).is_empty());
+
+    let id = act_add_eve(&mut contract);
+    set_caller(get_account_vecno!());
+    contract.vote_guild_motion(get_guild_id!());
+    assert!(contract
+        .guild_motion_vote(id, get_account_alice!())
+        .is_none());
+}
+
+#[test]
+#[should_panic(expected = "missing delegation")]
+fn revoke_delegation_missing() {
+    let mut contract = get_contract_nodra();
+    set_caller(get_account_alice!());
+    contract.revoke_delegation(None);
+}
@@ -0,0 +1,375 @@
+use crate::guild::*;
+
+use crate::guild::test_utils::*;
+
+/// Leaves two of the three board seats open for the election.
+fn get_contract_election() -> GuildContract {
+    get_contract_with(
+        &get_guild_info(3, 5),
+        &[(get_account_nodra!(), 4000)],
+        &[
+            get_account_alice!(),
+            get_account_bob!(),
+            get_account_nitya!(),
+            get_account_nodra!(),
+            get_account_vecno!(),
+        ],
+    )
+}
+
+fn open_election(contract: &mut GuildContract, seats: u64, share: u16, list: Vec<AccountId>) {
+    set_caller(get_account_nitya!());
+    contract.act_board_election(get_guild_id!(), seats, share, list, "election".to_string());
+}
+
+fn vote_election(contract: &mut GuildContract, voter: AccountId, candidate: AccountId) {
+    set_caller(voter);
+    contract.vote_board_election(get_guild_id!(), candidate);
+}
+
+fn get_board(contract: &GuildContract) -> BoardMembers {
+    contract
+        .guilds
+        .board_map
+        .get(&get_guild_id!())
+        .expect("missing guild board")
+}
+
+// ==== act_board_election ====
+
+#[test]
+fn act_board_election() {
+    let mut contract = get_contract_election();
+    open_election(
+        &mut contract,
+        1,
+        3000,
+        vec![get_account_alice!(), get_account_bob!()],
+    );
+
+    let guild = contract.guilds.guild_map.get(&get_guild_id!()).unwrap();
+    let election = guild.election.expect("missing election");
+    assert_eq!(1, election.seats);
+    assert_eq!(3000, election.share);
+
+    let motion = contract.votes.motion_map.get(&election.id).unwrap();
+    assert_eq!(2, motion.info.vote_options.len());
+}
+
+#[test]
+#[should_panic(expected = "unauthorized call")]
+fn act_board_election_not_authorized() {
+    let mut contract = get_contract_election();
+    set_caller("other.near".parse::<AccountId>().unwrap());
+    contract.act_board_election(
+        get_guild_id!(),
+        1,
+        3000,
+        vec![get_account_alice!()],
+        "election".to_string(),
+    );
+}
+
+#[test]
+#[should_panic(expected = "The CEO can not be a board member")]
+fn act_board_election_ceo() {
+    let mut contract = get_contract_election();
+    open_election(&mut contract, 1, 3000, vec![get_account_vecno!()]);
+}
+
+#[test]
+#[should_panic(expected = "The account is already a board member: nodra.near")]
+fn act_board_election_board() {
+    let mut contract = get_contract_election();
+    open_election(&mut contract, 1, 3000, vec![get_account_nodra!()]);
+}
+
+#[test]
+#[should_panic(expected = "Board member need to be a guild member: other.near")]
+fn act_board_election_member() {
+    let mut contract = get_contract_election();
+    open_election(
+        &mut contract,
+        1,
+        3000,
+        vec!["other.near".parse::<AccountId>().unwrap()],
+    );
+}
+
+#[test]
+#[should_panic(expected = "Duplicated candidate entry found: bob.near")]
+fn act_board_election_duplicate() {
+    let mut contract = get_contract_election();
+    open_election(
+        &mut contract,
+        1,
+        3000,
+        vec![get_account_bob!(), get_account_bob!()],
+    );
+}
+
+#[test]
+#[should_panic(expected = "The board list can not be larger than the boards size")]
+fn act_board_election_seats() {
+    let mut contract = get_contract_election();
+    open_election(
+        &mut contract,
+        3,
+        1000,
+        vec![
+            get_account_alice!(),
+            get_account_bob!(),
+            get_account_nitya!(),
+        ],
+    );
+}
+
+#[test]
+#[should_panic(expected = "Need at least one candidate for every open seat")]
+fn act_board_election_candidates() {
+    let mut contract = get_contract_election();
+    open_election(&mut contract, 2, 1000, vec![get_account_alice!()]);
+}
+
+#[test]
+#[should_panic(expected = "Total board shares can not be more than 100_00 basis points")]
+fn act_board_election_shares() {
+    let mut contract = get_contract_election();
+    open_election(
+        &mut contract,
+        2,
+        3001,
+        vec![get_account_alice!(), get_account_bob!()],
+    );
+}
+
+// ==== close_board_election ====
+
+#[test]
+fn close_board_election_all_voted() {
+    let mut contract = get_contract_election();
+    open_election(
+        &mut contract,
+        1,
+        3000,
+        vec![get_account_alice!(), get_account_bob!()],
+    );
+    vote_election(&mut contract, get_account_vecno!(), get_account_alice!());
+    vote_election(&mut contract, get_account_nodra!(), get_account_bob!());
+    vote_election(&mut contract, get_account_bob!(), get_account_alice!());
+    vote_election(&mut contract, get_account_alice!(), get_account_bob!());
+    vote_election(&mut contract, get_account_nitya!(), get_account_alice!());
+    contract.close_board_election(get_guild_id!());
+
+    let board = get_board(&contract);
+    board.assert_valid(3);
+    assert_eq!(2, board.list.len());
+    assert_eq!(Some(3000), board.list.get(&get_account_alice!()));
+    assert!(board.list.get(&get_account_bob!()).is_none());
+
+    let guild = contract.guilds.guild_map.get(&get_guild_id!()).unwrap();
+    assert!(guild.election.is_none());
+}
+
+#[test]
+#[should_panic(expected = "The board election is still open")]
+fn close_board_election_removed_member() {
+    let mut contract = get_contract_election();
+    open_election(
+        &mut contract,
+        1,
+        3000,
+        vec![get_account_alice!(), get_account_nitya!()],
+    );
+    let change = GuildChange::RemoveMember {
+        account_id: get_account_bob!(),
+    };
+    contract.guilds.apply_change(&get_guild_id!(), &change);
+
+    // Note: The removed member is still a voter of the snapshot
+    vote_election(&mut contract, get_account_vecno!(), get_account_alice!());
+    vote_election(&mut contract, get_account_nodra!(), get_account_nitya!());
+    vote_election(&mut contract, get_account_alice!(), get_account_nitya!());
+    vote_election(&mut contract, get_account_nitya!(), get_account_alice!());
+    contract.close_board_election(get_guild_id!());
+}
+
+fn set_election_policy(contract: &mut GuildContract, quorum: u16, weighted: bool) {
+    let policy = VotePolicy {
+        quorum,
+        weighted,
+        ..contract.votes.get_policy(MotionKind::ElectionGuild)
+    };
+    contract
+        .votes
+        .set_policy(MotionKind::ElectionGuild, &policy);
+}
+
+#[test]
+fn close_board_election_quorum() {
+    let mut contract = get_contract_election();
+    set_election_policy(&mut contract, 5000, false);
+    open_election(
+        &mut contract,
+        1,
+        3000,
+        vec![get_account_alice!(), get_account_bob!()],
+    );
+    vote_election(&mut contract, get_account_vecno!(), get_account_alice!());
+    vote_election(&mut contract, get_account_alice!(), get_account_bob!());
+
+    // Note: Two of five voices miss the quorum, the seat stays open
+    set_expired();
+    contract.close_board_election(get_guild_id!());
+
+    assert_eq!(1, get_board(&contract).list.len());
+    let guild = contract.guilds.guild_map.get(&get_guild_id!()).unwrap();
+    assert!(guild.election.is_none());
+}
+
+#[test]
+fn close_board_election_weighted() {
+    let mut contract = get_contract_election();
+    set_election_policy(&mut contract, 0, true);
+    let change = GuildChange::StakeMember {
+        account_id: get_account_vecno!(),
+        value: 500,
+    };
+    contract.guilds.apply_change(&get_guild_id!(), &change);
+    open_election(
+        &mut contract,
+        1,
+        3000,
+        vec![get_account_alice!(), get_account_bob!()],
+    );
+    vote_election(&mut contract, get_account_vecno!(), get_account_bob!());
+    vote_election(&mut contract, get_account_nodra!(), get_account_alice!());
+    vote_election(&mut contract, get_account_bob!(), get_account_alice!());
+    vote_election(&mut contract, get_account_alice!(), get_account_alice!());
+    vote_election(&mut contract, get_account_nitya!(), get_account_alice!());
+    contract.close_board_election(get_guild_id!());
+
+    // Note: The stake of one voter outweighs the voices of four
+    let board = get_board(&contract);
+    assert_eq!(Some(3000), board.list.get(&get_account_bob!()));
+    assert!(board.list.get(&get_account_alice!()).is_none());
+}
+
+#[test]
+fn close_board_election_expired() {
+    let mut contract = get_contract_election();
+    open_election(
+        &mut contract,
+        2,
+        2000,
+        vec![
+            get_account_alice!(),
+            get_account_bob!(),
+            get_account_nitya!(),
+        ],
+    );
+    vote_election(&mut contract, get_account_vecno!(), get_account_nitya!());
+    vote_election(&mut contract, get_account_alice!(), get_account_bob!());
+
+    set_expired();
+    contract.close_board_election(get_guild_id!());
+
+    let board = get_board(&contract);
+    assert_eq!(3, board.list.len());
+    assert_eq!(Some(2000), board.list.get(&get_account_bob!()));
+    assert_eq!(Some(2000), board.list.get(&get_account_nitya!()));
+    assert!(board.list.get(&get_account_alice!()).is_none());
+}
+
+#[test]
+#[should_panic(expected = "The board election is still open")]
+fn close_board_election_open() {
+    let mut contract = get_contract_election();
+    open_election(
+        &mut contract,
+        1,
+        3000,
+        vec![get_account_alice!(), get_account_bob!()],
+    );
+    vote_election(&mut contract, get_account_vecno!(), get_account_alice!());
+    contract.close_board_election(get_guild_id!());
+}
+
+#[test]
+#[should_panic(expected = "missing board candidate")]
+fn vote_board_election_candidate() {
+    let mut contract = get_contract_election();
+    open_election(&mut contract, 1, 3000, vec![get_account_alice!()]);
+    vote_election(&mut contract, get_account_vecno!(), get_account_bob!());
+}
+
+// ==== act_share_board_member ====
+
+#[test]
+fn share_board_member() {
+    let mut contract = get_contract_election();
+    set_caller(get_account_vecno!());
+    contract.act_share_board_member(
+        get_guild_id!(),
+        get_account_nodra!(),
+        6000,
+        "share".to_string(),
+    );
+    contract.vote_guild_motion(get_guild_id!());
+    set_caller(get_account_nodra!());
+    contract.vote_guild_motion(get_guild_id!());
+
+    assert_eq!(
+        Some(6000),
+        get_board(&contract).list.get(&get_account_nodra!())
+    );
+}
+
+#[test]
+#[should_panic(expected = "Total board shares can not be more than 100_00 basis points")]
+fn share_board_member_total() {
+    let mut contract = get_contract_election();
+    set_caller(get_account_vecno!());
+    contract.act_share_board_member(
+        get_guild_id!(),
+        get_account_nodra!(),
+        10001,
+        "share".to_string(),
+    );
+}
+
+// ==== act_remove_board_member ====
+
+#[test]
+fn remove_board_member() {
+    let mut contract = get_contract_election();
+    set_caller(get_account_vecno!());
+    contract.act_remove_board_member(get_guild_id!(), get_account_nodra!(), "remove".to_string());
+    contract.vote_guild_motion(get_guild_id!());
+    set_caller(get_account_nodra!());
+    contract.vote_guild_motion(get_guild_id!());
+    assert!(get_board(&contract)
+        .list
+        .get(&get_account_nodra!())
+        .is_none());
+
+    // Note: A former board member can leave the guild
+    let change = GuildChange::RemoveMember {
+        account_id: get_account_nodra!(),
+    };
+    contract.guilds.apply_change(&get_guild_id!(), &change);
+    assert_eq!(
+        0,
+        contract
+            .guilds
+            .get_weight(&get_guild_id!(), &get_account_nodra!())
+    );
+}
+
+#[test]
+#[should_panic(expected = "The account is not a board member: bob.near")]
+fn remove_board_member_not_board() {
+    let mut contract = get_contract_election();
+    set_caller(get_account_vecno!());
+    contract.act_remove_board_member(get_guild_id!(), get_account_bob!(), "remove".to_string());
+}
@@ -0,0 +1,234 @@
+use crate::guild::*;
+
+use crate::guild::test_utils::*;
+use near_sdk::test_utils::get_logs;
+use near_sdk::ONE_NEAR;
+
+/// Seats nodra alone on the board with nodra and vecno as the only members.
+fn get_contract_nodra() -> GuildContract {
+    get_contract_with(
+        &get_guild_info(1, 3),
+        &[(get_account_nodra!(), 5000)],
+        &[get_account_vecno!(), get_account_nodra!()],
+    )
+}
+
+// ==== guild events ====
+
+#[test]
+fn log_guild_register() {
+    set_caller(get_account_vecno!());
+    get_contract_nodra();
+    assert_eq!(
+        vec![
+            r#"EVENT_ARC:{"module":"guild","version":"1.0.0","event":"guild_register","data":[{"guild_id":"G:01","ceo_id":"vecno.near","board_ids":["nodra.near"],"member_ids":["nodra.near","vecno.near"]}]}"#
+        ],
+        get_logs()
+    );
+}
+
+#[test]
+fn log_guild_lock() {
+    let mut contract = get_contract_nodra();
+    set_caller(get_account_vecno!());
+    contract.act_lock_guild(get_guild_id!(), None);
+    assert_eq!(
+        vec![
+            r#"EVENT_ARC:{"module":"guild","version":"1.0.0","event":"guild_lock","data":[{"guild_id":"G:01","lock":"Emergency"}]}"#
+        ],
+        get_logs()
+    );
+}
+
+#[test]
+fn log_contract_lock() {
+    let mut contract = get_contract_nodra();
+    set_caller(get_account_vecno!());
+    contract.act_lock_contract(None, false);
+    assert_eq!(
+        vec![
+            r#"EVENT_ARC:{"module":"guild","version":"1.0.0","event":"guild_lock","data":[{"lock":"Emergency"}]}"#
+        ],
+        get_logs()
+    );
+}
+
+// ==== motion events ====
+
+#[test]
+fn log_motion_vote_and_execute() {
+    let mut contract = get_contract_nodra();
+    set_caller(get_account_nodra!());
+    contract.act_lock_guild(get_guild_id!(), Some("lock".to_string()));
+    contract.vote_lock_guild(get_guild_id!());
+    assert_eq!(
+        vec![
+            r#"EVENT_ARC:{"module":"guild","version":"1.0.0","event":"motion_issue","data":[{"motion_id":"LG:1","issuer_id":"nodra.near","title":"Guild Lockdown","starts_at":"0","expires_at":"43200000000000"}]}"#,
+            r#"EVENT_ARC:{"module":"guild","version":"1.0.0","event":"guild_lock","data":[{"guild_id":"G:01","lock":"Locking"}]}"#,
+            r#"EVENT_ARC:{"module":"guild","version":"1.0.0","event":"motion_vote","data":[{"motion_id":"LG:1","voter_id":"nodra.near","vote_id":"V:A","role":"board","weight":"100"}]}"#,
+            r#"EVENT_ARC:{"module":"guild","version":"1.0.0","event":"motion_execute","data":[{"motion_id":"LG:1"}]}"#,
+            r#"EVENT_ARC:{"module":"guild","version":"1.0.0","event":"guild_lock","data":[{"guild_id":"G:01","lock":"Emergency"}]}"#,
+        ],
+        get_logs()
+    );
+}
+
+// ==== state change events ====
+
+#[test]
+fn log_guild_member_change() {
+    let mut contract = get_contract_nodra();
+    set_caller(get_account_vecno!());
+    let changes = [
+        GuildChange::AddMember {
+            account_id: get_account_alice!(),
+            value: 50,
+        },
+        GuildChange::StakeMember {
+            account_id: get_account_alice!(),
+            value: 80,
+        },
+        GuildChange::RemoveMember {
+            account_id: get_account_alice!(),
+        },
+    ];
+    for change in changes.iter() {
+        contract.guilds.apply_change(&get_guild_id!(), change);
+    }
+    assert_eq!(
+        vec![
+            r#"EVENT_ARC:{"module":"guild","version":"1.0.0","event":"guild_member_add","data":[{"guild_id":"G:01","account_id":"alice.near","stake":"50"}]}"#,
+            r#"EVENT_ARC:{"module":"guild","version":"1.0.0","event":"guild_member_stake","data":[{"guild_id":"G:01","account_id":"alice.near","stake":"80"}]}"#,
+            r#"EVENT_ARC:{"module":"guild","version":"1.0.0","event":"guild_member_remove","data":[{"guild_id":"G:01","account_id":"alice.near","stake":"80"}]}"#,
+        ],
+        get_logs()
+    );
+}
+
+#[test]
+fn log_guild_board_change() {
+    let mut contract = get_contract_nodra();
+    set_caller(get_account_vecno!());
+    contract.guilds.apply_change(
+        &get_guild_id!(),
+        &GuildChange::RemoveBoard {
+            account_id: get_account_nodra!(),
+        },
+    );
+    contract
+        .guilds
+        .seat_board(&get_guild_id!(), &[get_account_nodra!()], 4000);
+    assert_eq!(
+        vec![
+            r#"EVENT_ARC:{"module":"guild","version":"1.0.0","event":"guild_board_remove","data":[{"guild_id":"G:01","account_id":"nodra.near","share":"5000"}]}"#,
+            r#"EVENT_ARC:{"module":"guild","version":"1.0.0","event":"guild_board_seat","data":[{"guild_id":"G:01","account_id":"nodra.near","share":"4000"}]}"#,
+        ],
+        get_logs()
+    );
+}
+
+#[test]
+fn log_guild_ceo_replace() {
+    let mut contract = get_contract_nodra();
+    let policy = VotePolicy {
+        threshold: 5000,
+        ..contract.votes.get_policy(MotionKind::ReplaceContract)
+    };
+    contract
+        .votes
+        .set_policy(MotionKind::ReplaceContract, &policy);
+    set_caller(get_account_vecno!());
+    contract.guilds.apply_change(
+        &get_guild_id!(),
+        &GuildChange::AddMember {
+            account_id: get_account_alice!(),
+            value: 100,
+        },
+    );
+    set_caller(get_account_nodra!());
+    contract.act_challenge_contract_exec("challenge".to_string(), false);
+    contract.act_replace_contract_exec(get_account_alice!(), "replace".to_string(), false);
+    set_caller(get_account_alice!());
+    contract.vote_replace_contract_exec();
+    assert!(get_logs().contains(
+        &r#"EVENT_ARC:{"module":"guild","version":"1.0.0","event":"guild_ceo_replace","data":[{"guild_id":"G:01","ceo_id":"alice.near","prev_id":"vecno.near"}]}"#.to_string()
+    ));
+}
+
+#[test]
+fn log_policy_change() {
+    let mut contract = get_contract_nodra();
+    set_caller(get_account_vecno!());
+    let policy = contract.votes.get_policy(MotionKind::SpendContract);
+    contract.act_policy_contract(MotionKind::SpendContract, policy, "policy".to_string());
+    contract.vote_policy_contract();
+    set_caller(get_account_nodra!());
+    contract.vote_policy_contract();
+    assert!(get_logs().contains(
+        &r#"EVENT_ARC:{"module":"guild","version":"1.0.0","event":"policy_change","data":[{"kind":"SpendContract"}]}"#.to_string()
+    ));
+}
+
+#[test]
+fn log_contract_change() {
+    let mut contract = get_contract_nodra();
+    set_caller(get_account_vecno!());
+    contract.act_change_contract(
+        ContractChange::Create {
+            policy: CreatePolicy::Open,
+        },
+        "create".to_string(),
+    );
+    contract.vote_change_contract();
+    set_caller(get_account_nodra!());
+    contract.vote_change_contract();
+    assert!(get_logs().contains(
+        &r#"EVENT_ARC:{"module":"guild","version":"1.0.0","event":"contract_change","data":[{"change":"create","value":"Open"}]}"#.to_string()
+    ));
+}
+
+#[test]
+fn log_spend_pay() {
+    let mut contract = get_contract_nodra();
+    set_caller(get_account_nodra!());
+    contract.act_spend_contract(get_account_alice!(), U128(ONE_NEAR), "spend".to_string());
+    contract.vote_spend_contract("SC:1".parse::<MotionId>().unwrap());
+    set_caller(get_account_vecno!());
+    contract.vote_spend_contract("SC:1".parse::<MotionId>().unwrap());
+
+    set_result(PromiseResult::Failed);
+    contract.resolve_spend_contract("SC:1".parse::<MotionId>().unwrap());
+    assert_eq!(
+        vec![
+            r#"EVENT_ARC:{"module":"guild","version":"1.0.0","event":"spend_pay","data":[{"spend_id":"SC:1","receiver_id":"alice.near","amount":"1000000000000000000000000","status":"failed"}]}"#
+        ],
+        get_logs()
+    );
+}
+
+#[test]
+fn log_proposal_execute() {
+    let mut contract = get_contract_nodra();
+    set_caller(get_account_nodra!());
+    let action = ProposalAction {
+        receiver_id: get_account_alice!(),
+        method_name: "ping".to_string(),
+        args: "{}".to_string(),
+        deposit: U128(0),
+        gas: U64(20_000_000_000_000),
+    };
+    contract.act_proposal_contract(action, "proposal".to_string());
+    contract.vote_proposal_contract("XC:1".parse::<MotionId>().unwrap());
+    set_caller(get_account_vecno!());
+    contract.vote_proposal_contract("XC:1".parse::<MotionId>().unwrap());
+    contract.exec_proposal_contract("XC:1".parse::<MotionId>().unwrap());
+
+    set_result(PromiseResult::Successful(vec![]));
+    contract.resolve_proposal_contract("XC:1".parse::<MotionId>().unwrap());
+    assert_eq!(
+        vec![
+            r#"EVENT_ARC:{"module":"guild","version":"1.0.0","event":"proposal_execute","data":[{"proposal_id":"XC:1","receiver_id":"alice.near","method_name":"ping","status":"succeeded"}]}"#
+        ],
+        get_logs()
+    );
+}
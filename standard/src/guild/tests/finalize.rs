@@ -0,0 +1,217 @@
+use crate::guild::*;
+
+use crate::guild::test_utils::*;
+use near_sdk::test_utils::get_created_receipts;
+use near_sdk::ONE_NEAR;
+
+macro_rules! spend_id {
+    () => {
+        "SC:1".parse::<MotionId>().unwrap()
+    };
+}
+
+fn open_spend(contract: &mut GuildContract) {
+    set_caller(get_account_nodra!());
+    contract.act_spend_contract(get_account_bob!(), U128(10 * ONE_NEAR), "spend".to_string());
+}
+
+fn pass_spend(contract: &mut GuildContract) {
+    open_spend(contract);
+    for account in [
+        get_account_vecno!(),
+        get_account_nodra!(),
+        get_account_nitya!(),
+    ] {
+        set_caller(account);
+        contract.vote_spend_contract(spend_id!());
+    }
+    set_result(PromiseResult::Successful(vec![]));
+    contract.resolve_spend_contract(spend_id!());
+}
+
+fn open_guild_motion(contract: &mut GuildContract) -> MotionId {
+    set_caller(get_account_nodra!());
+    contract.act_add_guild_member(
+        get_guild_id!(),
+        get_account_alice!(),
+        U128(50),
+        "add".to_string(),
+    );
+    contract.vote_guild_motion(get_guild_id!());
+    contract.guild_latest_motion(get_guild_id!()).unwrap()
+}
+
+fn set_refund(contract: &mut GuildContract) {
+    set_caller(get_account_vecno!());
+    contract.set_refund_policy_contract(true);
+}
+
+// ==== finalize_motion ====
+
+#[test]
+fn finalize_motion_executed() {
+    let mut contract = get_contract();
+    pass_spend(&mut contract);
+    set_caller(get_account_bob!());
+    let result = contract.finalize_motion(spend_id!());
+
+    assert_eq!(MotionStatus::Executed, result.status);
+    assert_eq!(get_account_nodra!(), result.issuer);
+    assert_eq!(3, result.voters);
+    let tally = result.tally.get(&get_vote_id_agree()).unwrap();
+    assert_eq!((true, 2, 3), (tally.ceo, tally.board, tally.members));
+    assert_eq!(Some(result), contract.guild_motion_result(spend_id!()));
+
+    // Note: The motion and its voices are removed
+    assert!(contract.guild_motion(spend_id!()).is_none());
+    assert!(contract
+        .guild_motion_vote(spend_id!(), get_account_vecno!())
+        .is_none());
+    assert!(contract.votes.voices_map.get(&spend_id!()).is_none());
+}
+
+#[test]
+#[should_panic(expected = "The spend transfer is still pending")]
+fn finalize_motion_spend_paying() {
+    let mut contract = get_contract();
+    open_spend(&mut contract);
+    for account in [
+        get_account_vecno!(),
+        get_account_nodra!(),
+        get_account_nitya!(),
+    ] {
+        set_caller(account);
+        contract.vote_spend_contract(spend_id!());
+    }
+    contract.finalize_motion(spend_id!());
+}
+
+#[test]
+fn finalize_motion_expired_guild_motion() {
+    let mut contract = get_contract();
+    let id = open_guild_motion(&mut contract);
+    set_expired();
+    let result = contract.finalize_motion(id.clone());
+    assert_eq!(MotionStatus::Expired, result.status);
+    assert_eq!(1, result.voters);
+
+    // Note: The expired motion no longer blocks a new guild motion
+    let guild = contract.guilds.guild_map.get(&get_guild_id!()).unwrap();
+    assert!(guild.motion.is_none());
+    set_caller(get_account_nodra!());
+    contract.act_add_guild_member(
+        get_guild_id!(),
+        get_account_alice!(),
+        U128(50),
+        "add".to_string(),
+    );
+}
+
+#[test]
+fn finalize_motion_refund() {
+    let mut contract = get_contract();
+    set_refund(&mut contract);
+    set_deposit(get_account_nodra!(), ONE_NEAR / 10);
+    contract.act_add_guild_member(
+        get_guild_id!(),
+        get_account_alice!(),
+        U128(50),
+        "add".to_string(),
+    );
+    // Note: Only the storage of the motion is held, the rest of the deposit is refunded
+    let receipts = get_created_receipts();
+    assert_eq!(1, receipts.len());
+    assert_eq!(get_account_nodra!(), receipts[0].receiver_id);
+    let id = contract.guild_latest_motion(get_guild_id!()).unwrap();
+    let storage = contract.votes.motion_map.get(&id).unwrap().storage;
+    assert!(storage > 0 && storage < ONE_NEAR / 10);
+    assert_eq!(storage, contract.guilds.storage_amount);
+
+    set_expired();
+    contract.finalize_motion(id);
+    assert_eq!(0, contract.guilds.storage_amount);
+    let receipts = get_created_receipts();
+    assert_eq!(1, receipts.len());
+    assert_eq!(get_account_nodra!(), receipts[0].receiver_id);
+}
+
+#[test]
+fn finalize_motion_refund_available() {
+    let mut contract = get_contract();
+    set_refund(&mut contract);
+    set_deposit(get_account_nodra!(), ONE_NEAR / 10);
+    let available = contract.get_available_amount();
+    contract.act_add_guild_member(
+        get_guild_id!(),
+        get_account_alice!(),
+        U128(50),
+        "add".to_string(),
+    );
+
+    // Note: The held storage deposit is not available to the treasury
+    let id = contract.guild_latest_motion(get_guild_id!()).unwrap();
+    let storage = contract.votes.motion_map.get(&id).unwrap().storage;
+    let used = env::storage_byte_cost() * Balance::from(env::storage_usage());
+    assert_eq!(
+        env::account_balance() - used - storage,
+        contract.get_available_amount()
+    );
+    assert!(contract.get_available_amount() < available);
+}
+
+#[test]
+fn finalize_motion_refund_unpaid() {
+    let mut contract = get_contract();
+    set_refund(&mut contract);
+    let id = open_guild_motion(&mut contract);
+    set_expired();
+    contract.finalize_motion(id);
+
+    // Note: The issuer did not prepay the storage of the motion
+    assert!(get_created_receipts().is_empty());
+}
+
+#[test]
+fn finalize_motion_no_refund() {
+    let mut contract = get_contract();
+    let id = open_guild_motion(&mut contract);
+    set_expired();
+    contract.finalize_motion(id);
+    assert!(get_created_receipts().is_empty());
+}
+
+#[test]
+#[should_panic(expected = "Can not finalize an open motion")]
+fn finalize_motion_open() {
+    let mut contract = get_contract();
+    let id = open_guild_motion(&mut contract);
+    contract.finalize_motion(id);
+}
+
+#[test]
+#[should_panic(expected = "The spend has to be released first")]
+fn finalize_motion_reserved_spend() {
+    let mut contract = get_contract();
+    open_spend(&mut contract);
+    set_expired();
+    contract.finalize_motion(spend_id!());
+}
+
+#[test]
+#[should_panic(expected = "missing motion")]
+fn finalize_motion_twice() {
+    let mut contract = get_contract();
+    pass_spend(&mut contract);
+    contract.finalize_motion(spend_id!());
+    contract.finalize_motion(spend_id!());
+}
+
+// ==== set_refund_policy_contract ====
+
+#[test]
+#[should_panic(expected = "unauthorized call")]
+fn set_refund_policy_contract_not_ceo() {
+    let mut contract = get_contract();
+    set_caller(get_account_nodra!());
+    contract.set_refund_policy_contract(true);
+}
@@ -0,0 +1,365 @@
+use crate::guild::*;
+
+use crate::guild::test_utils::*;
+use near_sdk::ONE_NEAR;
+
+fn get_guild_state(contract: &GuildContract) -> GuildState {
+    contract
+        .guilds
+        .guild_map
+        .get(&get_guild_id!())
+        .expect("missing guild state")
+}
+
+// ==== act_lock_guild ====
+
+#[test]
+fn act_lock_guild_as_ceo() {
+    let mut contract = get_contract();
+    set_caller(get_account_vecno!());
+    contract.act_lock_guild(get_guild_id!(), None);
+
+    let guild = get_guild_state(&contract);
+    assert_eq!(LockedFor::Emergency, guild.lock);
+    assert!(guild.vote.is_none());
+
+    // Note: The contract lock is independent of the guild lock
+    let state = contract.guilds.state.get().expect("missing state");
+    assert_eq!(LockedFor::None, state.lock);
+}
+
+#[test]
+fn act_lock_guild_as_board() {
+    let mut contract = get_contract();
+    set_caller(get_account_nodra!());
+    contract.act_lock_guild(get_guild_id!(), Some("lock".to_string()));
+
+    let guild = get_guild_state(&contract);
+    assert_eq!(LockedFor::Locking, guild.lock);
+    assert!(guild.vote.is_some());
+}
+
+#[test]
+#[should_panic(expected = "details needed")]
+fn act_lock_guild_needs_details() {
+    let mut contract = get_contract();
+    set_caller(get_account_nodra!());
+    contract.act_lock_guild(get_guild_id!(), None);
+}
+
+#[test]
+#[should_panic(expected = "unauthorized call")]
+fn act_lock_guild_not_authorized() {
+    let mut contract = get_contract();
+    set_caller(get_account_bob!());
+    contract.act_lock_guild(get_guild_id!(), Some("lock".to_string()));
+}
+
+#[test]
+#[should_panic(expected = "guild is not unlocked")]
+fn act_lock_guild_active_lock() {
+    let mut contract = get_contract();
+    set_caller(get_account_vecno!());
+    contract.act_lock_guild(get_guild_id!(), None);
+    contract.act_lock_guild(get_guild_id!(), None);
+}
+
+// ==== vote_lock_guild ====
+
+#[test]
+fn vote_lock_guild_as_board() {
+    let mut contract = get_contract();
+    set_caller(get_account_nodra!());
+    contract.act_lock_guild(get_guild_id!(), Some("lock".to_string()));
+    contract.vote_lock_guild(get_guild_id!());
+    assert_eq!(LockedFor::Locking, get_guild_state(&contract).lock);
+
+    set_caller(get_account_nitya!());
+    contract.vote_lock_guild(get_guild_id!());
+
+    let guild = get_guild_state(&contract);
+    assert_eq!(LockedFor::Emergency, guild.lock);
+    assert!(guild.vote.is_none());
+    assert!(guild.time.is_some());
+}
+
+#[test]
+fn vote_lock_guild_as_ceo() {
+    let mut contract = get_contract();
+    set_caller(get_account_nodra!());
+    contract.act_lock_guild(get_guild_id!(), Some("lock".to_string()));
+    set_caller(get_account_vecno!());
+    contract.vote_lock_guild(get_guild_id!());
+
+    let guild = get_guild_state(&contract);
+    assert_eq!(LockedFor::Emergency, guild.lock);
+    assert!(guild.vote.is_none());
+}
+
+#[test]
+#[should_panic(expected = "guild is not locking")]
+fn vote_lock_guild_not_locking() {
+    let mut contract = get_contract();
+    set_caller(get_account_nodra!());
+    contract.vote_lock_guild(get_guild_id!());
+}
+
+// ==== unlock guild ====
+
+#[test]
+fn vote_unlock_guild_as_ceo_board() {
+    let mut contract = get_contract();
+    set_caller(get_account_vecno!());
+    contract.act_lock_guild(get_guild_id!(), None);
+    contract.act_unlock_guild(get_guild_id!(), "unlock".to_string());
+    contract.vote_unlock_guild(get_guild_id!());
+
+    set_caller(get_account_nodra!());
+    contract.vote_unlock_guild(get_guild_id!());
+    assert_eq!(LockedFor::Emergency, get_guild_state(&contract).lock);
+
+    set_caller(get_account_nitya!());
+    contract.vote_unlock_guild(get_guild_id!());
+
+    let guild = get_guild_state(&contract);
+    assert_eq!(LockedFor::None, guild.lock);
+    assert!(guild.vote.is_none());
+    assert!(guild.time.is_some());
+}
+
+#[test]
+fn vote_lock_guild_timeout() {
+    let mut contract = get_contract();
+    set_caller(get_account_nodra!());
+    contract.act_lock_guild(get_guild_id!(), Some("lock".to_string()));
+    set_caller(get_account_vecno!());
+    contract.vote_lock_guild(get_guild_id!());
+
+    let guild = get_guild_state(&contract);
+    let timeout = guild.time.expect("missing timeout");
+    assert_eq!(EXPIRE_24H.as_nanos(), timeout.as_nanos());
+    assert_eq!(86400, EXPIRE_24H.as_secs());
+}
+
+#[test]
+#[should_panic(expected = "timeout still active")]
+fn act_unlock_guild_timeout() {
+    let mut contract = get_contract();
+    set_caller(get_account_nodra!());
+    contract.act_lock_guild(get_guild_id!(), Some("lock".to_string()));
+    set_caller(get_account_vecno!());
+    contract.vote_lock_guild(get_guild_id!());
+
+    // Note: One hour later the 24 hour timeout is still active
+    set_caller_at(get_account_vecno!(), Duration::from_hours(1).as_nanos());
+    contract.act_unlock_guild(get_guild_id!(), "unlock".to_string());
+}
+
+// ==== abort_lock_guild ====
+
+#[test]
+fn abort_lock_guild_expired() {
+    let mut contract = get_contract();
+    set_caller(get_account_nodra!());
+    contract.act_lock_guild(get_guild_id!(), Some("lock".to_string()));
+    let id = get_guild_state(&contract).vote.unwrap();
+
+    set_caller_at(get_account_bob!(), EXPIRE_12H.as_nanos());
+    contract.abort_lock_guild(get_guild_id!());
+
+    let guild = get_guild_state(&contract);
+    assert_eq!(LockedFor::None, guild.lock);
+    assert!(guild.vote.is_none());
+    assert_eq!(MotionStatus::Expired, contract.finalize_motion(id).status);
+}
+
+#[test]
+fn abort_unlock_guild_expired() {
+    let mut contract = get_contract();
+    set_caller(get_account_vecno!());
+    contract.act_lock_guild(get_guild_id!(), None);
+    contract.act_unlock_guild(get_guild_id!(), "unlock".to_string());
+
+    set_caller_at(get_account_bob!(), EXPIRE_48H.as_nanos());
+    contract.abort_lock_guild(get_guild_id!());
+
+    // Note: The guild stays locked, a new unlock motion can be issued
+    let guild = get_guild_state(&contract);
+    assert_eq!(LockedFor::Emergency, guild.lock);
+    assert!(guild.vote.is_none());
+    set_caller(get_account_nodra!());
+    contract.act_unlock_guild(get_guild_id!(), "unlock".to_string());
+}
+
+#[test]
+#[should_panic(expected = "motion has not expired")]
+fn abort_lock_guild_open() {
+    let mut contract = get_contract();
+    set_caller(get_account_nodra!());
+    contract.act_lock_guild(get_guild_id!(), Some("lock".to_string()));
+    contract.abort_lock_guild(get_guild_id!());
+}
+
+// ==== abort_lock_contract ====
+
+#[test]
+fn abort_lock_contract_expired() {
+    let mut contract = get_contract();
+    set_caller(get_account_nodra!());
+    contract.act_lock_contract(Some("lock".to_string()), false);
+
+    set_caller_at(get_account_bob!(), EXPIRE_12H.as_nanos());
+    contract.abort_lock_contract();
+
+    let state = contract.guild_contract_state();
+    assert_eq!(LockedFor::None, state.lock);
+    assert!(state.vote.is_none());
+}
+
+#[test]
+#[should_panic(expected = "motion has not expired")]
+fn abort_unlock_contract_open() {
+    let mut contract = get_contract();
+    set_caller(get_account_vecno!());
+    contract.act_lock_contract(None, false);
+    ContractLocking::act_unlock_contract(&mut contract, "unlock".to_string());
+    contract.abort_lock_contract();
+}
+
+#[test]
+#[should_panic(expected = "unauthorized call")]
+fn act_unlock_guild_not_authorized() {
+    let mut contract = get_contract();
+    set_caller(get_account_vecno!());
+    contract.act_lock_guild(get_guild_id!(), None);
+    set_caller(get_account_bob!());
+    contract.act_unlock_guild(get_guild_id!(), "unlock".to_string());
+}
+
+#[test]
+#[should_panic(expected = "guild is not locked")]
+fn act_unlock_guild_not_locked() {
+    let mut contract = get_contract();
+    set_caller(get_account_vecno!());
+    contract.act_unlock_guild(get_guild_id!(), "unlock".to_string());
+}
+
+// ==== guild lock guard ====
+
+#[test]
+#[should_panic(expected = "The guild is locked")]
+fn guilds_assert_unlocked() {
+    let mut contract = get_contract();
+    contract.guilds.assert_unlocked(&get_guild_id!());
+
+    set_caller(get_account_vecno!());
+    contract.act_lock_guild(get_guild_id!(), None);
+    contract.guilds.assert_unlocked(&get_guild_id!());
+}
+
+// ==== arc_create_guild ====
+
+fn get_new_guild() -> (GuildInfo, HashMap<AccountId, u16>, HashMap<AccountId, U128>) {
+    let guild = GuildInfo {
+        ceo_id: get_account_bob!(),
+        ceo_share: 1000,
+        board_size: 1,
+        board_share: 4000,
+        members_size: 2,
+        members_share: 5000,
+    };
+
+    let mut member_map = HashMap::new();
+    member_map.insert(get_account_bob!(), U128(100));
+    member_map.insert(get_account_nitya!(), U128(200));
+
+    let mut board_map = HashMap::new();
+    board_map.insert(get_account_nitya!(), 10000_u16);
+
+    (guild, board_map, member_map)
+}
+
+fn set_create_open(contract: &mut GuildContract) {
+    let mut state = contract.guilds.state.get().unwrap();
+    state.create = CreatePolicy::Open;
+    contract.guilds.state.set(&state);
+}
+
+#[test]
+fn arc_create_guild_registers() {
+    let mut contract = get_contract();
+    set_create_open(&mut contract);
+    let (guild, board_map, member_map) = get_new_guild();
+    set_deposit(get_account_nodra!(), ONE_NEAR);
+    contract.arc_create_guild("G:02".parse().unwrap(), guild, board_map, member_map, None);
+
+    let state = contract
+        .guilds
+        .guild_map
+        .get(&"G:02".parse().unwrap())
+        .unwrap();
+    assert_eq!(get_account_bob!(), state.info.ceo_id);
+    assert_eq!(LockedFor::None, state.lock);
+    assert_eq!(
+        200,
+        contract
+            .guilds
+            .get_weight(&"G:02".parse().unwrap(), &get_account_nitya!())
+    );
+}
+
+#[test]
+#[should_panic(expected = "Guild creation requires an approved motion")]
+fn arc_create_guild_needs_motion() {
+    let mut contract = get_contract();
+    let (guild, board_map, member_map) = get_new_guild();
+    set_deposit(get_account_vecno!(), ONE_NEAR);
+    contract.arc_create_guild("G:02".parse().unwrap(), guild, board_map, member_map, None);
+}
+
+#[test]
+#[should_panic(expected = "requires attached deposit of at least 1 yocto")]
+fn arc_create_guild_no_deposit() {
+    let mut contract = get_contract();
+    set_create_open(&mut contract);
+    let (guild, board_map, member_map) = get_new_guild();
+    set_caller(get_account_nodra!());
+    contract.arc_create_guild("G:02".parse().unwrap(), guild, board_map, member_map, None);
+}
+
+#[test]
+#[should_panic(expected = "contract is not unlocked")]
+fn arc_create_guild_locked() {
+    let mut contract = get_contract();
+    set_create_open(&mut contract);
+    let (guild, board_map, member_map) = get_new_guild();
+    set_caller(get_account_vecno!());
+    contract.act_lock_contract(None, false);
+    set_deposit(get_account_vecno!(), ONE_NEAR);
+    contract.arc_create_guild("G:02".parse().unwrap(), guild, board_map, member_map, None);
+}
+
+// ==== GuildContract::new ====
+
+#[test]
+fn guild_contract_new() {
+    let guild = GuildInfo {
+        ceo_id: get_account_vecno!(),
+        ceo_share: 1000,
+        board_size: 1,
+        board_share: 4000,
+        members_size: 2,
+        members_share: 5000,
+    };
+    let mut board_map = HashMap::new();
+    board_map.insert(get_account_nodra!(), 10000_u16);
+    let mut member_map = HashMap::new();
+    member_map.insert(get_account_nodra!(), 100_u128);
+    member_map.insert(get_account_vecno!(), 100_u128);
+
+    let contract = GuildContract::new(&get_guild_id!(), &guild, &board_map, &member_map);
+    let state = contract.guild_contract_state();
+    assert_eq!(get_guild_id!(), state.exec);
+    assert_eq!(LockedFor::None, state.lock);
+    assert_eq!(get_account_vecno!(), get_guild_state(&contract).info.ceo_id);
+}
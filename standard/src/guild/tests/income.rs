@@ -0,0 +1,177 @@
+use crate::guild::*;
+
+use crate::guild::test_utils::*;
+use near_sdk::ONE_NEAR;
+
+fn get_balance(contract: &GuildContract, account: AccountId) -> u128 {
+    contract.guild_income_balance(get_guild_id!(), account).0
+}
+
+// ==== deposit_guild_income ====
+
+#[test]
+fn deposit_guild_income() {
+    let mut contract = get_contract();
+    set_deposit(get_account_bob!(), 100 * ONE_NEAR);
+    contract.deposit_guild_income(get_guild_id!());
+
+    // ceo 10% + members 50% / 4
+    assert_eq!(
+        22_500 * ONE_NEAR / 1000,
+        get_balance(&contract, get_account_vecno!())
+    );
+    // board 40% / 2 + members 50% / 4
+    assert_eq!(
+        32_500 * ONE_NEAR / 1000,
+        get_balance(&contract, get_account_nitya!())
+    );
+    assert_eq!(
+        32_500 * ONE_NEAR / 1000,
+        get_balance(&contract, get_account_nodra!())
+    );
+    // members 50% / 4
+    assert_eq!(
+        12_500 * ONE_NEAR / 1000,
+        get_balance(&contract, get_account_bob!())
+    );
+    assert_eq!(100 * ONE_NEAR, contract.guilds.income_amount);
+}
+
+#[test]
+fn deposit_guild_income_dust() {
+    let mut contract = get_contract();
+    set_deposit(get_account_bob!(), 3);
+    contract.deposit_guild_income(get_guild_id!());
+    // Note: Only whole yocto are credited, the dust stays in the treasury
+    assert_eq!(0, get_balance(&contract, get_account_bob!()));
+    assert_eq!(0, contract.guilds.income_amount);
+}
+
+#[test]
+fn deposit_guild_income_accrues() {
+    let mut contract = get_contract();
+    set_deposit(get_account_bob!(), 100 * ONE_NEAR);
+    contract.deposit_guild_income(get_guild_id!());
+    contract.deposit_guild_income(get_guild_id!());
+    assert_eq!(25 * ONE_NEAR, get_balance(&contract, get_account_bob!()));
+}
+
+#[test]
+#[should_panic(expected = "Requires an attached deposit")]
+fn deposit_guild_income_no_deposit() {
+    let mut contract = get_contract();
+    set_deposit(get_account_bob!(), 0);
+    contract.deposit_guild_income(get_guild_id!());
+}
+
+#[test]
+#[should_panic(expected = "missing guild")]
+fn deposit_guild_income_unknown_guild() {
+    let mut contract = get_contract();
+    set_deposit(get_account_bob!(), ONE_NEAR);
+    contract.deposit_guild_income("G:02".parse().unwrap());
+}
+
+#[test]
+#[should_panic(expected = "The guild is locked")]
+fn deposit_guild_income_locked() {
+    let mut contract = get_contract();
+    set_deposit(get_account_vecno!(), 0);
+    contract.act_lock_guild(get_guild_id!(), None);
+    set_deposit(get_account_bob!(), 10 * ONE_NEAR);
+    contract.deposit_guild_income(get_guild_id!());
+}
+
+#[test]
+#[should_panic(expected = "The guild is locked")]
+fn distribute_locked() {
+    let mut contract = get_contract();
+    set_deposit(get_account_vecno!(), 0);
+    contract.act_lock_guild(get_guild_id!(), None);
+    contract.guilds.distribute(&get_guild_id!(), 10 * ONE_NEAR);
+}
+
+// ==== claim_guild_income ====
+
+#[test]
+fn claim_guild_income() {
+    let mut contract = get_contract();
+    set_deposit(get_account_bob!(), 100 * ONE_NEAR);
+    contract.deposit_guild_income(get_guild_id!());
+
+    set_deposit(get_account_bob!(), 0);
+    contract.claim_guild_income(get_guild_id!());
+    assert_eq!(0, get_balance(&contract, get_account_bob!()));
+    assert_eq!(87_500 * ONE_NEAR / 1000, contract.guilds.income_amount);
+}
+
+#[test]
+#[should_panic(expected = "no income to claim")]
+fn claim_guild_income_empty() {
+    let mut contract = get_contract();
+    set_deposit(get_account_bob!(), 0);
+    contract.claim_guild_income(get_guild_id!());
+}
+
+#[test]
+#[should_panic(expected = "The guild is locked")]
+fn claim_guild_income_locked() {
+    let mut contract = get_contract();
+    set_deposit(get_account_bob!(), 100 * ONE_NEAR);
+    contract.deposit_guild_income(get_guild_id!());
+    set_deposit(get_account_vecno!(), 0);
+    contract.act_lock_guild(get_guild_id!(), None);
+
+    set_deposit(get_account_bob!(), 0);
+    contract.claim_guild_income(get_guild_id!());
+}
+
+// ==== resolve_claim_guild_income ====
+
+#[test]
+fn resolve_claim_guild_income_failed() {
+    let mut contract = get_contract();
+    set_deposit(get_account_bob!(), 100 * ONE_NEAR);
+    contract.deposit_guild_income(get_guild_id!());
+    set_deposit(get_account_bob!(), 0);
+    contract.claim_guild_income(get_guild_id!());
+
+    set_result(PromiseResult::Failed);
+    let amount = U128(12_500 * ONE_NEAR / 1000);
+    assert!(!contract.resolve_claim_guild_income(get_guild_id!(), get_account_bob!(), amount));
+    assert_eq!(amount.0, get_balance(&contract, get_account_bob!()));
+}
+
+#[test]
+fn resolve_claim_guild_income_succeeded() {
+    let mut contract = get_contract();
+    set_deposit(get_account_bob!(), 100 * ONE_NEAR);
+    contract.deposit_guild_income(get_guild_id!());
+    set_deposit(get_account_bob!(), 0);
+    contract.claim_guild_income(get_guild_id!());
+
+    set_result(PromiseResult::Successful(vec![]));
+    let amount = U128(12_500 * ONE_NEAR / 1000);
+    assert!(contract.resolve_claim_guild_income(get_guild_id!(), get_account_bob!(), amount));
+    assert_eq!(0, get_balance(&contract, get_account_bob!()));
+}
+
+#[test]
+#[should_panic(expected = "Method resolve_claim_guild_income is private")]
+fn resolve_claim_guild_income_private() {
+    let mut contract = get_contract();
+    set_deposit(get_account_bob!(), 0);
+    contract.resolve_claim_guild_income(get_guild_id!(), get_account_bob!(), U128(1));
+}
+
+// ==== get_share_amount ====
+
+#[test]
+fn get_share_amount_values() {
+    assert_eq!(0, get_share_amount(100, 1, 0));
+    assert_eq!(33, get_share_amount(100, 1, 3));
+    assert_eq!(
+        u128::MAX / 10000 * 5000,
+        get_share_amount(u128::MAX, 5000, 10000)
+    );
+}
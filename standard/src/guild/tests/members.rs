@@ -0,0 +1,352 @@
+use crate::guild::*;
+
+use crate::guild::test_utils::*;
+
+fn get_contract_sized(members_size: u64) -> GuildContract {
+    get_contract_with(
+        &get_guild_info(2, members_size),
+        &[(get_account_nitya!(), 5000), (get_account_nodra!(), 5000)],
+        &[
+            get_account_bob!(),
+            get_account_nitya!(),
+            get_account_nodra!(),
+            get_account_vecno!(),
+        ],
+    )
+}
+
+fn pass_guild_motion(contract: &mut GuildContract) {
+    set_caller(get_account_vecno!());
+    contract.vote_guild_motion(get_guild_id!());
+    set_caller(get_account_nodra!());
+    contract.vote_guild_motion(get_guild_id!());
+    set_caller(get_account_nitya!());
+    contract.vote_guild_motion(get_guild_id!());
+}
+
+fn get_members(contract: &GuildContract) -> GuildMembers {
+    contract
+        .guilds
+        .member_map
+        .get(&get_guild_id!())
+        .expect("missing guild members")
+}
+
+// ==== act_add_guild_member ====
+
+#[test]
+fn add_guild_member() {
+    let mut contract = get_contract_sized(5);
+    set_caller(get_account_vecno!());
+    contract.act_add_guild_member(
+        get_guild_id!(),
+        get_account_alice!(),
+        U128(50),
+        "add".to_string(),
+    );
+    pass_guild_motion(&mut contract);
+
+    let members = get_members(&contract);
+    members.assert_valid(5);
+    assert_eq!(450, members.value);
+    assert_eq!(Some(50), members.list.get(&get_account_alice!()));
+
+    let member_set = contract
+        .guilds
+        .account_map
+        .get(&get_account_alice!())
+        .expect("missing member set");
+    assert_eq!(50, member_set.value);
+    assert!(member_set.store.contains(&get_guild_id!()));
+
+    let guild = contract.guilds.guild_map.get(&get_guild_id!()).unwrap();
+    assert!(guild.motion.is_none());
+}
+
+#[test]
+#[should_panic(expected = "The members list can not be larger than the guilds size")]
+fn add_guild_member_max() {
+    let mut contract = get_contract_sized(4);
+    set_caller(get_account_vecno!());
+    contract.act_add_guild_member(
+        get_guild_id!(),
+        get_account_alice!(),
+        U128(50),
+        "add".to_string(),
+    );
+}
+
+#[test]
+#[should_panic(expected = "The account is already a guild member: bob.near")]
+fn add_guild_member_exists() {
+    let mut contract = get_contract_sized(5);
+    set_caller(get_account_vecno!());
+    contract.act_add_guild_member(
+        get_guild_id!(),
+        get_account_bob!(),
+        U128(50),
+        "add".to_string(),
+    );
+}
+
+// ==== act_remove_guild_member ====
+
+#[test]
+fn remove_guild_member() {
+    let mut contract = get_contract_sized(5);
+    set_caller(get_account_nodra!());
+    contract.act_remove_guild_member(get_guild_id!(), get_account_bob!(), "remove".to_string());
+    pass_guild_motion(&mut contract);
+
+    let members = get_members(&contract);
+    members.assert_valid(5);
+    assert_eq!(300, members.value);
+    assert!(members.list.get(&get_account_bob!()).is_none());
+    assert!(contract
+        .guilds
+        .account_map
+        .get(&get_account_bob!())
+        .is_none());
+}
+
+#[test]
+fn remove_guild_member_other_guild() {
+    let mut contract = get_contract_sized(5);
+
+    let other: GuildId = "G:02".parse().unwrap();
+    let mut member_map = HashMap::new();
+    member_map.insert(get_account_bob!(), 70_u128);
+    contract.guilds.register(
+        &other,
+        &GuildInfo {
+            ceo_id: get_account_bob!(),
+            ceo_share: 0,
+            board_size: 0,
+            board_share: 0,
+            members_size: 1,
+            members_share: 0,
+        },
+        &HashMap::new(),
+        &member_map,
+    );
+
+    set_caller(get_account_vecno!());
+    contract.act_remove_guild_member(get_guild_id!(), get_account_bob!(), "remove".to_string());
+    pass_guild_motion(&mut contract);
+
+    let member_set = contract
+        .guilds
+        .account_map
+        .get(&get_account_bob!())
+        .expect("missing member set");
+    assert_eq!(70, member_set.value);
+    assert_eq!(1, member_set.store.len());
+    assert!(member_set.store.contains(&other));
+}
+
+#[test]
+#[should_panic(expected = "The CEO must be a guild member")]
+fn remove_guild_member_ceo() {
+    let mut contract = get_contract_sized(5);
+    set_caller(get_account_nodra!());
+    contract.act_remove_guild_member(get_guild_id!(), get_account_vecno!(), "remove".to_string());
+}
+
+#[test]
+#[should_panic(expected = "Board member need to be a guild member: nitya.near")]
+fn remove_guild_member_board() {
+    let mut contract = get_contract_sized(5);
+    set_caller(get_account_nodra!());
+    contract.act_remove_guild_member(get_guild_id!(), get_account_nitya!(), "remove".to_string());
+}
+
+// ==== act_stake_guild_member ====
+
+#[test]
+fn stake_guild_member() {
+    let mut contract = get_contract_sized(5);
+    set_caller(get_account_vecno!());
+    contract.act_stake_guild_member(
+        get_guild_id!(),
+        get_account_bob!(),
+        U128(250),
+        "stake".to_string(),
+    );
+    pass_guild_motion(&mut contract);
+
+    let members = get_members(&contract);
+    members.assert_valid(5);
+    assert_eq!(550, members.value);
+    assert_eq!(Some(250), members.list.get(&get_account_bob!()));
+
+    let member_set = contract
+        .guilds
+        .account_map
+        .get(&get_account_bob!())
+        .expect("missing member set");
+    assert_eq!(250, member_set.value);
+}
+
+#[test]
+#[should_panic(expected = "The account is not a guild member: alice.near")]
+fn stake_guild_member_missing() {
+    let mut contract = get_contract_sized(5);
+    set_caller(get_account_vecno!());
+    contract.act_stake_guild_member(
+        get_guild_id!(),
+        get_account_alice!(),
+        U128(250),
+        "stake".to_string(),
+    );
+}
+
+// ==== act_update_guild_metadata ====
+
+fn get_metadata() -> GuildMetadata {
+    GuildMetadata {
+        name: "Guild".to_string(),
+        description: Some("A guild".to_string()),
+        icon: Some("data:image/svg+xml,<svg></svg>".to_string()),
+        media: None,
+        media_hash: None,
+        reference: None,
+        reference_hash: None,
+    }
+}
+
+#[test]
+fn update_guild_metadata() {
+    let mut contract = get_contract_sized(5);
+    assert!(contract.guild_metadata(get_guild_id!()).is_none());
+    set_caller(get_account_vecno!());
+    contract.act_update_guild_metadata(get_guild_id!(), get_metadata(), "name".to_string());
+    assert!(contract.guild_metadata(get_guild_id!()).is_none());
+    pass_guild_motion(&mut contract);
+
+    assert_eq!(
+        Some(get_metadata()),
+        contract.guild_metadata(get_guild_id!())
+    );
+}
+
+#[test]
+#[should_panic(expected = "Guild metadata requires a name")]
+fn update_guild_metadata_invalid() {
+    let mut contract = get_contract_sized(5);
+    set_caller(get_account_vecno!());
+    let metadata = GuildMetadata {
+        name: "".to_string(),
+        ..get_metadata()
+    };
+    contract.act_update_guild_metadata(get_guild_id!(), metadata, "name".to_string());
+}
+
+// ==== vote_guild_motion ====
+
+#[test]
+fn vote_guild_motion_no_tally() {
+    let mut contract = get_contract_sized(5);
+    set_caller(get_account_vecno!());
+    contract.act_stake_guild_member(
+        get_guild_id!(),
+        get_account_bob!(),
+        U128(250),
+        "stake".to_string(),
+    );
+    contract.vote_guild_motion(get_guild_id!());
+
+    assert_eq!(400, get_members(&contract).value);
+    let guild = contract.guilds.guild_map.get(&get_guild_id!()).unwrap();
+    assert!(guild.motion.is_some());
+}
+
+#[test]
+fn vote_guild_motion_weighted() {
+    let mut contract = get_contract_sized(5);
+    set_caller(get_account_vecno!());
+    contract.act_stake_guild_member(
+        get_guild_id!(),
+        get_account_vecno!(),
+        U128(500),
+        "stake".to_string(),
+    );
+    pass_guild_motion(&mut contract);
+
+    contract.votes.set_policy(
+        MotionKind::ChangeGuild,
+        &VotePolicy {
+            quorum: 0,
+            threshold: 5000,
+            roles: vec![VoteRoles {
+                ceo: false,
+                board: false,
+                members: true,
+            }],
+            weighted: true,
+            delay: Duration::from_nanos(0),
+            reveal: EXPIRE_24H,
+            bond: None,
+        },
+    );
+
+    // Note: One out of four voices holds 500 out of 800 stake
+    set_caller(get_account_vecno!());
+    contract.act_stake_guild_member(
+        get_guild_id!(),
+        get_account_bob!(),
+        U128(250),
+        "stake".to_string(),
+    );
+    contract.vote_guild_motion(get_guild_id!());
+
+    let members = get_members(&contract);
+    assert_eq!(950, members.value);
+    assert_eq!(Some(250), members.list.get(&get_account_bob!()));
+}
+
+#[test]
+#[should_panic(expected = "unauthorized call")]
+fn act_guild_motion_not_authorized() {
+    let mut contract = get_contract_sized(5);
+    set_caller(get_account_bob!());
+    contract.act_stake_guild_member(
+        get_guild_id!(),
+        get_account_bob!(),
+        U128(250),
+        "stake".to_string(),
+    );
+}
+
+#[test]
+#[should_panic(expected = "other guild motion is active")]
+fn act_guild_motion_active() {
+    let mut contract = get_contract_sized(5);
+    set_caller(get_account_vecno!());
+    contract.act_stake_guild_member(
+        get_guild_id!(),
+        get_account_bob!(),
+        U128(250),
+        "stake".to_string(),
+    );
+    contract.act_add_guild_member(
+        get_guild_id!(),
+        get_account_alice!(),
+        U128(50),
+        "add".to_string(),
+    );
+}
+
+#[test]
+#[should_panic(expected = "The guild is locked")]
+fn vote_guild_motion_locked() {
+    let mut contract = get_contract_sized(5);
+    set_caller(get_account_vecno!());
+    contract.act_stake_guild_member(
+        get_guild_id!(),
+        get_account_bob!(),
+        U128(250),
+        "stake".to_string(),
+    );
+    contract.act_lock_guild(get_guild_id!(), None);
+    contract.vote_guild_motion(get_guild_id!());
+}
@@ -0,0 +1,175 @@
+use crate::guild::*;
+
+use crate::guild::test_utils::*;
+
+/// Seats nodra alone on the board with bob, nodra and vecno as members.
+fn get_contract_nodra() -> GuildContract {
+    get_contract_with(
+        &get_guild_info(1, 4),
+        &[(get_account_nodra!(), 5000)],
+        &[
+            get_account_bob!(),
+            get_account_nodra!(),
+            get_account_vecno!(),
+        ],
+    )
+}
+
+fn get_action() -> ProposalAction {
+    ProposalAction {
+        receiver_id: get_account_bob!(),
+        method_name: "ping".to_string(),
+        args: "{}".to_string(),
+        deposit: U128(0),
+        gas: U64(20_000_000_000_000),
+    }
+}
+
+// ==== motion ids ====
+
+#[test]
+fn motion_ids_same_block() {
+    let mut contract = get_contract_nodra();
+    set_caller(get_account_nodra!());
+    contract.act_proposal_contract(get_action(), "first".to_string());
+    contract.act_proposal_contract(get_action(), "second".to_string());
+    contract.act_lock_guild(get_guild_id!(), Some("lock".to_string()));
+
+    assert_eq!(3, contract.votes.motion_map.len());
+    assert!(contract
+        .guilds
+        .proposal_map
+        .get(&"XC:1".parse().unwrap())
+        .is_some());
+    assert!(contract
+        .guilds
+        .proposal_map
+        .get(&"XC:2".parse().unwrap())
+        .is_some());
+    let guild = contract.guilds.guild_map.get(&get_guild_id!()).unwrap();
+    assert_eq!(Some("LG:3".parse().unwrap()), guild.vote);
+}
+
+// ==== guild_latest_motion ====
+
+#[test]
+fn guild_latest_motion() {
+    let mut contract = get_contract_nodra();
+    assert!(contract.guild_latest_motion(get_guild_id!()).is_none());
+
+    set_caller(get_account_nodra!());
+    contract.act_add_guild_member(
+        get_guild_id!(),
+        get_account_alice!(),
+        U128(50),
+        "add".to_string(),
+    );
+    assert_eq!(
+        Some("GM:1".parse().unwrap()),
+        contract.guild_latest_motion(get_guild_id!())
+    );
+
+    // Note: Contract motions are not namespaced by a guild
+    contract.act_proposal_contract(get_action(), "proposal".to_string());
+    contract.act_lock_guild(get_guild_id!(), Some("lock".to_string()));
+    assert_eq!(
+        Some("LG:3".parse().unwrap()),
+        contract.guild_latest_motion(get_guild_id!())
+    );
+}
+
+// ==== guild_active_motions ====
+
+#[test]
+fn guild_active_motions() {
+    let mut contract = get_contract_nodra();
+    assert!(contract.guild_active_motions(get_guild_id!()).is_empty());
+
+    set_caller(get_account_nodra!());
+    contract.act_add_guild_member(
+        get_guild_id!(),
+        get_account_alice!(),
+        U128(50),
+        "add".to_string(),
+    );
+    contract.act_lock_guild(get_guild_id!(), Some("lock".to_string()));
+    let active = contract.guild_active_motions(get_guild_id!());
+    assert_eq!(2, active.len());
+    assert!(active.contains(&"GM:1".parse().unwrap()));
+    assert!(active.contains(&"LG:2".parse().unwrap()));
+
+    // Note: The lock motion passes with the single board vote
+    contract.vote_lock_guild(get_guild_id!());
+    assert_eq!(
+        vec!["GM:1".parse::<MotionId>().unwrap()],
+        contract.guild_active_motions(get_guild_id!())
+    );
+
+    set_expired();
+    assert!(contract.guild_active_motions(get_guild_id!()).is_empty());
+}
+
+#[test]
+fn guild_active_motions_unknown_guild() {
+    let contract = get_contract_nodra();
+    assert!(contract
+        .guild_active_motions("G:02".parse().unwrap())
+        .is_empty());
+}
+
+// ==== guild_contract_state ====
+
+#[test]
+fn guild_contract_state() {
+    let mut contract = get_contract_nodra();
+    set_caller(get_account_nodra!());
+    contract.act_lock_contract(Some("lock".to_string()), false);
+
+    let state = contract.guild_contract_state();
+    assert_eq!(LockedFor::Locking, state.lock);
+    assert_eq!(Some("LC:1".parse().unwrap()), state.vote);
+
+    let json = serde_json::to_value(&state).unwrap();
+    assert_eq!("Locking", json["lock"]);
+    assert_eq!("LC:1", json["vote"]);
+    assert!(json["time"].is_null());
+}
+
+// ==== guild_motion ====
+
+#[test]
+fn guild_motion() {
+    let mut contract = get_contract_nodra();
+    set_caller(get_account_nodra!());
+    contract.act_add_guild_member(
+        get_guild_id!(),
+        get_account_alice!(),
+        U128(50),
+        "add".to_string(),
+    );
+    contract.vote_guild_motion(get_guild_id!());
+
+    let id: MotionId = "GM:1".parse().unwrap();
+    let motion = contract.guild_motion(id.clone()).expect("missing motion");
+    assert_eq!(MotionStatus::Open, motion.status);
+    let tally = motion
+        .tally
+        .get(&get_vote_id_agree())
+        .expect("missing tally");
+    assert_eq!(1, tally.board);
+    assert_eq!(U128(100), tally.weight);
+
+    assert_eq!(
+        Some(get_vote_id_agree()),
+        contract.guild_motion_vote(id.clone(), get_account_nodra!())
+    );
+    assert!(contract
+        .guild_motion_vote(id, get_account_vecno!())
+        .is_none());
+    assert_eq!(
+        1,
+        contract
+            .guild_motions(Some(MotionStatus::Open), None, None)
+            .len()
+    );
+}
@@ -0,0 +1,135 @@
+use crate::guild::*;
+
+use crate::guild::test_utils::*;
+
+fn get_policy_half_board() -> VotePolicy {
+    VotePolicy {
+        quorum: 0,
+        threshold: 5000,
+        roles: vec![VoteRoles {
+            ceo: false,
+            board: true,
+            members: false,
+        }],
+        weighted: false,
+        delay: Duration::from_nanos(0),
+        reveal: Duration::from_hours(6),
+        bond: None,
+    }
+}
+
+fn pass_policy_motion(contract: &mut GuildContract) {
+    set_caller(get_account_vecno!());
+    contract.vote_policy_contract();
+    set_caller(get_account_nodra!());
+    contract.vote_policy_contract();
+    set_caller(get_account_nitya!());
+    contract.vote_policy_contract();
+    set_caller(get_account_bob!());
+    contract.vote_policy_contract();
+}
+
+// ==== act_policy_contract ====
+
+#[test]
+fn act_policy_contract_as_ceo() {
+    let mut contract = get_contract();
+    set_caller(get_account_vecno!());
+    contract.act_policy_contract(
+        MotionKind::LockContract,
+        get_policy_half_board(),
+        "policy".to_string(),
+    );
+
+    let state = contract.guilds.state.get().expect("missing state");
+    let motion = state.policy.expect("missing policy motion");
+    assert_eq!(MotionKind::LockContract, motion.kind);
+    assert_eq!(get_policy_half_board(), motion.policy);
+}
+
+#[test]
+#[should_panic(expected = "unauthorized call")]
+fn act_policy_contract_not_authorized() {
+    let mut contract = get_contract();
+    set_caller(get_account_bob!());
+    contract.act_policy_contract(
+        MotionKind::LockContract,
+        get_policy_half_board(),
+        "policy".to_string(),
+    );
+}
+
+#[test]
+#[should_panic(expected = "Threshold must be between 1 and 100_00 basis points")]
+fn act_policy_contract_invalid() {
+    let mut contract = get_contract();
+    let mut policy = get_policy_half_board();
+    policy.threshold = 0;
+    set_caller(get_account_vecno!());
+    contract.act_policy_contract(MotionKind::LockContract, policy, "policy".to_string());
+}
+
+// ==== vote_policy_contract ====
+
+#[test]
+fn vote_policy_contract_lock_board() {
+    let mut contract = get_contract();
+    assert_eq!(
+        get_default_policy(MotionKind::LockContract),
+        contract.votes.get_policy(MotionKind::LockContract)
+    );
+
+    set_caller(get_account_vecno!());
+    contract.act_policy_contract(
+        MotionKind::LockContract,
+        get_policy_half_board(),
+        "policy".to_string(),
+    );
+    pass_policy_motion(&mut contract);
+
+    let state = contract.guilds.state.get().expect("missing state");
+    assert!(state.policy.is_none());
+    assert_eq!(
+        get_policy_half_board(),
+        contract.votes.get_policy(MotionKind::LockContract)
+    );
+
+    // A single board member now passes the lock motion
+    set_caller(get_account_nodra!());
+    contract.act_lock_contract(Some("lock".to_string()), false);
+    contract.vote_lock_contract();
+
+    let state = contract.guilds.state.get().expect("missing state");
+    assert_eq!(LockedFor::Emergency, state.lock);
+}
+
+#[test]
+fn vote_policy_contract_no_tally() {
+    let mut contract = get_contract();
+    set_caller(get_account_vecno!());
+    contract.act_policy_contract(
+        MotionKind::LockContract,
+        get_policy_half_board(),
+        "policy".to_string(),
+    );
+    contract.vote_policy_contract();
+    set_caller(get_account_nodra!());
+    contract.vote_policy_contract();
+    set_caller(get_account_nitya!());
+    contract.vote_policy_contract();
+
+    let state = contract.guilds.state.get().expect("missing state");
+    assert!(state.policy.is_some());
+    assert_eq!(
+        get_default_policy(MotionKind::LockContract),
+        contract.votes.get_policy(MotionKind::LockContract)
+    );
+}
+
+#[test]
+#[should_panic(expected = "missing policy motion")]
+fn vote_policy_contract_no_motion() {
+    let mut contract = get_contract();
+    set_caller(get_account_vecno!());
+    contract.vote_policy_contract();
+}
@@ -0,0 +1,309 @@
+use crate::guild::*;
+
+use crate::guild::test_utils::*;
+use near_sdk::ONE_NEAR;
+
+macro_rules! proposal_id {
+    () => {
+        "XC:1".parse::<MotionId>().unwrap()
+    };
+}
+
+fn get_action() -> ProposalAction {
+    ProposalAction {
+        receiver_id: get_account_bob!(),
+        method_name: "ping".to_string(),
+        args: "{\"value\":1}".to_string(),
+        deposit: U128(0),
+        gas: U64(20_000_000_000_000),
+    }
+}
+
+fn get_proposal(contract: &GuildContract) -> Proposal {
+    contract
+        .guilds
+        .proposal_map
+        .get(&proposal_id!())
+        .expect("missing proposal")
+}
+
+fn open_proposal(contract: &mut GuildContract) {
+    set_caller(get_account_nodra!());
+    contract.act_proposal_contract(get_action(), "proposal".to_string());
+}
+
+fn pass_proposal(contract: &mut GuildContract) {
+    open_proposal(contract);
+    set_caller(get_account_vecno!());
+    contract.vote_proposal_contract(proposal_id!());
+    set_caller(get_account_nodra!());
+    contract.vote_proposal_contract(proposal_id!());
+    set_caller(get_account_nitya!());
+    contract.vote_proposal_contract(proposal_id!());
+}
+
+// ==== act_proposal_contract ====
+
+#[test]
+fn act_proposal_contract() {
+    let mut contract = get_contract();
+    open_proposal(&mut contract);
+
+    let proposal = get_proposal(&contract);
+    assert_eq!(ProposalStatus::Voting, proposal.status);
+    assert_eq!(get_action(), proposal.action);
+
+    let motion = contract.votes.motion_map.get(&proposal_id!()).unwrap();
+    assert!(!motion.executed);
+}
+
+#[test]
+#[should_panic(expected = "unauthorized call")]
+fn act_proposal_contract_not_authorized() {
+    let mut contract = get_contract();
+    set_caller(get_account_bob!());
+    contract.act_proposal_contract(get_action(), "proposal".to_string());
+}
+
+#[test]
+#[should_panic(expected = "Proposal action args have to be valid JSON")]
+fn act_proposal_contract_args() {
+    let mut contract = get_contract();
+    set_caller(get_account_nodra!());
+    contract.act_proposal_contract(
+        ProposalAction {
+            args: "{value".to_string(),
+            ..get_action()
+        },
+        "proposal".to_string(),
+    );
+}
+
+#[test]
+#[should_panic(expected = "Proposal action gas must be between 1 and 250 TGas")]
+fn act_proposal_contract_gas() {
+    let mut contract = get_contract();
+    set_caller(get_account_nodra!());
+    contract.act_proposal_contract(
+        ProposalAction {
+            gas: U64(MAX_GAS_FOR_PROPOSAL.0 + 1),
+            ..get_action()
+        },
+        "proposal".to_string(),
+    );
+}
+
+#[test]
+fn act_proposal_contract_deposit() {
+    let mut contract = get_contract();
+    set_caller(get_account_nodra!());
+    let action = ProposalAction {
+        deposit: U128(10 * ONE_NEAR),
+        ..get_action()
+    };
+    contract.act_proposal_contract(action, "proposal".to_string());
+    assert_eq!(U128(10 * ONE_NEAR), contract.guild_treasury_locked());
+}
+
+#[test]
+#[should_panic(expected = "Proposal deposit exceeds the available amount")]
+fn act_proposal_contract_deposit_available() {
+    let mut contract = get_contract();
+    set_caller(get_account_nodra!());
+    let action = ProposalAction {
+        deposit: U128(1000 * ONE_NEAR),
+        ..get_action()
+    };
+    contract.act_proposal_contract(action, "proposal".to_string());
+}
+
+// ==== vote_proposal_contract ====
+
+#[test]
+fn vote_proposal_contract() {
+    let mut contract = get_contract();
+    open_proposal(&mut contract);
+    set_caller(get_account_vecno!());
+    contract.vote_proposal_contract(proposal_id!());
+    assert_eq!(ProposalStatus::Voting, get_proposal(&contract).status);
+
+    set_caller(get_account_nodra!());
+    contract.vote_proposal_contract(proposal_id!());
+    assert_eq!(ProposalStatus::Voting, get_proposal(&contract).status);
+
+    set_caller(get_account_nitya!());
+    contract.vote_proposal_contract(proposal_id!());
+    assert_eq!(ProposalStatus::Approved, get_proposal(&contract).status);
+
+    // Note: The motion closes when the action is approved
+    let motion = contract.votes.motion_map.get(&proposal_id!()).unwrap();
+    assert!(motion.executed);
+}
+
+#[test]
+#[should_panic(expected = "proposal is not voting")]
+fn vote_proposal_contract_approved() {
+    let mut contract = get_contract();
+    pass_proposal(&mut contract);
+    set_caller(get_account_bob!());
+    contract.vote_proposal_contract(proposal_id!());
+}
+
+#[test]
+#[should_panic(expected = "unauthorized call")]
+fn vote_proposal_contract_not_authorized() {
+    let mut contract = get_contract();
+    open_proposal(&mut contract);
+    set_caller("other.near".parse::<AccountId>().unwrap());
+    contract.vote_proposal_contract(proposal_id!());
+}
+
+// ==== exec_proposal_contract ====
+
+#[test]
+fn exec_proposal_contract() {
+    let mut contract = get_contract();
+    pass_proposal(&mut contract);
+    set_caller("other.near".parse::<AccountId>().unwrap());
+    contract.exec_proposal_contract(proposal_id!());
+
+    assert_eq!(ProposalStatus::Executing, get_proposal(&contract).status);
+    let motion = contract.votes.motion_map.get(&proposal_id!()).unwrap();
+    assert!(motion.executed);
+}
+
+#[test]
+fn exec_proposal_contract_expired() {
+    let mut contract = get_contract();
+    pass_proposal(&mut contract);
+
+    // Note: An approved motion does not expire before its action runs
+    set_expired();
+    contract.exec_proposal_contract(proposal_id!());
+    assert_eq!(ProposalStatus::Executing, get_proposal(&contract).status);
+}
+
+#[test]
+#[should_panic(expected = "proposal is not approved")]
+fn exec_proposal_contract_voting() {
+    let mut contract = get_contract();
+    open_proposal(&mut contract);
+    contract.exec_proposal_contract(proposal_id!());
+}
+
+#[test]
+#[should_panic(expected = "proposal is not approved")]
+fn exec_proposal_contract_twice() {
+    let mut contract = get_contract();
+    pass_proposal(&mut contract);
+    contract.exec_proposal_contract(proposal_id!());
+    contract.exec_proposal_contract(proposal_id!());
+}
+
+#[test]
+#[should_panic(expected = "contract is not unlocked")]
+fn exec_proposal_contract_locked() {
+    let mut contract = get_contract();
+    pass_proposal(&mut contract);
+    set_caller(get_account_vecno!());
+    contract.act_lock_contract(None, false);
+    contract.exec_proposal_contract(proposal_id!());
+}
+
+// ==== resolve_proposal_contract ====
+
+#[test]
+fn resolve_proposal_contract_succeeded() {
+    let mut contract = get_contract();
+    pass_proposal(&mut contract);
+    contract.exec_proposal_contract(proposal_id!());
+
+    set_result(PromiseResult::Successful(vec![]));
+    assert!(contract.resolve_proposal_contract(proposal_id!()));
+    assert_eq!(ProposalStatus::Succeeded, get_proposal(&contract).status);
+}
+
+#[test]
+fn resolve_proposal_contract_failed() {
+    let mut contract = get_contract();
+    pass_proposal(&mut contract);
+    contract.exec_proposal_contract(proposal_id!());
+
+    set_result(PromiseResult::Failed);
+    assert!(!contract.resolve_proposal_contract(proposal_id!()));
+    assert_eq!(ProposalStatus::Failed, get_proposal(&contract).status);
+}
+
+#[test]
+fn resolve_proposal_contract_deposit() {
+    let mut contract = get_contract();
+    set_caller(get_account_nodra!());
+    let action = ProposalAction {
+        deposit: U128(ONE_NEAR),
+        ..get_action()
+    };
+    contract.act_proposal_contract(action, "proposal".to_string());
+    for account in [
+        get_account_vecno!(),
+        get_account_nodra!(),
+        get_account_nitya!(),
+    ] {
+        set_caller(account);
+        contract.vote_proposal_contract(proposal_id!());
+    }
+    contract.exec_proposal_contract(proposal_id!());
+    assert_eq!(U128(ONE_NEAR), contract.guild_treasury_locked());
+
+    set_result(PromiseResult::Successful(vec![]));
+    contract.resolve_proposal_contract(proposal_id!());
+    assert_eq!(U128(0), contract.guild_treasury_locked());
+}
+
+#[test]
+#[should_panic(expected = "Method resolve_proposal_contract is private")]
+fn resolve_proposal_contract_private() {
+    let mut contract = get_contract();
+    pass_proposal(&mut contract);
+    contract.exec_proposal_contract(proposal_id!());
+    contract.resolve_proposal_contract(proposal_id!());
+}
+
+// ==== finalize_motion ====
+
+#[test]
+fn finalize_motion_proposal_deposit() {
+    let mut contract = get_contract();
+    set_caller(get_account_nodra!());
+    let action = ProposalAction {
+        deposit: U128(ONE_NEAR),
+        ..get_action()
+    };
+    contract.act_proposal_contract(action, "proposal".to_string());
+
+    set_expired();
+    contract.finalize_motion(proposal_id!());
+    assert_eq!(U128(0), contract.guild_treasury_locked());
+    assert_eq!(ProposalStatus::Expired, get_proposal(&contract).status);
+}
+
+#[test]
+#[should_panic(expected = "proposal is not approved")]
+fn finalize_motion_proposal_exec() {
+    let mut contract = get_contract();
+    set_caller(get_account_nodra!());
+    contract.act_proposal_contract(get_action(), "proposal".to_string());
+
+    set_expired();
+    contract.finalize_motion(proposal_id!());
+    contract.exec_proposal_contract(proposal_id!());
+}
+
+#[test]
+#[should_panic(expected = "The proposal has to be executed first")]
+fn finalize_motion_proposal_approved() {
+    let mut contract = get_contract();
+    pass_proposal(&mut contract);
+
+    set_expired();
+    contract.finalize_motion(proposal_id!());
+}
@@ -0,0 +1,252 @@
+use crate::guild::*;
+
+use crate::guild::test_utils::*;
+
+const DELAY: Duration = Duration::from_hours(1);
+
+/// Delays the contract lock and unlock motions of a guild with alice as a member.
+fn get_contract_delayed() -> GuildContract {
+    let mut contract = get_contract_with(
+        &get_guild_info(2, 5),
+        &[(get_account_nitya!(), 5000), (get_account_nodra!(), 5000)],
+        &[
+            get_account_alice!(),
+            get_account_bob!(),
+            get_account_nitya!(),
+            get_account_nodra!(),
+            get_account_vecno!(),
+        ],
+    );
+    for kind in [MotionKind::LockContract, MotionKind::UnlockContract] {
+        let policy = VotePolicy {
+            delay: DELAY,
+            ..get_default_policy(kind)
+        };
+        contract.votes.set_policy(kind, &policy);
+    }
+    contract
+}
+
+fn set_delayed(account: AccountId) {
+    set_caller_at(account, DELAY.as_nanos());
+}
+
+fn get_state(contract: &GuildContract) -> State {
+    contract.guild_contract_state()
+}
+
+fn set_guardian(contract: &mut GuildContract, guardian: Option<AccountId>) {
+    set_caller(get_account_vecno!());
+    let change = ContractChange::Guardian { guardian };
+    contract.act_change_contract(change, "guardian".to_string());
+    for account in [
+        get_account_vecno!(),
+        get_account_nodra!(),
+        get_account_nitya!(),
+        get_account_bob!(),
+    ] {
+        set_caller(account);
+        contract.vote_change_contract();
+    }
+}
+
+fn queue_lock(contract: &mut GuildContract) -> MotionId {
+    set_caller(get_account_nodra!());
+    contract.act_lock_contract(Some("lock".to_string()), false);
+    let id = get_state(contract).vote.unwrap();
+    contract.vote_lock_contract();
+    set_caller(get_account_nitya!());
+    contract.vote_lock_contract();
+    id
+}
+
+// ==== queue contract ====
+
+#[test]
+fn queue_lock_contract() {
+    let mut contract = get_contract_delayed();
+    let id = queue_lock(&mut contract);
+
+    // Note: The passed lock waits in the queue, the contract keeps locking
+    let state = get_state(&contract);
+    assert!(state.lock == LockedFor::Locking);
+    assert_eq!(Some(id.clone()), state.vote);
+    let queued = contract.guild_queued_motions();
+    assert_eq!(1, queued.len());
+    assert_eq!(id, queued[0].0);
+    assert_eq!(Timestamp::now() + DELAY, queued[0].1.eta);
+}
+
+#[test]
+fn queue_lock_contract_no_delay() {
+    let mut contract = get_contract_delayed();
+    let policy = get_default_policy(MotionKind::LockContract);
+    contract.votes.set_policy(MotionKind::LockContract, &policy);
+    queue_lock(&mut contract);
+
+    assert!(get_state(&contract).lock == LockedFor::Emergency);
+    assert!(contract.guild_queued_motions().is_empty());
+}
+
+#[test]
+fn queue_lock_contract_ceo() {
+    let mut contract = get_contract_delayed();
+    queue_lock(&mut contract);
+
+    // Note: The CEO locks without waiting for the delay
+    set_caller(get_account_vecno!());
+    contract.vote_lock_contract();
+    let state = get_state(&contract);
+    assert!(state.lock == LockedFor::Emergency);
+    assert!(state.vote.is_none());
+    assert!(contract.guild_queued_motions().is_empty());
+}
+
+// ==== exec_queued_contract ====
+
+#[test]
+fn exec_queued_contract_lock() {
+    let mut contract = get_contract_delayed();
+    let id = queue_lock(&mut contract);
+    set_delayed(get_account_bob!());
+    contract.exec_queued_contract(id);
+
+    let state = get_state(&contract);
+    assert!(state.lock == LockedFor::Emergency);
+    assert!(state.vote.is_none());
+    assert!(contract.guild_queued_motions().is_empty());
+}
+
+#[test]
+fn exec_queued_contract_unlock() {
+    let mut contract = get_contract_delayed();
+    set_caller(get_account_vecno!());
+    contract.act_lock_contract(None, false);
+    ContractLocking::act_unlock_contract(&mut contract, "unlock".to_string());
+    let id = get_state(&contract).vote.unwrap();
+    contract.vote_unlock_contract();
+    set_caller(get_account_nodra!());
+    contract.vote_unlock_contract();
+    set_caller(get_account_nitya!());
+    contract.vote_unlock_contract();
+    assert!(get_state(&contract).lock == LockedFor::Emergency);
+
+    set_delayed(get_account_bob!());
+    contract.exec_queued_contract(id);
+    assert!(get_state(&contract).lock == LockedFor::None);
+}
+
+#[test]
+#[should_panic(expected = "The queued motion is still delayed")]
+fn exec_queued_contract_delayed() {
+    let mut contract = get_contract_delayed();
+    let id = queue_lock(&mut contract);
+    contract.exec_queued_contract(id);
+}
+
+// ==== cancel_queued_contract ====
+
+#[test]
+fn cancel_queued_contract() {
+    let mut contract = get_contract_delayed();
+    set_guardian(&mut contract, Some(get_account_alice!()));
+    assert_eq!(Some(get_account_alice!()), get_state(&contract).guardian);
+    let id = queue_lock(&mut contract);
+
+    set_caller(get_account_alice!());
+    contract.cancel_queued_contract(id);
+    let state = get_state(&contract);
+    assert!(state.lock == LockedFor::None);
+    assert!(state.vote.is_none());
+    assert!(contract.guild_queued_motions().is_empty());
+}
+
+#[test]
+#[should_panic(expected = "unauthorized call")]
+fn cancel_queued_contract_not_guardian() {
+    let mut contract = get_contract_delayed();
+    let id = queue_lock(&mut contract);
+    set_caller(get_account_alice!());
+    contract.cancel_queued_contract(id);
+}
+
+#[test]
+#[should_panic(expected = "contract is not unlocked")]
+fn act_change_contract_locking() {
+    let mut contract = get_contract_delayed();
+    queue_lock(&mut contract);
+    set_caller(get_account_vecno!());
+    let change = ContractChange::Guardian {
+        guardian: Some(get_account_vecno!()),
+    };
+    contract.act_change_contract(change, "guardian".to_string());
+}
+
+#[test]
+fn vote_change_contract_no_tally() {
+    let mut contract = get_contract_delayed();
+    set_caller(get_account_nodra!());
+    let change = ContractChange::Guardian {
+        guardian: Some(get_account_nodra!()),
+    };
+    contract.act_change_contract(change, "guardian".to_string());
+    contract.vote_change_contract();
+
+    // Note: The CEO can not set the guardian on its own
+    set_caller(get_account_vecno!());
+    contract.vote_change_contract();
+    let state = get_state(&contract);
+    assert!(state.guardian.is_none());
+    assert!(state.change.is_some());
+}
+
+#[test]
+#[should_panic(expected = "unauthorized call")]
+fn act_change_contract_not_authorized() {
+    let mut contract = get_contract_delayed();
+    set_caller(get_account_bob!());
+    let change = ContractChange::Guardian {
+        guardian: Some(get_account_bob!()),
+    };
+    contract.act_change_contract(change, "guardian".to_string());
+}
+
+// ==== act_cancel_queued_contract ====
+
+#[test]
+fn act_cancel_queued_contract() {
+    let mut contract = get_contract_delayed();
+    let id = queue_lock(&mut contract);
+
+    set_caller(get_account_nodra!());
+    contract.act_cancel_queued_contract(id.clone(), "cancel".to_string());
+    let queued = contract.guild_queued_motions();
+    let cancel = queued[0].1.cancel.clone().unwrap();
+    let motion = contract.guild_motion(cancel).unwrap();
+    assert_eq!(Timestamp::now() + DELAY, motion.info.expires_at);
+
+    // Note: The board passes the counter-motion
+    set_caller(get_account_nitya!());
+    contract.vote_cancel_queued_contract(id);
+    assert!(get_state(&contract).lock == LockedFor::None);
+    assert!(contract.guild_queued_motions().is_empty());
+}
+
+#[test]
+#[should_panic(expected = "other cancel motion is active")]
+fn act_cancel_queued_contract_active() {
+    let mut contract = get_contract_delayed();
+    let id = queue_lock(&mut contract);
+    set_caller(get_account_bob!());
+    contract.act_cancel_queued_contract(id.clone(), "cancel".to_string());
+    contract.act_cancel_queued_contract(id, "cancel".to_string());
+}
+
+#[test]
+#[should_panic(expected = "The queued motion is ready for execution")]
+fn act_cancel_queued_contract_due() {
+    let mut contract = get_contract_delayed();
+    let id = queue_lock(&mut contract);
+    set_delayed(get_account_bob!());
+    contract.act_cancel_queued_contract(id, "cancel".to_string());
+}
@@ -0,0 +1,226 @@
+use crate::guild::*;
+
+use crate::guild::test_utils::*;
+use near_sdk::ONE_NEAR;
+
+macro_rules! spend_id {
+    () => {
+        "SC:1".parse::<MotionId>().unwrap()
+    };
+}
+
+fn open_spend(contract: &mut GuildContract) {
+    set_caller(get_account_nodra!());
+    contract.act_spend_contract(get_account_bob!(), U128(10 * ONE_NEAR), "spend".to_string());
+}
+
+fn pass_spend(contract: &mut GuildContract) {
+    for account in [
+        get_account_vecno!(),
+        get_account_nodra!(),
+        get_account_nitya!(),
+    ] {
+        set_caller(account);
+        contract.vote_spend_contract(spend_id!());
+    }
+    set_result(PromiseResult::Successful(vec![]));
+    contract.resolve_spend_contract(spend_id!());
+}
+
+fn stake_bob(contract: &mut GuildContract, value: u128) {
+    let change = GuildChange::StakeMember {
+        account_id: get_account_bob!(),
+        value,
+    };
+    contract.guilds.apply_change(&get_guild_id!(), &change);
+}
+
+fn add_alice(contract: &mut GuildContract) {
+    let change = GuildChange::AddMember {
+        account_id: get_account_alice!(),
+        value: 100,
+    };
+    contract.guilds.apply_change(&get_guild_id!(), &change);
+}
+
+// ==== motion snapshot ====
+
+#[test]
+fn snapshot_count() {
+    let mut contract = get_contract();
+    open_spend(&mut contract);
+    add_alice(&mut contract);
+
+    let snapshot = contract.votes.get_snapshot(&spend_id!());
+    assert_eq!(
+        MotionCount {
+            board: 2,
+            members: 4,
+            value: 400,
+        },
+        snapshot.count
+    );
+    assert_eq!(5, contract.guilds.get_count(&get_guild_id!()).members);
+    assert!(snapshot.is_ceo(&get_account_vecno!()));
+    assert!(snapshot.is_board(&get_account_nitya!()));
+    assert!(!contract
+        .guilds
+        .is_member_at(&snapshot, &get_account_alice!()));
+}
+
+#[test]
+fn snapshot_stake_change() {
+    let mut contract = get_contract();
+    open_spend(&mut contract);
+    let change = GuildChange::StakeMember {
+        account_id: get_account_bob!(),
+        value: 1000,
+    };
+    contract.guilds.apply_change(&get_guild_id!(), &change);
+
+    set_caller(get_account_bob!());
+    contract.vote_spend_contract(spend_id!());
+    let motion = contract.guild_motion(spend_id!()).unwrap();
+    let tally = motion.tally.get(&get_vote_id_agree()).unwrap();
+    assert_eq!((1, U128(100)), (tally.members, tally.weight));
+}
+
+#[test]
+fn snapshot_stake_checkpoint() {
+    let mut contract = get_contract();
+    open_spend(&mut contract);
+    for value in [1000, 10] {
+        let change = GuildChange::StakeMember {
+            account_id: get_account_bob!(),
+            value,
+        };
+        contract.guilds.apply_change(&get_guild_id!(), &change);
+    }
+    open_spend(&mut contract);
+
+    // Note: Each motion reads the stake at its own checkpoint
+    let snapshot = contract.votes.get_snapshot(&spend_id!());
+    assert_eq!(
+        100,
+        contract
+            .guilds
+            .get_weight_at(&snapshot, &get_account_bob!())
+    );
+    let snapshot = contract
+        .votes
+        .get_snapshot(&"SC:2".parse::<MotionId>().unwrap());
+    assert_eq!(
+        10,
+        contract
+            .guilds
+            .get_weight_at(&snapshot, &get_account_bob!())
+    );
+}
+
+#[test]
+fn snapshot_removed_member() {
+    let mut contract = get_contract();
+    open_spend(&mut contract);
+    let change = GuildChange::RemoveMember {
+        account_id: get_account_bob!(),
+    };
+    contract.guilds.apply_change(&get_guild_id!(), &change);
+
+    // Note: The member was eligible when the motion was issued
+    set_caller(get_account_bob!());
+    contract.vote_spend_contract(spend_id!());
+    assert_eq!(
+        Some(get_vote_id_agree()),
+        contract.guild_motion_vote(spend_id!(), get_account_bob!())
+    );
+}
+
+#[test]
+#[should_panic(expected = "unauthorized call")]
+fn snapshot_added_member() {
+    let mut contract = get_contract();
+    open_spend(&mut contract);
+    add_alice(&mut contract);
+
+    set_caller(get_account_alice!());
+    contract.vote_spend_contract(spend_id!());
+}
+
+#[test]
+#[should_panic(expected = "unauthorized call")]
+fn snapshot_unlock_contract_added_member() {
+    let mut contract = get_contract();
+    set_caller(get_account_vecno!());
+    contract.act_lock_contract(None, false);
+
+    let timestamp = EXPIRE_48H.as_nanos();
+    set_caller_at(get_account_nitya!(), timestamp);
+    ContractLocking::act_unlock_contract(&mut contract, "unlock".to_string());
+    assert!(contract.guild_contract_state().vote.is_some());
+    add_alice(&mut contract);
+
+    set_caller_at(get_account_alice!(), timestamp);
+    contract.vote_unlock_contract();
+}
+
+#[test]
+fn snapshot_cleared() {
+    let mut contract = get_contract();
+    open_spend(&mut contract);
+    for account in [
+        get_account_vecno!(),
+        get_account_nodra!(),
+        get_account_nitya!(),
+    ] {
+        set_caller(account);
+        contract.vote_spend_contract(spend_id!());
+    }
+    set_result(PromiseResult::Successful(vec![]));
+    contract.resolve_spend_contract(spend_id!());
+    contract.finalize_motion(spend_id!());
+    assert!(contract.votes.snapshot_map.get(&spend_id!()).is_none());
+}
+
+#[test]
+fn snapshot_contract_scope() {
+    let mut contract = get_contract();
+    set_caller(get_account_nodra!());
+    contract.act_lock_contract(Some("lock".to_string()), false);
+
+    // Note: Contract motions are voted by the executive guild
+    let id = contract.guild_contract_state().vote.unwrap();
+    assert_eq!(get_guild_id!(), contract.votes.get_snapshot(&id).scope);
+}
+
+// ==== stake history ====
+
+#[test]
+fn stake_history_open_motion() {
+    let mut contract = get_contract();
+    stake_bob(&mut contract, 1000);
+    let key = (get_guild_id!(), get_account_bob!());
+    assert!(contract.guilds.stake_history.get(&key).is_none());
+
+    open_spend(&mut contract);
+    stake_bob(&mut contract, 10);
+    stake_bob(&mut contract, 20);
+    assert_eq!(
+        vec![(1, Some(1000))],
+        contract.guilds.stake_history.get(&key).unwrap()
+    );
+}
+
+#[test]
+fn stake_history_pruned() {
+    let mut contract = get_contract();
+    open_spend(&mut contract);
+    stake_bob(&mut contract, 1000);
+    pass_spend(&mut contract);
+    contract.finalize_motion(spend_id!());
+    assert!(contract.guilds.checkpoints.is_empty());
+
+    // Note: No open snapshot reads the stake of the finalized motion
+    stake_bob(&mut contract, 10);
+    let key = (get_guild_id!(), get_account_bob!());
+    assert!(contract.guilds.stake_history.get(&key).is_none());
+}
@@ -0,0 +1,212 @@
+use crate::guild::*;
+
+use crate::guild::test_utils::*;
+use near_sdk::ONE_NEAR;
+
+macro_rules! spend_id {
+    () => {
+        "SC:1".parse::<MotionId>().unwrap()
+    };
+}
+
+fn get_spend(contract: &GuildContract) -> Spend {
+    contract
+        .guild_treasury_spend(spend_id!())
+        .expect("missing spend")
+}
+
+fn open_spend(contract: &mut GuildContract) {
+    set_caller(get_account_nodra!());
+    contract.act_spend_contract(get_account_bob!(), U128(10 * ONE_NEAR), "spend".to_string());
+}
+
+fn pass_spend(contract: &mut GuildContract) {
+    open_spend(contract);
+    set_caller(get_account_vecno!());
+    contract.vote_spend_contract(spend_id!());
+    set_caller(get_account_nodra!());
+    contract.vote_spend_contract(spend_id!());
+    set_caller(get_account_nitya!());
+    contract.vote_spend_contract(spend_id!());
+}
+
+// ==== act_spend_contract ====
+
+#[test]
+fn act_spend_contract() {
+    let mut contract = get_contract();
+    set_caller(get_account_nodra!());
+    let available = contract.get_available_amount();
+    contract.act_spend_contract(get_account_bob!(), U128(10 * ONE_NEAR), "spend".to_string());
+
+    let spend = get_spend(&contract);
+    assert_eq!(SpendStatus::Voting, spend.status);
+    assert_eq!(get_account_bob!(), spend.receiver_id);
+    assert_eq!(get_account_nodra!(), spend.proposer_id);
+    assert!(spend.closed_at.is_none());
+    assert_eq!(U128(10 * ONE_NEAR), contract.guild_treasury_locked());
+    assert!(contract.get_available_amount() <= available - 10 * ONE_NEAR);
+}
+
+#[test]
+#[should_panic(expected = "unauthorized call")]
+fn act_spend_contract_not_authorized() {
+    let mut contract = get_contract();
+    set_caller(get_account_bob!());
+    contract.act_spend_contract(get_account_bob!(), U128(ONE_NEAR), "spend".to_string());
+}
+
+#[test]
+#[should_panic(expected = "Spend amount exceeds the available amount")]
+fn act_spend_contract_over_available() {
+    let mut contract = get_contract();
+    open_spend(&mut contract);
+    contract.act_spend_contract(get_account_bob!(), U128(90 * ONE_NEAR), "spend".to_string());
+}
+
+#[test]
+#[should_panic(expected = "Spend amount must be greater than zero")]
+fn act_spend_contract_zero() {
+    let mut contract = get_contract();
+    set_caller(get_account_nodra!());
+    contract.act_spend_contract(get_account_bob!(), U128(0), "spend".to_string());
+}
+
+// ==== vote_spend_contract ====
+
+#[test]
+fn vote_spend_contract() {
+    let mut contract = get_contract();
+    open_spend(&mut contract);
+    set_caller(get_account_vecno!());
+    contract.vote_spend_contract(spend_id!());
+    set_caller(get_account_nodra!());
+    contract.vote_spend_contract(spend_id!());
+    assert_eq!(SpendStatus::Voting, get_spend(&contract).status);
+
+    set_caller(get_account_nitya!());
+    contract.vote_spend_contract(spend_id!());
+    let spend = get_spend(&contract);
+    assert_eq!(SpendStatus::Paying, spend.status);
+    assert!(spend.closed_at.is_none());
+    assert_eq!(U128(10 * ONE_NEAR), contract.guild_treasury_locked());
+
+    let motion = contract.votes.motion_map.get(&spend_id!()).unwrap();
+    assert!(motion.executed);
+}
+
+#[test]
+#[should_panic(expected = "spend is not voting")]
+fn vote_spend_contract_paid() {
+    let mut contract = get_contract();
+    pass_spend(&mut contract);
+    set_caller(get_account_bob!());
+    contract.vote_spend_contract(spend_id!());
+}
+
+#[test]
+#[should_panic(expected = "Can not vote on an expired motion")]
+fn vote_spend_contract_expired() {
+    let mut contract = get_contract();
+    open_spend(&mut contract);
+    set_caller_at(get_account_vecno!(), EXPIRE_48H.as_nanos());
+    contract.vote_spend_contract(spend_id!());
+}
+
+// ==== resolve_spend_contract ====
+
+#[test]
+fn resolve_spend_contract_succeeded() {
+    let mut contract = get_contract();
+    pass_spend(&mut contract);
+    set_result(PromiseResult::Successful(vec![]));
+    assert!(contract.resolve_spend_contract(spend_id!()));
+
+    let spend = get_spend(&contract);
+    assert_eq!(SpendStatus::Paid, spend.status);
+    assert!(spend.closed_at.is_some());
+    assert_eq!(U128(0), contract.guild_treasury_locked());
+}
+
+#[test]
+fn resolve_spend_contract_failed() {
+    let mut contract = get_contract();
+    pass_spend(&mut contract);
+    set_result(PromiseResult::Failed);
+    assert!(!contract.resolve_spend_contract(spend_id!()));
+
+    // Note: The reservation is kept until the spend is released
+    let spend = get_spend(&contract);
+    assert_eq!(SpendStatus::Failed, spend.status);
+    assert_eq!(U128(10 * ONE_NEAR), contract.guild_treasury_locked());
+
+    contract.release_spend_contract(spend_id!());
+    assert_eq!(SpendStatus::Released, get_spend(&contract).status);
+    assert_eq!(U128(0), contract.guild_treasury_locked());
+}
+
+#[test]
+#[should_panic(expected = "Method resolve_spend_contract is private")]
+fn resolve_spend_contract_private() {
+    let mut contract = get_contract();
+    pass_spend(&mut contract);
+    set_caller(get_account_bob!());
+    contract.resolve_spend_contract(spend_id!());
+}
+
+#[test]
+#[should_panic(expected = "spend is not paying")]
+fn resolve_spend_contract_voting() {
+    let mut contract = get_contract();
+    open_spend(&mut contract);
+    set_result(PromiseResult::Successful(vec![]));
+    contract.resolve_spend_contract(spend_id!());
+}
+
+// ==== release_spend_contract ====
+
+#[test]
+fn release_spend_contract() {
+    let mut contract = get_contract();
+    open_spend(&mut contract);
+    set_expired();
+    contract.release_spend_contract(spend_id!());
+
+    let spend = get_spend(&contract);
+    assert_eq!(SpendStatus::Released, spend.status);
+    assert!(spend.closed_at.is_some());
+    assert_eq!(U128(0), contract.guild_treasury_locked());
+}
+
+#[test]
+#[should_panic(expected = "motion has not expired")]
+fn release_spend_contract_open() {
+    let mut contract = get_contract();
+    open_spend(&mut contract);
+    contract.release_spend_contract(spend_id!());
+}
+
+#[test]
+#[should_panic(expected = "spend is not voting")]
+fn release_spend_contract_paid() {
+    let mut contract = get_contract();
+    pass_spend(&mut contract);
+    set_expired();
+    contract.release_spend_contract(spend_id!());
+}
+
+// ==== guild_treasury_spends ====
+
+#[test]
+fn guild_treasury_spends() {
+    let mut contract = get_contract();
+    open_spend(&mut contract);
+    contract.act_spend_contract(get_account_nitya!(), U128(ONE_NEAR), "spend".to_string());
+
+    let spends = contract.guild_treasury_spends(None, None);
+    assert_eq!(2, spends.len());
+    assert_eq!(U128(11 * ONE_NEAR), contract.guild_treasury_locked());
+    let spends = contract.guild_treasury_spends(Some(U128(1)), Some(1));
+    assert_eq!(1, spends.len());
+    assert_eq!(get_account_nitya!(), spends[0].1.receiver_id);
+}
@@ -0,0 +1,124 @@
+use crate::guild::*;
+
+macro_rules! guild_id_other {
+    () => {
+        "G:02".parse::<GuildId>().unwrap()
+    };
+}
+
+fn get_guild(ceo_id: AccountId) -> GuildInfo {
+    GuildInfo {
+        ceo_id,
+        ceo_share: 1000,
+        board_size: 2,
+        board_share: 4000,
+        members_size: 5,
+        members_share: 5000,
+    }
+}
+
+fn get_contract() -> GuildContract {
+    let mut member_map = HashMap::new();
+    member_map.insert(get_account_bob!(), 100_u128);
+    member_map.insert(get_account_nitya!(), 200_u128);
+    member_map.insert(get_account_nodra!(), 300_u128);
+    member_map.insert(get_account_vecno!(), 400_u128);
+
+    let mut board_map = HashMap::new();
+    board_map.insert(get_account_nitya!(), 5000_u16);
+    board_map.insert(get_account_nodra!(), 5000_u16);
+
+    let mut contract = GuildContract::new(
+        &get_guild_id!(),
+        &get_guild(get_account_vecno!()),
+        &board_map,
+        &member_map,
+    );
+
+    let mut member_map = HashMap::new();
+    member_map.insert(get_account_bob!(), 50_u128);
+    contract.guilds.register(
+        &guild_id_other!(),
+        &get_guild(get_account_bob!()),
+        &HashMap::new(),
+        &member_map,
+    );
+    contract
+}
+
+// ==== guild views ====
+
+#[test]
+fn guild_info() {
+    let contract = get_contract();
+    let info = contract.guild_info(guild_id_other!()).unwrap();
+    assert_eq!(get_account_bob!(), info.ceo_id);
+    assert!(contract.guild_info("G:03".parse().unwrap()).is_none());
+}
+
+#[test]
+fn guild_list() {
+    let contract = get_contract();
+    let guilds = contract.guild_list(None, None);
+    let ids: Vec<GuildId> = guilds.into_iter().map(|(id, _)| id).collect();
+    assert_eq!(vec![get_guild_id!(), guild_id_other!()], ids);
+
+    let page = contract.guild_list(Some(U128(1)), Some(1));
+    assert_eq!(1, page.len());
+    assert_eq!(guild_id_other!(), page[0].0);
+    assert!(contract.guild_list(Some(U128(2)), None).is_empty());
+}
+
+#[test]
+fn guild_members() {
+    let contract = get_contract();
+    let members = contract.guild_members(get_guild_id!(), None, None);
+    assert_eq!(4, members.len());
+    let total: u128 = members.iter().map(|(_, value)| value.0).sum();
+    assert_eq!(1000, total);
+
+    let first = contract.guild_members(get_guild_id!(), None, Some(2));
+    let last = contract.guild_members(get_guild_id!(), Some(U128(2)), Some(2));
+    assert_eq!(members, [first, last].concat());
+    assert!(contract
+        .guild_members("G:03".parse().unwrap(), None, None)
+        .is_empty());
+}
+
+#[test]
+fn guild_board() {
+    let contract = get_contract();
+    let mut board = contract.guild_board(get_guild_id!(), None, None);
+    board.sort();
+    assert_eq!(
+        vec![(get_account_nitya!(), 5000), (get_account_nodra!(), 5000)],
+        board
+    );
+    assert_eq!(
+        1,
+        contract
+            .guild_board(get_guild_id!(), Some(U128(1)), None)
+            .len()
+    );
+    assert!(contract
+        .guild_board(guild_id_other!(), None, None)
+        .is_empty());
+}
+
+#[test]
+fn guild_member_set() {
+    let contract = get_contract();
+    let member_set = contract
+        .guild_member_set(get_account_bob!(), None, None)
+        .unwrap();
+    assert_eq!(U128(150), member_set.value);
+    assert_eq!(vec![get_guild_id!(), guild_id_other!()], member_set.guilds);
+
+    let page = contract
+        .guild_member_set(get_account_bob!(), Some(U128(1)), Some(1))
+        .unwrap();
+    assert_eq!(vec![guild_id_other!()], page.guilds);
+    assert!(contract
+        .guild_member_set("alice.near".parse().unwrap(), None, None)
+        .is_none());
+}
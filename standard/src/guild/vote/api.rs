@@ -4,29 +4,238 @@ use super::*;
 
 #[derive(BorshSerialize, BorshDeserialize)]
 pub struct Votes {
+    /// Contract wide counter used to create unique motion ids.
+    pub motion_count: u64,
+    /// The latest motion id issued for a guild.
+    pub latest_map: LookupMap<GuildId, MotionId>,
     pub motion_map: UnorderedMap<MotionId, MotionState>,
     pub voices_map: LookupMap<MotionId, MotionVoices>,
+    pub policy_map: UnorderedMap<MotionKind, VotePolicy>,
+    /// The outcome of finalized motions.
+    pub result_map: LookupMap<MotionId, MotionResult>,
+    /// The voters of each motion when it was issued.
+    pub snapshot_map: LookupMap<MotionId, MotionSnapshot>,
+}
+
+impl Default for Votes {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl Votes {
     pub fn new() -> Self {
         Self {
+            motion_count: 0,
+            latest_map: LookupMap::new(StorageKey::VotesLatestMap.try_to_vec().unwrap()),
             motion_map: UnorderedMap::new(StorageKey::VotesMotionMap.try_to_vec().unwrap()),
             voices_map: LookupMap::new(StorageKey::VotesVoicesMap.try_to_vec().unwrap()),
+            policy_map: UnorderedMap::new(StorageKey::VotesPolicyMap.try_to_vec().unwrap()),
+            result_map: LookupMap::new(StorageKey::VotesResultMap.try_to_vec().unwrap()),
+            snapshot_map: LookupMap::new(StorageKey::VotesSnapshotMap.try_to_vec().unwrap()),
+        }
+    }
+
+    /// Returns a new unique motion id, an id namespaced by a guild
+    /// is tracked as the latest motion of that guild.
+    pub fn next_id(&mut self, prefix: &str, guild_id: Option<&GuildId>) -> MotionId {
+        self.motion_count += 1;
+        let id: MotionId = format!("{}:{}", prefix, self.motion_count).into();
+        if let Some(guild_id) = guild_id {
+            self.latest_map.insert(guild_id, &id);
         }
+        id
+    }
+
+    /// Returns the policy for a kind of motion, or the default when not set.
+    pub fn get_policy(&self, kind: MotionKind) -> VotePolicy {
+        self.policy_map
+            .get(&kind)
+            .unwrap_or_else(|| get_default_policy(kind))
+    }
+
+    pub fn set_policy(&mut self, kind: MotionKind, policy: &VotePolicy) {
+        policy.assert_valid(kind);
+        self.policy_map.insert(&kind, policy);
+    }
+
+    /// Evaluates the agree tally of a motion against the policy for its kind.
+    pub fn is_passed(&self, kind: MotionKind, voices: &MotionVoices, count: &MotionCount) -> bool {
+        let tally = voices
+            .tally
+            .get(&get_vote_id_agree())
+            .expect("missing tally");
+        self.get_policy(kind).is_passed(tally, count)
+    }
+
+    /// Evaluates the agree tally of a motion against the policy for its kind,
+    /// marks the motion as executed when it passes.
+    pub fn exec_policy(
+        &mut self,
+        kind: MotionKind,
+        id: &MotionId,
+        voices: &MotionVoices,
+        count: &MotionCount,
+    ) -> bool {
+        if !self.is_passed(kind, voices, count) {
+            return false;
+        }
+        self.execute(id);
+        true
+    }
+
+    /// Marks the motion as executed, closing it for votes.
+    pub fn execute(&mut self, id: &MotionId) {
+        let mut motion = self.motion_map.get(id).expect("missing motion");
+        // Validate the motion state before marking it as executed
+        require!(!motion.executed, "motion already closed");
+        motion.executed = true;
+        self.motion_map.insert(id, &motion);
+
+        let motion_execute_log: ArcEventLog = ArcEventLog {
+            module: EVENT_ARC_STANDARD_GUILD.to_string(),
+            version: EVENT_ARC_METADATA_SPEC.to_string(),
+            event: ArcEventVariant::MotionExecute(vec![MotionExecuteLog {
+                motion_id: id.to_string(),
+            }]),
+        };
+        env::log_str(&motion_execute_log.to_string());
+    }
+
+    /// Closes an executed or expired motion, the outcome is kept and
+    /// the motion with all its voices is removed from storage.
+    pub fn finalize(&mut self, id: &MotionId) -> MotionResult {
+        let motion = self.motion_map.get(id).expect("missing motion");
+        let status = motion.get_status();
+        require!(
+            status != MotionStatus::Open,
+            "Can not finalize an open motion"
+        );
+
+        let mut voices = self.voices_map.remove(id).expect("missing motion voices");
+        // The winner of a choice motion is picked from the stored ballots,
+        // no option wins when the voices, or their stake, miss the policy quorum
+        let winner = motion.method.and_then(|method| {
+            let policy = self.get_policy(MotionKind::ChoiceGuild);
+            let turnout = MotionTally {
+                ceo: false,
+                board: 0,
+                members: voices.tally.values().map(|tally| tally.members).sum(),
+                weight: voices.tally.values().map(|tally| tally.weight).sum(),
+            };
+            let count = self.get_snapshot(id).count;
+            if !policy.is_quorum(&turnout, &count) {
+                return None;
+            }
+            let options: Vec<VoteId> = motion.info.vote_options.keys().cloned().collect();
+            let ballots: Vec<(Vec<VoteId>, u128)> = voices
+                .votes
+                .values()
+                .map(|ballot| match policy.weighted {
+                    true => (ballot.get_ranking(), ballot.weight),
+                    false => (ballot.get_ranking(), 1),
+                })
+                .collect();
+            method.resolve_weighted(&options, &ballots)
+        });
+        // The bond is settled by the voters the motion drew besides its issuer
+        let bond = motion.bond.map(|mut bond| {
+            let voters = voices
+                .votes
+                .keys()
+                .filter(|account| account != &motion.issuer)
+                .count();
+            bond.settle(status, voters as u64);
+            bond
+        });
+        let result = MotionResult {
+            status,
+            issuer: motion.issuer,
+            finalized_at: Timestamp::now(),
+            voters: voices.votes.len(),
+            tally: voices
+                .tally
+                .iter()
+                .map(|(vote, tally)| (vote.clone(), tally.into()))
+                .collect(),
+            winner,
+            unrevealed: voices.commits.keys().collect(),
+            bond,
+        };
+        for account in voices.votes.keys().collect::<Vec<AccountId>>() {
+            voices.proxies.remove(&account);
+        }
+        voices.votes.clear();
+        voices.commits.clear();
+        self.snapshot_map.remove(id);
+        self.motion_map.remove(id);
+        self.result_map.insert(id, &result);
+
+        let motion_finalize_log: ArcEventLog = ArcEventLog {
+            module: EVENT_ARC_STANDARD_GUILD.to_string(),
+            version: EVENT_ARC_METADATA_SPEC.to_string(),
+            event: ArcEventVariant::MotionFinalize(vec![MotionFinalizeLog {
+                motion_id: id.to_string(),
+                status: result.status.as_str().to_string(),
+                voters: result.voters.to_string(),
+            }]),
+        };
+        env::log_str(&motion_finalize_log.to_string());
+        result
+    }
+
+    /// Returns the outcome of a finalized motion.
+    pub fn get_result(&self, id: &MotionId) -> Option<MotionResult> {
+        self.result_map.get(id)
+    }
+
+    /// Returns the motion with the tally for each of its options.
+    pub fn get_motion(&self, id: &MotionId) -> Option<JsonMotion> {
+        let motion = self.motion_map.get(id)?;
+        Some(self.to_json(id, &motion))
+    }
+
+    /// Returns a page of motions, optionally only those with the status.
+    pub fn get_motions(
+        &self,
+        status: Option<MotionStatus>,
+        from_index: Option<U128>,
+        limit: Option<u64>,
+    ) -> Vec<JsonMotion> {
+        let start = u128::from(from_index.unwrap_or(U128(0)));
+        self.motion_map
+            .iter()
+            .filter(|(_, motion)| status.is_none_or(|status| motion.get_status() == status))
+            .skip(start as usize)
+            .take(limit.unwrap_or(50) as usize)
+            .map(|(id, motion)| self.to_json(&id, &motion))
+            .collect()
+    }
+
+    /// Returns the vote an account cast on the motion.
+    pub fn get_vote(&self, id: &MotionId, account: &AccountId) -> Option<VoteId> {
+        self.voices_map
+            .get(id)?
+            .votes
+            .get(account)
+            .map(|ballot| ballot.vote)
     }
 
     pub fn issue(&mut self, id: &MotionId, motion: &MotionInfo) {
         motion.assert_valid();
 
         require!(
-            env::block_timestamp() <= motion.starts_at,
+            Timestamp::now() <= motion.starts_at,
             "Voting can not start before the motion is published"
         );
 
         let state = MotionState {
             info: motion.clone(),
             executed: false,
+            issuer: env::predecessor_account_id(),
+            method: None,
+            bond: None,
+            storage: 0,
         };
         require!(
             self.motion_map.insert(id, &state).is_none(),
@@ -40,6 +249,16 @@ impl Votes {
                     .try_to_vec()
                     .unwrap(),
             ),
+            commits: UnorderedMap::new(
+                StorageKey::VotesCommitMapList { id: id.clone() }
+                    .try_to_vec()
+                    .unwrap(),
+            ),
+            proxies: LookupMap::new(
+                StorageKey::VotesProxyMapList { id: id.clone() }
+                    .try_to_vec()
+                    .unwrap(),
+            ),
         };
         for (vote_id, _value) in motion.vote_options.iter() {
             // Note: In theory this is imposible, but check
@@ -52,6 +271,7 @@ impl Votes {
                             ceo: false,
                             board: 0,
                             members: 0,
+                            weight: 0,
                         }
                     )
                     .is_none(),
@@ -59,86 +279,462 @@ impl Votes {
             );
         }
         self.voices_map.insert(id, &voices);
+
+        let motion_issue_log: ArcEventLog = ArcEventLog {
+            module: EVENT_ARC_STANDARD_GUILD.to_string(),
+            version: EVENT_ARC_METADATA_SPEC.to_string(),
+            event: ArcEventVariant::MotionIssue(vec![MotionIssueLog {
+                motion_id: id.to_string(),
+                issuer_id: env::predecessor_account_id().to_string(),
+                title: motion.title.clone(),
+                starts_at: motion.starts_at.as_nanos().to_string(),
+                expires_at: motion.expires_at.as_nanos().to_string(),
+            }]),
+        };
+        env::log_str(&motion_issue_log.to_string());
     }
 
-    pub fn vote_ceo(&mut self, id: &MotionId, vote: VoteId, account: AccountId) -> MotionVoices {
-        let mut voices = self.assert_voices(id);
-        let tally = self.assert_tally(&vote, &account, &mut voices);
+    /// Issues a motion where the ballots pick one of several options.
+    pub fn issue_choice(&mut self, id: &MotionId, motion: &MotionInfo, method: ChoiceMethod) {
+        require!(
+            motion.vote_options.len() > 1,
+            "The motion requires at least two options to choose from"
+        );
+        self.issue(id, motion);
 
-        voices.tally.insert(
-            vote,
-            MotionTally {
-                ceo: true,
-                board: tally.board,
-                members: tally.members + 1,
-            },
+        let mut state = self.motion_map.get(id).expect("missing motion info");
+        state.method = Some(method);
+        self.motion_map.insert(id, &state);
+    }
+
+    /// Captures the voters of the motion, the tallies and thresholds
+    /// of the motion are measured against them.
+    pub fn snapshot(&mut self, id: &MotionId, snapshot: &MotionSnapshot) {
+        self.snapshot_map.insert(id, snapshot);
+    }
+
+    pub fn get_snapshot(&self, id: &MotionId) -> MotionSnapshot {
+        self.snapshot_map.get(id).expect("missing motion snapshot")
+    }
+
+    /// Holds the bond the issuer attached to the motion until it is finalized.
+    pub fn hold_bond(&mut self, id: &MotionId, amount: Balance, quorum: u64) {
+        let mut state = self.motion_map.get(id).expect("missing motion info");
+        state.bond = Some(MotionBond {
+            amount: U128(amount),
+            quorum,
+            status: BondStatus::Held,
+        });
+        self.motion_map.insert(id, &state);
+    }
+
+    /// Holds the storage deposit the issuer attached until the motion is finalized.
+    pub fn hold_storage(&mut self, id: &MotionId, amount: Balance) {
+        let mut state = self.motion_map.get(id).expect("missing motion info");
+        state.storage = amount;
+        self.motion_map.insert(id, &state);
+    }
+
+    /// Casts the vote for the first option of the ranking, the full
+    /// ranking is kept for the resolution of the motion.
+    pub fn rank(
+        &mut self,
+        id: &MotionId,
+        ranking: Vec<VoteId>,
+        account: AccountId,
+        role: BallotRole,
+        weight: u128,
+    ) -> MotionVoices {
+        self.assert_unsealed(id);
+        self.rank_ballot(id, ranking, account, role, weight)
+    }
+
+    /// Seals the vote of the account as a hash, see `get_commitment_hash`.
+    ///
+    /// Note: The commitment can be replaced until the reveal starts.
+    pub fn commit(
+        &mut self,
+        id: &MotionId,
+        hash: String,
+        account: AccountId,
+        role: BallotRole,
+        weight: u128,
+    ) {
+        let motion = self.motion_map.get(id).expect("missing motion info");
+        let reveal_at = motion
+            .info
+            .reveal_at
+            .expect("The motion does not take sealed votes");
+        let mut voices = self.assert_voices(id);
+        require!(
+            Timestamp::now() < reveal_at,
+            "The commit phase of the motion has ended"
+        );
+        require!(
+            hash.len() == 64,
+            "Commitment hash has to be hex encoded string (64 bytes)"
+        );
+        require!(
+            voices
+                .votes
+                .get(&account)
+                .is_none_or(|ballot| ballot.delegate.is_some()),
+            "The account has already voted on the motion"
         );
-        self.voices_map.insert(&id, &voices);
 
         voices
+            .commits
+            .insert(&account, &Commitment { hash, role, weight });
+        self.voices_map.insert(id, &voices);
     }
 
-    pub fn vote_board(&mut self, id: &MotionId, vote: VoteId, account: AccountId) -> MotionVoices {
+    /// Opens the sealed vote of the account, only revealed votes count in the tally.
+    pub fn reveal(
+        &mut self,
+        id: &MotionId,
+        ranking: Vec<VoteId>,
+        salt: &str,
+        account: AccountId,
+    ) -> MotionVoices {
+        let motion = self.motion_map.get(id).expect("missing motion info");
+        let reveal_at = motion
+            .info
+            .reveal_at
+            .expect("The motion does not take sealed votes");
         let mut voices = self.assert_voices(id);
-        let tally = self.assert_tally(&vote, &account, &mut voices);
+        require!(
+            reveal_at <= Timestamp::now(),
+            "The reveal phase of the motion has not started"
+        );
+        let commitment = voices
+            .commits
+            .get(&account)
+            .expect("The account has no commitment on the motion");
+        require!(
+            get_commitment_hash(&account, &ranking, salt) == commitment.hash,
+            "The revealed vote does not match the commitment"
+        );
+        voices.commits.remove(&account);
+        self.voices_map.insert(id, &voices);
 
-        voices.tally.insert(
-            vote,
-            MotionTally {
-                ceo: tally.ceo,
-                board: tally.board + 1,
-                members: tally.members + 1,
-            },
+        if motion.method.is_some() {
+            return self.rank_ballot(id, ranking, account, commitment.role, commitment.weight);
+        }
+        require!(ranking.len() == 1, "The motion takes a single option");
+        self.place(
+            id,
+            ranking[0].clone(),
+            account,
+            commitment.role,
+            commitment.weight,
+        )
+    }
+
+    fn rank_ballot(
+        &mut self,
+        id: &MotionId,
+        ranking: Vec<VoteId>,
+        account: AccountId,
+        role: BallotRole,
+        weight: u128,
+    ) -> MotionVoices {
+        let motion = self.motion_map.get(id).expect("missing motion info");
+        let method = motion
+            .method
+            .expect("The motion has no options to choose from");
+        require!(
+            !ranking.is_empty(),
+            "The ranking requires at least one option"
+        );
+        require!(
+            method == ChoiceMethod::RankedChoice || ranking.len() == 1,
+            "A plurality motion takes a single option"
+        );
+        let mut unique = ranking.clone();
+        unique.sort();
+        unique.dedup();
+        require!(
+            unique.len() == ranking.len(),
+            "The ranking has duplicated options"
+        );
+        require!(
+            ranking
+                .iter()
+                .all(|vote| motion.info.vote_options.contains_key(vote)),
+            "missing motion vote"
         );
 
-        self.voices_map.insert(&id, &voices);
+        let mut voices = self.place(id, ranking[0].clone(), account.clone(), role, weight);
+        let mut ballot = voices.votes.get(&account).expect("missing ballot");
+        ballot.ranking = ranking;
+        voices.votes.insert(&account, &ballot);
         voices
     }
 
-    pub fn vote_member(&mut self, id: &MotionId, vote: VoteId, account: AccountId) -> MotionVoices {
+    pub fn vote_ceo(
+        &mut self,
+        id: &MotionId,
+        vote: VoteId,
+        account: AccountId,
+        weight: u128,
+    ) -> MotionVoices {
+        self.cast(id, vote, account, BallotRole::Ceo, weight)
+    }
+
+    pub fn vote_board(
+        &mut self,
+        id: &MotionId,
+        vote: VoteId,
+        account: AccountId,
+        weight: u128,
+    ) -> MotionVoices {
+        self.cast(id, vote, account, BallotRole::Board, weight)
+    }
+
+    pub fn vote_member(
+        &mut self,
+        id: &MotionId,
+        vote: VoteId,
+        account: AccountId,
+        weight: u128,
+    ) -> MotionVoices {
+        self.cast(id, vote, account, BallotRole::Member, weight)
+    }
+
+    /// Retracts the vote of the account, the motion has to be open.
+    ///
+    /// Note: The voices delegated to the account are retracted with it.
+    pub fn unvote(&mut self, id: &MotionId, account: AccountId) -> MotionVoices {
         let mut voices = self.assert_voices(id);
-        let tally = self.assert_tally(&vote, &account, &mut voices);
+        let ballot = voices
+            .votes
+            .remove(&account)
+            .expect("The account has not voted on the motion");
+        retract_tally(&mut voices, &ballot);
+        voices.remove_proxy(&ballot, &account);
+        log_ballot(true, id, &account, &ballot);
 
-        voices.tally.insert(
-            vote,
-            MotionTally {
-                ceo: tally.ceo,
-                board: tally.board,
-                members: tally.members + 1,
-            },
-        );
-        self.voices_map.insert(&id, &voices);
+        let proxied = voices.proxies.remove(&account).unwrap_or_default();
+        for proxy in proxied {
+            let ballot = voices.votes.remove(&proxy).expect("missing proxy ballot");
+            retract_tally(&mut voices, &ballot);
+            log_ballot(true, id, &proxy, &ballot);
+        }
 
+        self.voices_map.insert(id, &voices);
         voices
     }
 
-    fn assert_tally(
+    /// Moves the vote of the account to another option, keeping its role and weight.
+    ///
+    /// Note: The voices delegated to the account move with it, a ranked ballot
+    /// keeps the rest of its ranking.
+    pub fn revote(&mut self, id: &MotionId, vote: VoteId, account: AccountId) -> MotionVoices {
+        let voices = self.voices_map.get(id).expect("missing motion voices");
+        let ballot = voices
+            .votes
+            .get(&account)
+            .expect("The account has not voted on the motion");
+        let proxies: Vec<(AccountId, u128)> = voices
+            .proxies
+            .get(&account)
+            .unwrap_or_default()
+            .into_iter()
+            .map(|proxy| {
+                let weight = voices
+                    .votes
+                    .get(&proxy)
+                    .expect("missing proxy ballot")
+                    .weight;
+                (proxy, weight)
+            })
+            .collect();
+
+        self.unvote(id, account.clone());
+        let motion = self.motion_map.get(id).expect("missing motion info");
+        let voices = match motion.method {
+            // A ranked ballot keeps its order behind the new first choice
+            Some(ChoiceMethod::RankedChoice) => {
+                let mut ranking = vec![vote.clone()];
+                ranking.extend(
+                    ballot
+                        .get_ranking()
+                        .into_iter()
+                        .filter(|option| option != &vote),
+                );
+                self.rank(id, ranking, account.clone(), ballot.role, ballot.weight)
+            }
+            _ => self.cast(id, vote, account.clone(), ballot.role, ballot.weight),
+        };
+        if proxies.is_empty() {
+            return voices;
+        }
+        self.vote_proxies(id, &account, proxies)
+    }
+
+    /// Adds the delegated voices to the option the delegate voted for,
+    /// accounts that voted directly keep their own vote.
+    pub fn vote_proxies(
         &mut self,
-        vote: &VoteId,
-        account: &AccountId,
-        voices: &mut MotionVoices,
-    ) -> MotionTally {
-        let tally = voices.tally.get(vote).expect("missing motion vote");
+        id: &MotionId,
+        delegate: &AccountId,
+        proxies: Vec<(AccountId, u128)>,
+    ) -> MotionVoices {
+        let mut voices = self.assert_voices(id);
+        let cast = voices
+            .votes
+            .get(delegate)
+            .expect("The account has not voted on the motion");
+        let vote = cast.vote;
+
+        for (proxy, weight) in proxies {
+            if &proxy == delegate || voices.votes.get(&proxy).is_some() {
+                continue;
+            }
+            let tally = voices.tally.get_mut(&vote).expect("missing motion vote");
+            tally.members += 1;
+            tally.weight += weight;
+
+            let ballot = Ballot {
+                vote: vote.clone(),
+                role: BallotRole::Member,
+                weight,
+                delegate: Some(delegate.clone()),
+                ranking: cast.ranking.clone(),
+            };
+            voices.votes.insert(&proxy, &ballot);
+            voices.insert_proxy(&ballot, &proxy);
+            log_ballot(false, id, &proxy, &ballot);
+        }
+
+        self.voices_map.insert(id, &voices);
+        voices
+    }
+
+    fn cast(
+        &mut self,
+        id: &MotionId,
+        vote: VoteId,
+        account: AccountId,
+        role: BallotRole,
+        weight: u128,
+    ) -> MotionVoices {
+        self.assert_unsealed(id);
+        self.place(id, vote, account, role, weight)
+    }
+
+    fn place(
+        &mut self,
+        id: &MotionId,
+        vote: VoteId,
+        account: AccountId,
+        role: BallotRole,
+        weight: u128,
+    ) -> MotionVoices {
+        let mut voices = self.assert_voices(id);
+        require!(voices.tally.contains_key(&vote), "missing motion vote");
+
+        // A delegated voice is replaced when the account votes directly
+        if let Some(ballot) = voices.votes.get(&account) {
+            require!(
+                ballot.delegate.is_some(),
+                "The account has already voted on the motion"
+            );
+            voices.votes.remove(&account);
+            retract_tally(&mut voices, &ballot);
+            voices.remove_proxy(&ballot, &account);
+            log_ballot(true, id, &account, &ballot);
+        }
+
+        let tally = voices.tally.get_mut(&vote).expect("missing motion vote");
+        match role {
+            BallotRole::Ceo => tally.ceo = true,
+            BallotRole::Board => tally.board += 1,
+            BallotRole::Member => {}
+        }
+        tally.members += 1;
+        tally.weight += weight;
+
+        let ballot = Ballot {
+            vote,
+            role,
+            weight,
+            delegate: None,
+            ranking: vec![],
+        };
+        voices.votes.insert(&account, &ballot);
+        self.voices_map.insert(id, &voices);
+
+        log_ballot(false, id, &account, &ballot);
+        voices
+    }
+
+    fn to_json(&self, id: &MotionId, motion: &MotionState) -> JsonMotion {
+        let voices = self.voices_map.get(id).expect("missing motion voices");
+        JsonMotion {
+            id: id.clone(),
+            status: motion.get_status(),
+            info: motion.info.clone(),
+            method: motion.method,
+            tally: voices
+                .tally
+                .iter()
+                .map(|(vote, tally)| (vote.clone(), tally.into()))
+                .collect(),
+            commits: voices.commits.len(),
+            bond: motion.bond.clone(),
+        }
+    }
+
+    fn assert_unsealed(&self, id: &MotionId) {
+        let motion = self.motion_map.get(id).expect("missing motion info");
         require!(
-            voices.votes.insert(&account, &vote).is_none(),
-            "The account has already voted on the motion"
+            motion.info.reveal_at.is_none(),
+            "The motion takes sealed votes, commit the vote first"
         );
-        MotionTally {
-            ceo: tally.ceo,
-            board: tally.board,
-            members: tally.members,
-        }
     }
 
     fn assert_voices(&mut self, id: &MotionId) -> MotionVoices {
-        let motion = self.motion_map.get(&id).expect("missing motion info");
+        let motion = self.motion_map.get(id).expect("missing motion info");
         require!(!motion.executed, "Can not vote on an executed motion");
         require!(
-            motion.info.expires_at > env::block_timestamp(),
+            motion.info.expires_at > Timestamp::now(),
             "Can not vote on an expired motion"
         );
 
-        let voices = self.voices_map.get(&id).expect("missing motion voices");
-        voices
+        self.voices_map.get(id).expect("missing motion voices")
     }
 }
+
+fn retract_tally(voices: &mut MotionVoices, ballot: &Ballot) {
+    let tally = voices
+        .tally
+        .get_mut(&ballot.vote)
+        .expect("missing motion vote");
+    match ballot.role {
+        BallotRole::Ceo => tally.ceo = false,
+        BallotRole::Board => tally.board -= 1,
+        BallotRole::Member => {}
+    }
+    tally.members -= 1;
+    tally.weight -= ballot.weight;
+}
+
+fn log_ballot(retract: bool, id: &MotionId, account: &AccountId, ballot: &Ballot) {
+    let data = vec![MotionVoteLog {
+        motion_id: id.to_string(),
+        voter_id: account.to_string(),
+        vote_id: ballot.vote.to_string(),
+        role: ballot.role.as_str().to_string(),
+        weight: ballot.weight.to_string(),
+    }];
+    let motion_vote_log: ArcEventLog = ArcEventLog {
+        module: EVENT_ARC_STANDARD_GUILD.to_string(),
+        version: EVENT_ARC_METADATA_SPEC.to_string(),
+        event: match retract {
+            true => ArcEventVariant::MotionUnvote(data),
+            false => ArcEventVariant::MotionVote(data),
+        },
+    };
+    env::log_str(&motion_vote_log.to_string());
+}
@@ -14,7 +14,7 @@ pub struct VoteInfo {
 impl VoteInfo {
     pub fn assert_valid(&self) {
         require!(
-            self.title.len() > 0,
+            !self.title.is_empty(),
             "Vote info requires a title is required"
         );
         require!(
@@ -48,21 +48,28 @@ impl VoteInfo {
 pub struct MotionInfo {
     pub title: String,
     pub details: String,
-    pub issued_at: u64,
-    pub starts_at: u64,
-    pub expires_at: u64,
+    pub issued_at: Timestamp,
+    pub starts_at: Timestamp,
+    pub expires_at: Timestamp,
     pub executor: Option<AccountId>,
     pub media: Option<String>,
     pub media_hash: Option<String>,
     pub reference: Option<String>,
     pub reference_hash: Option<String>,
     pub vote_options: HashMap<VoteId, VoteInfo>,
+    /// Votes are committed as hashes until the reveal, none for open votes.
+    pub reveal_at: Option<Timestamp>,
 }
 
 impl MotionInfo {
+    /// Seals the votes of the motion until the reveal window passed.
+    pub fn seal(&mut self, reveal: Duration) {
+        self.reveal_at = Some(self.starts_at + reveal);
+    }
+
     pub fn assert_valid(&self) {
         require!(
-            self.title.len() > 0,
+            !self.title.is_empty(),
             "Motion info requires a title is required"
         );
         require!(
@@ -85,10 +92,17 @@ impl MotionInfo {
         );
 
         require!(
-            self.vote_options.len() > 0,
+            !self.vote_options.is_empty(),
             "The motion requires at least one option to vote on"
         );
 
+        if let Some(reveal_at) = self.reveal_at {
+            require!(
+                self.starts_at < reveal_at && reveal_at < self.expires_at,
+                "The reveal must start while the motion is open"
+            );
+        }
+
         require!(
             self.media.is_some() == self.media_hash.is_some(),
             "Media hash is required to verify media integrity"
@@ -118,6 +132,193 @@ impl MotionInfo {
 pub struct MotionState {
     pub info: MotionInfo,
     pub executed: bool,
+    /// The account that issued the motion.
+    pub issuer: AccountId,
+    /// How the winning option is picked, none when a vote policy decides the motion.
+    pub method: Option<ChoiceMethod>,
+    /// The deposit the issuer attached to open the motion.
+    pub bond: Option<MotionBond>,
+    /// The deposit the issuer prepaid for the storage of the motion.
+    pub storage: Balance,
+}
+
+impl MotionState {
+    pub fn get_status(&self) -> MotionStatus {
+        if self.executed {
+            return MotionStatus::Executed;
+        }
+        if self.info.expires_at <= Timestamp::now() {
+            return MotionStatus::Expired;
+        }
+        MotionStatus::Open
+    }
+}
+
+#[derive(
+    Clone, Copy, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize, BorshSerialize,
+)]
+#[serde(crate = "near_sdk::serde")]
+pub enum MotionStatus {
+    Open,
+    Executed,
+    Expired,
+}
+
+impl MotionStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            MotionStatus::Open => "open",
+            MotionStatus::Executed => "executed",
+            MotionStatus::Expired => "expired",
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(crate = "near_sdk::serde")]
+pub struct JsonMotion {
+    pub id: MotionId,
+    pub status: MotionStatus,
+    pub info: MotionInfo,
+    pub method: Option<ChoiceMethod>,
+    pub tally: HashMap<VoteId, JsonTally>,
+    /// The number of sealed votes that are not revealed yet.
+    pub commits: u64,
+    pub bond: Option<MotionBond>,
+}
+
+/// The outcome of a finalized motion, kept after its voices are cleared.
+#[derive(Debug, PartialEq, Serialize, Deserialize, BorshDeserialize, BorshSerialize)]
+#[serde(crate = "near_sdk::serde")]
+pub struct MotionResult {
+    pub status: MotionStatus,
+    pub issuer: AccountId,
+    pub finalized_at: Timestamp,
+    /// The number of accounts that voted on the motion.
+    pub voters: u64,
+    pub tally: HashMap<VoteId, JsonTally>,
+    /// The winning option of a multi-option motion.
+    pub winner: Option<VoteId>,
+    /// The accounts that committed a sealed vote without revealing it.
+    pub unrevealed: Vec<AccountId>,
+    /// The settled bond of the motion.
+    pub bond: Option<MotionBond>,
+}
+
+// ==== Motion Bond ====
+
+#[derive(
+    Clone, Copy, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize, BorshSerialize,
+)]
+#[serde(crate = "near_sdk::serde")]
+pub enum BondStatus {
+    /// The motion is not finalized yet.
+    Held,
+    /// The motion passed or reached its quorum, the bond goes back to the issuer.
+    Returned,
+    /// The motion was rejected or ignored, the bond stays in the treasury.
+    Kept,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize, BorshSerialize)]
+#[serde(crate = "near_sdk::serde")]
+pub struct MotionBond {
+    pub amount: U128,
+    /// Voters besides the issuer needed for the bond to be returned.
+    pub quorum: u64,
+    pub status: BondStatus,
+}
+
+impl MotionBond {
+    /// Settles a held bond by the final status and the voters besides the issuer.
+    pub fn settle(&mut self, status: MotionStatus, voters: u64) {
+        self.status = match status == MotionStatus::Executed || voters >= self.quorum {
+            true => BondStatus::Returned,
+            false => BondStatus::Kept,
+        };
+    }
+}
+
+// ==== Motion Choice ====
+
+/// How the winning option of a multi-option motion is picked from the ballots.
+#[derive(
+    Clone, Copy, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize, BorshSerialize,
+)]
+#[serde(crate = "near_sdk::serde")]
+pub enum ChoiceMethod {
+    /// The option with the most first choices wins.
+    Plurality,
+    /// Instant-runoff, the options with the least first choices are eliminated
+    /// until one option holds a majority of the remaining ballots.
+    RankedChoice,
+}
+
+impl ChoiceMethod {
+    /// Returns the winning option for the rankings, none on a tie or without ballots.
+    pub fn resolve(&self, options: &[VoteId], rankings: &[Vec<VoteId>]) -> Option<VoteId> {
+        let ballots: Vec<(Vec<VoteId>, u128)> = rankings
+            .iter()
+            .map(|ranking| (ranking.clone(), 1))
+            .collect();
+        self.resolve_weighted(options, &ballots)
+    }
+
+    /// Returns the winning option for the rankings counted with their weight,
+    /// none on a tie or without ballots.
+    pub fn resolve_weighted(
+        &self,
+        options: &[VoteId],
+        ballots: &[(Vec<VoteId>, u128)],
+    ) -> Option<VoteId> {
+        let mut active = options.to_vec();
+        active.sort();
+        loop {
+            // Count each ballot for its highest ranked option still in the race
+            let counts: Vec<u128> = active
+                .iter()
+                .map(|option| {
+                    ballots
+                        .iter()
+                        .filter(|(ranking, _)| {
+                            ranking.iter().find(|vote| active.contains(vote)) == Some(option)
+                        })
+                        .map(|(_, weight)| weight)
+                        .sum()
+                })
+                .collect();
+            let total: u128 = counts.iter().sum();
+            let max = counts.iter().copied().max().unwrap_or(0);
+            let min = counts.iter().copied().min().unwrap_or(0);
+            if total == 0 {
+                return None;
+            }
+
+            match self {
+                ChoiceMethod::Plurality => {
+                    if counts.iter().filter(|count| **count == max).count() > 1 {
+                        return None;
+                    }
+                }
+                ChoiceMethod::RankedChoice => {
+                    if max * 2 <= total {
+                        // Note: All remaining options are tied when none can be eliminated
+                        if min == max {
+                            return None;
+                        }
+                        let mut index = 0;
+                        active.retain(|_| {
+                            index += 1;
+                            counts[index - 1] != min
+                        });
+                        continue;
+                    }
+                }
+            }
+            let index = counts.iter().position(|count| *count == max)?;
+            return Some(active[index].clone());
+        }
+    }
 }
 
 // ==== Motion Voice ====
@@ -127,10 +328,293 @@ pub struct MotionTally {
     pub ceo: bool,
     pub board: u64,
     pub members: u64,
+    /// The summed stake of all the voices.
+    pub weight: u128,
+}
+
+/// The eligible voices a motion tally is measured against.
+#[derive(Clone, Debug, PartialEq, BorshDeserialize, BorshSerialize)]
+pub struct MotionCount {
+    pub board: u64,
+    pub members: u64,
+    /// The summed stake of all the members.
+    pub value: u128,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize, BorshDeserialize, BorshSerialize)]
+#[serde(crate = "near_sdk::serde")]
+pub struct JsonTally {
+    pub ceo: bool,
+    pub board: u64,
+    pub members: u64,
+    pub weight: U128,
+}
+
+impl From<&MotionTally> for JsonTally {
+    fn from(tally: &MotionTally) -> Self {
+        Self {
+            ceo: tally.ceo,
+            board: tally.board,
+            members: tally.members,
+            weight: U128(tally.weight),
+        }
+    }
+}
+
+/// The role an account voted with, it sets what the voice adds to a tally.
+#[derive(
+    Clone, Copy, Debug, PartialEq, Serialize, Deserialize, BorshDeserialize, BorshSerialize,
+)]
+#[serde(crate = "near_sdk::serde")]
+pub enum BallotRole {
+    Ceo,
+    Board,
+    Member,
+}
+
+impl BallotRole {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            BallotRole::Ceo => "ceo",
+            BallotRole::Board => "board",
+            BallotRole::Member => "member",
+        }
+    }
+}
+
+/// The vote cast by an account, kept to retract it from the tally.
+#[derive(Clone, Debug, PartialEq, BorshDeserialize, BorshSerialize)]
+pub struct Ballot {
+    pub vote: VoteId,
+    pub role: BallotRole,
+    pub weight: u128,
+    /// The account that cast the vote on behalf of this account.
+    pub delegate: Option<AccountId>,
+    /// The preference order of a ranked ballot, empty for a single vote.
+    pub ranking: Vec<VoteId>,
+}
+
+impl Ballot {
+    /// Returns the preference order, a single vote ranks only its option.
+    pub fn get_ranking(&self) -> Vec<VoteId> {
+        match self.ranking.is_empty() {
+            true => vec![self.vote.clone()],
+            false => self.ranking.clone(),
+        }
+    }
+}
+
+/// A sealed vote, it only counts once the vote behind the hash is revealed.
+#[derive(Clone, Debug, PartialEq, BorshDeserialize, BorshSerialize)]
+pub struct Commitment {
+    pub hash: String,
+    pub role: BallotRole,
+    pub weight: u128,
 }
 
 #[derive(BorshDeserialize, BorshSerialize)]
 pub struct MotionVoices {
     pub tally: HashMap<VoteId, MotionTally>,
-    pub votes: UnorderedMap<AccountId, VoteId>,
+    pub votes: UnorderedMap<AccountId, Ballot>,
+    pub commits: UnorderedMap<AccountId, Commitment>,
+    /// The accounts that hold a delegated ballot, by their delegate.
+    pub proxies: LookupMap<AccountId, Vec<AccountId>>,
+}
+
+impl MotionVoices {
+    /// Drops the delegated ballot of the account from the index of its delegate.
+    pub fn remove_proxy(&mut self, ballot: &Ballot, proxy: &AccountId) {
+        let delegate = match &ballot.delegate {
+            Some(delegate) => delegate,
+            None => return,
+        };
+        let mut list = self.proxies.get(delegate).unwrap_or_default();
+        list.retain(|account| account != proxy);
+        match list.is_empty() {
+            true => self.proxies.remove(delegate),
+            false => self.proxies.insert(delegate, &list),
+        };
+    }
+
+    /// Indexes the delegated ballot of the account by its delegate.
+    pub fn insert_proxy(&mut self, ballot: &Ballot, proxy: &AccountId) {
+        if let Some(delegate) = &ballot.delegate {
+            let mut list = self.proxies.get(delegate).unwrap_or_default();
+            list.push(proxy.clone());
+            self.proxies.insert(delegate, &list);
+        }
+    }
+}
+
+// ==== Motion Snapshot ====
+
+/// The voters of a motion as they were when the motion was issued.
+#[derive(BorshDeserialize, BorshSerialize)]
+pub struct MotionSnapshot {
+    /// The voices and stake the tally is measured against.
+    pub count: MotionCount,
+    pub ceo: AccountId,
+    pub board: Vec<AccountId>,
+    /// The guild of the voters, the executive guild for a contract motion.
+    pub scope: GuildId,
+    /// The checkpoint the member stakes are read at.
+    pub epoch: u64,
+}
+
+impl MotionSnapshot {
+    pub fn is_ceo(&self, account: &AccountId) -> bool {
+        &self.ceo == account
+    }
+
+    pub fn is_board(&self, account: &AccountId) -> bool {
+        self.board.contains(account)
+    }
+}
+
+// ==== Vote Policy ====
+
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Hash,
+    Eq,
+    PartialEq,
+    Serialize,
+    Deserialize,
+    BorshSerialize,
+    BorshDeserialize,
+)]
+#[serde(crate = "near_sdk::serde")]
+pub enum MotionKind {
+    LockContract,
+    UnlockContract,
+    ChallengeContract,
+    ReplaceContract,
+    PolicyContract,
+    ProposalContract,
+    SpendContract,
+    CancelContract,
+    CreateGuild,
+    LockGuild,
+    UnlockGuild,
+    ChangeGuild,
+    ElectionGuild,
+    ChoiceGuild,
+    ChangeContract,
+}
+
+/// A combination of roles that need to concur to pass a motion.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
+#[serde(crate = "near_sdk::serde")]
+pub struct VoteRoles {
+    pub ceo: bool,
+    pub board: bool,
+    pub members: bool,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
+#[serde(crate = "near_sdk::serde")]
+pub struct VotePolicy {
+    /// Minimum of member voices in basis points before any role can concur.
+    pub quorum: u16,
+    /// Threshold in basis points for the board or members to concur.
+    pub threshold: u16,
+    /// The motion passes when all the roles of any one entry concur.
+    pub roles: Vec<VoteRoles>,
+    /// Measure the member quorum and threshold by stake instead of voices.
+    pub weighted: bool,
+    /// Time a passed contract lock or unlock waits in the queue before it takes effect.
+    #[serde(default)]
+    pub delay: Duration,
+    /// Time sealed votes are committed before the reveal starts.
+    #[serde(default)]
+    pub reveal: Duration,
+    /// Deposit in yoctoNEAR the issuer attaches to open a motion of the kind.
+    #[serde(default)]
+    pub bond: Option<U128>,
+}
+
+impl VotePolicy {
+    /// Validates the policy for the kind of motion it decides.
+    pub fn assert_valid(&self, kind: MotionKind) {
+        require!(
+            self.quorum <= MAX_BASE_POINTS_TOTAL,
+            "Quorum can not be more than 100_00 basis points"
+        );
+        require!(
+            self.threshold > 0 && self.threshold <= MAX_BASE_POINTS_TOTAL,
+            "Threshold must be between 1 and 100_00 basis points"
+        );
+        require!(
+            !self.roles.is_empty(),
+            "The policy requires at least one set of roles"
+        );
+        for roles in self.roles.iter() {
+            require!(
+                roles.ceo || roles.board || roles.members,
+                "Each set of roles requires at least one role"
+            );
+        }
+        // Note: Only a passed contract lock or unlock waits in the queue
+        require!(
+            self.delay.as_nanos() == 0
+                || kind == MotionKind::LockContract
+                || kind == MotionKind::UnlockContract,
+            "A delay is only supported for contract lock and unlock motions"
+        );
+        let expires = match kind {
+            MotionKind::LockContract | MotionKind::LockGuild => EXPIRE_12H,
+            _ => EXPIRE_48H,
+        };
+        require!(
+            self.reveal < expires,
+            "The reveal has to start before the motion expires"
+        );
+    }
+
+    /// Returns true when the tally passes the policy.
+    ///
+    /// Note: A role without any voters can never concur.
+    pub fn is_passed(&self, tally: &MotionTally, count: &MotionCount) -> bool {
+        let quorum = self.is_quorum(tally, count);
+        let members = match self.weighted {
+            true => {
+                count.value > 0 && tally.weight >= get_target_value(count.value, self.threshold)
+            }
+            false => {
+                count.members > 0 && tally.members >= get_target(count.members, self.threshold)
+            }
+        };
+        let board = count.board > 0 && tally.board >= get_target(count.board, self.threshold);
+        // Note: The quorum is measured on the members, it only gates the sets that include them
+        self.roles.iter().any(|roles| {
+            (!roles.ceo || tally.ceo)
+                && (!roles.board || board)
+                && (!roles.members || (quorum && members))
+        })
+    }
+
+    /// Returns true when the member voices, or their stake when weighted, reach the quorum.
+    pub fn is_quorum(&self, tally: &MotionTally, count: &MotionCount) -> bool {
+        match self.weighted {
+            true => tally.weight >= get_target_value(count.value, self.quorum),
+            false => tally.members >= get_target(count.members, self.quorum),
+        }
+    }
+}
+
+/// Returns the basis points of the count, rounded up.
+#[inline(always)]
+pub fn get_target(count: u64, basis_points: u16) -> u64 {
+    (count * basis_points as u64).div_ceil(MAX_BASE_POINTS_TOTAL as u64)
+}
+
+/// Returns the basis points of the value, rounded up.
+#[inline(always)]
+pub fn get_target_value(value: u128, basis_points: u16) -> u128 {
+    let total = MAX_BASE_POINTS_TOTAL as u128;
+    let (div, rem) = (value / total, value % total);
+    // Note: Split the value to avoid an overflow on large stakes
+    div * basis_points as u128 + (rem * basis_points as u128).div_ceil(total)
 }
@@ -1,5 +1,6 @@
 use crate::*;
 
+use crate::event::*;
 use crate::share::*;
 
 pub mod api;
@@ -1,25 +1,103 @@
 use super::*;
 
-pub const EXPIRE_12H: u64 = 43200;
-pub const EXPIRE_24H: u64 = 86400;
-pub const EXPIRE_48H: u64 = 172800;
+pub const EXPIRE_12H: Duration = Duration::from_hours(12);
+pub const EXPIRE_24H: Duration = Duration::from_hours(24);
+pub const EXPIRE_48H: Duration = Duration::from_hours(48);
 
 #[inline(always)]
 pub fn get_vote_id_no() -> VoteId {
-    return "V:N".to_string().into();
+    "V:N".to_string().into()
 }
 
 #[inline(always)]
 pub fn get_vote_id_yes() -> VoteId {
-    return "V:Y".to_string().into();
+    "V:Y".to_string().into()
 }
 
 #[inline(always)]
 pub fn get_vote_id_agree() -> VoteId {
-    return "V:A".to_string().into();
+    "V:A".to_string().into()
 }
 
-pub fn new_motion_to_lock_contract(details: &String) -> MotionInfo {
+#[inline(always)]
+pub fn get_vote_id_option(index: usize) -> VoteId {
+    format!("V:{}", index).into()
+}
+
+/// Returns the hex encoded hash that seals the ranking of an account, a single
+/// vote is a ranking of one option.
+pub fn get_commitment_hash(account: &AccountId, ranking: &[VoteId], salt: &str) -> String {
+    let ranking: Vec<String> = ranking.iter().map(|vote| vote.to_string()).collect();
+    let value = format!("{}:{}:{}", account, ranking.join(","), salt);
+    env::sha256(value.as_bytes())
+        .iter()
+        .map(|byte| format!("{:02x}", byte))
+        .collect()
+}
+
+pub fn get_default_policy(kind: MotionKind) -> VotePolicy {
+    let ceo = |board, members| VoteRoles {
+        ceo: true,
+        board,
+        members,
+    };
+    let board_and_members = VoteRoles {
+        ceo: false,
+        board: true,
+        members: true,
+    };
+    let roles = match kind {
+        // board threshold, the CEO locks without a vote
+        MotionKind::LockContract | MotionKind::LockGuild => vec![VoteRoles {
+            ceo: false,
+            board: true,
+            members: false,
+        }],
+        // ceo + (board | members) OR board & members
+        MotionKind::UnlockContract
+        | MotionKind::ProposalContract
+        | MotionKind::SpendContract
+        | MotionKind::CreateGuild
+        | MotionKind::UnlockGuild
+        | MotionKind::ChangeGuild => {
+            vec![ceo(true, false), ceo(false, true), board_and_members]
+        }
+        // board | members
+        MotionKind::ChallengeContract | MotionKind::CancelContract => vec![
+            VoteRoles {
+                ceo: false,
+                board: true,
+                members: false,
+            },
+            VoteRoles {
+                ceo: false,
+                board: false,
+                members: true,
+            },
+        ],
+        // board & members, the ballots decide elections and choice motions
+        MotionKind::ReplaceContract
+        | MotionKind::PolicyContract
+        | MotionKind::ChangeContract
+        | MotionKind::ElectionGuild
+        | MotionKind::ChoiceGuild => vec![board_and_members],
+    };
+    VotePolicy {
+        quorum: 0,
+        threshold: 8000,
+        roles,
+        weighted: false,
+        delay: Duration::from_nanos(0),
+        reveal: match kind {
+            // Note: Lock motions expire after 12 hours
+            MotionKind::LockContract | MotionKind::LockGuild => Duration::from_hours(6),
+            _ => EXPIRE_24H,
+        },
+        bond: None,
+    }
+}
+
+pub fn new_motion_to_lock_contract(details: &str) -> MotionInfo {
     let mut options = HashMap::new();
     options.insert(
         get_vote_id_agree(),
@@ -32,20 +110,21 @@ pub fn new_motion_to_lock_contract(details: &String) -> MotionInfo {
     );
     MotionInfo {
         title: "Contract Lockdown".to_string(),
-        details: details.clone(),
-        issued_at: env::block_timestamp(),
-        starts_at: env::block_timestamp(),
-        expires_at: env::block_timestamp() + EXPIRE_12H,
+        details: details.to_string(),
+        issued_at: Timestamp::now(),
+        starts_at: Timestamp::now(),
+        expires_at: Timestamp::now() + EXPIRE_12H,
         executor: Some(env::current_account_id()),
         media: None,
         media_hash: None,
         reference: None,
         reference_hash: None,
         vote_options: options,
+        reveal_at: None,
     }
 }
 
-pub fn new_motion_to_unlock_contract(details: &String) -> MotionInfo {
+pub fn new_motion_to_unlock_contract(details: &str) -> MotionInfo {
     let mut options = HashMap::new();
     options.insert(
         get_vote_id_agree(),
@@ -58,16 +137,179 @@ pub fn new_motion_to_unlock_contract(details: &String) -> MotionInfo {
     );
     MotionInfo {
         title: "Contract Unlock".to_string(),
-        details: details.clone(),
-        issued_at: env::block_timestamp(),
-        starts_at: env::block_timestamp(),
-        expires_at: env::block_timestamp() + EXPIRE_48H,
+        details: details.to_string(),
+        issued_at: Timestamp::now(),
+        starts_at: Timestamp::now(),
+        expires_at: Timestamp::now() + EXPIRE_48H,
+        executor: Some(env::current_account_id()),
+        media: None,
+        media_hash: None,
+        reference: None,
+        reference_hash: None,
+        vote_options: options,
+        reveal_at: None,
+    }
+}
+
+pub fn new_motion_to_challenge_contract(details: &str) -> MotionInfo {
+    let mut options = HashMap::new();
+    options.insert(
+        get_vote_id_agree(),
+        VoteInfo {
+            title: "agree".to_string(),
+            details: Some("You agree to challenge the executive.".to_string()),
+            reference: None,
+            reference_hash: None,
+        },
+    );
+    MotionInfo {
+        title: "Contract Challenge".to_string(),
+        details: details.to_string(),
+        issued_at: Timestamp::now(),
+        starts_at: Timestamp::now(),
+        expires_at: Timestamp::now() + EXPIRE_48H,
+        executor: Some(env::current_account_id()),
+        media: None,
+        media_hash: None,
+        reference: None,
+        reference_hash: None,
+        vote_options: options,
+        reveal_at: None,
+    }
+}
+
+pub fn new_motion_to_cancel_contract(details: &str, expires_at: Timestamp) -> MotionInfo {
+    let mut options = HashMap::new();
+    options.insert(
+        get_vote_id_agree(),
+        VoteInfo {
+            title: "agree".to_string(),
+            details: Some("You agree to cancel the queued motion.".to_string()),
+            reference: None,
+            reference_hash: None,
+        },
+    );
+    MotionInfo {
+        title: "Contract Cancel".to_string(),
+        details: details.to_string(),
+        issued_at: Timestamp::now(),
+        starts_at: Timestamp::now(),
+        expires_at,
+        executor: Some(env::current_account_id()),
+        media: None,
+        media_hash: None,
+        reference: None,
+        reference_hash: None,
+        vote_options: options,
+        reveal_at: None,
+    }
+}
+
+pub fn new_motion_to_replace_contract(details: &str) -> MotionInfo {
+    let mut options = HashMap::new();
+    options.insert(
+        get_vote_id_agree(),
+        VoteInfo {
+            title: "agree".to_string(),
+            details: Some("You agree to replace the executive.".to_string()),
+            reference: None,
+            reference_hash: None,
+        },
+    );
+    MotionInfo {
+        title: "Contract Replace".to_string(),
+        details: details.to_string(),
+        issued_at: Timestamp::now(),
+        starts_at: Timestamp::now(),
+        expires_at: Timestamp::now() + EXPIRE_48H,
+        executor: Some(env::current_account_id()),
+        media: None,
+        media_hash: None,
+        reference: None,
+        reference_hash: None,
+        vote_options: options,
+        reveal_at: None,
+    }
+}
+
+pub fn new_motion_to_execute_proposal(details: &str) -> MotionInfo {
+    let mut options = HashMap::new();
+    options.insert(
+        get_vote_id_agree(),
+        VoteInfo {
+            title: "agree".to_string(),
+            details: Some("You agree to execute the proposal.".to_string()),
+            reference: None,
+            reference_hash: None,
+        },
+    );
+    MotionInfo {
+        title: "Contract Proposal".to_string(),
+        details: details.to_string(),
+        issued_at: Timestamp::now(),
+        starts_at: Timestamp::now(),
+        expires_at: Timestamp::now() + EXPIRE_48H,
+        executor: Some(env::current_account_id()),
+        media: None,
+        media_hash: None,
+        reference: None,
+        reference_hash: None,
+        vote_options: options,
+        reveal_at: None,
+    }
+}
+
+pub fn new_motion_to_spend_treasury(details: &str) -> MotionInfo {
+    let mut options = HashMap::new();
+    options.insert(
+        get_vote_id_agree(),
+        VoteInfo {
+            title: "agree".to_string(),
+            details: Some("You agree to transfer the amount.".to_string()),
+            reference: None,
+            reference_hash: None,
+        },
+    );
+    MotionInfo {
+        title: "Treasury Spend".to_string(),
+        details: details.to_string(),
+        issued_at: Timestamp::now(),
+        starts_at: Timestamp::now(),
+        expires_at: Timestamp::now() + EXPIRE_48H,
+        executor: Some(env::current_account_id()),
+        media: None,
+        media_hash: None,
+        reference: None,
+        reference_hash: None,
+        vote_options: options,
+        reveal_at: None,
+    }
+}
+
+pub fn new_motion_to_create_guild(details: &str) -> MotionInfo {
+    let mut options = HashMap::new();
+    options.insert(
+        get_vote_id_agree(),
+        VoteInfo {
+            title: "agree".to_string(),
+            details: Some("You agree to the creation of the guild.".to_string()),
+            reference: None,
+            reference_hash: None,
+        },
+    );
+    MotionInfo {
+        title: "Guild Creation".to_string(),
+        details: details.to_string(),
+        issued_at: Timestamp::now(),
+        starts_at: Timestamp::now(),
+        expires_at: Timestamp::now() + EXPIRE_48H,
         executor: Some(env::current_account_id()),
         media: None,
         media_hash: None,
         reference: None,
         reference_hash: None,
         vote_options: options,
+        reveal_at: None,
     }
 }
 
@@ -85,15 +327,16 @@ pub fn new_motion_to_lock_guild(details: String) -> MotionInfo {
     MotionInfo {
         title: "Guild Lockdown".to_string(),
         details: details.clone(),
-        issued_at: env::block_timestamp(),
-        starts_at: env::block_timestamp(),
-        expires_at: env::block_timestamp() + EXPIRE_12H,
+        issued_at: Timestamp::now(),
+        starts_at: Timestamp::now(),
+        expires_at: Timestamp::now() + EXPIRE_12H,
         executor: Some(env::current_account_id()),
         media: None,
         media_hash: None,
         reference: None,
         reference_hash: None,
         vote_options: options,
+        reveal_at: None,
     }
 }
 
@@ -111,14 +354,151 @@ pub fn new_motion_to_unlock_guild(details: String) -> MotionInfo {
     MotionInfo {
         title: "Guild Unlock".to_string(),
         details: details.clone(),
-        issued_at: env::block_timestamp(),
-        starts_at: env::block_timestamp(),
-        expires_at: env::block_timestamp() + EXPIRE_48H,
+        issued_at: Timestamp::now(),
+        starts_at: Timestamp::now(),
+        expires_at: Timestamp::now() + EXPIRE_48H,
+        executor: Some(env::current_account_id()),
+        media: None,
+        media_hash: None,
+        reference: None,
+        reference_hash: None,
+        vote_options: options,
+        reveal_at: None,
+    }
+}
+
+pub fn new_motion_to_change_guild(details: String) -> MotionInfo {
+    let mut options = HashMap::new();
+    options.insert(
+        get_vote_id_agree(),
+        VoteInfo {
+            title: "agree".to_string(),
+            details: Some("You agree to change the guild.".to_string()),
+            reference: None,
+            reference_hash: None,
+        },
+    );
+    MotionInfo {
+        title: "Guild Change".to_string(),
+        details: details.clone(),
+        issued_at: Timestamp::now(),
+        starts_at: Timestamp::now(),
+        expires_at: Timestamp::now() + EXPIRE_48H,
+        executor: Some(env::current_account_id()),
+        media: None,
+        media_hash: None,
+        reference: None,
+        reference_hash: None,
+        vote_options: options,
+        reveal_at: None,
+    }
+}
+
+pub fn new_motion_to_elect_board(details: String, candidates: &[AccountId]) -> MotionInfo {
+    let mut options = HashMap::new();
+    for (index, candidate) in candidates.iter().enumerate() {
+        options.insert(
+            get_vote_id_option(index),
+            VoteInfo {
+                title: format!("candidate {}", index + 1),
+                details: Some(candidate.to_string()),
+                reference: None,
+                reference_hash: None,
+            },
+        );
+    }
+    MotionInfo {
+        title: "Board Election".to_string(),
+        details: details.clone(),
+        issued_at: Timestamp::now(),
+        starts_at: Timestamp::now(),
+        expires_at: Timestamp::now() + EXPIRE_48H,
+        executor: Some(env::current_account_id()),
+        media: None,
+        media_hash: None,
+        reference: None,
+        reference_hash: None,
+        vote_options: options,
+        reveal_at: None,
+    }
+}
+
+pub fn new_motion_with_options(
+    title: String,
+    details: String,
+    options: Vec<VoteInfo>,
+) -> MotionInfo {
+    let vote_options = options
+        .into_iter()
+        .enumerate()
+        .map(|(index, option)| (get_vote_id_option(index), option))
+        .collect();
+    MotionInfo {
+        title,
+        details,
+        issued_at: Timestamp::now(),
+        starts_at: Timestamp::now(),
+        expires_at: Timestamp::now() + EXPIRE_48H,
+        executor: None,
+        media: None,
+        media_hash: None,
+        reference: None,
+        reference_hash: None,
+        vote_options,
+        reveal_at: None,
+    }
+}
+
+pub fn new_motion_to_change_policy(details: &str) -> MotionInfo {
+    let mut options = HashMap::new();
+    options.insert(
+        get_vote_id_agree(),
+        VoteInfo {
+            title: "agree".to_string(),
+            details: Some("You agree to change the vote policy.".to_string()),
+            reference: None,
+            reference_hash: None,
+        },
+    );
+    MotionInfo {
+        title: "Policy Change".to_string(),
+        details: details.to_string(),
+        issued_at: Timestamp::now(),
+        starts_at: Timestamp::now(),
+        expires_at: Timestamp::now() + EXPIRE_48H,
+        executor: Some(env::current_account_id()),
+        media: None,
+        media_hash: None,
+        reference: None,
+        reference_hash: None,
+        vote_options: options,
+        reveal_at: None,
+    }
+}
+
+pub fn new_motion_to_change_contract(details: &str) -> MotionInfo {
+    let mut options = HashMap::new();
+    options.insert(
+        get_vote_id_agree(),
+        VoteInfo {
+            title: "agree".to_string(),
+            details: Some("You agree to change the contract.".to_string()),
+            reference: None,
+            reference_hash: None,
+        },
+    );
+    MotionInfo {
+        title: "Contract Change".to_string(),
+        details: details.to_string(),
+        issued_at: Timestamp::now(),
+        starts_at: Timestamp::now(),
+        expires_at: Timestamp::now() + EXPIRE_48H,
         executor: Some(env::current_account_id()),
         media: None,
         media_hash: None,
         reference: None,
         reference_hash: None,
         vote_options: options,
+        reveal_at: None,
     }
 }
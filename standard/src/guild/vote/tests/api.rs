@@ -50,35 +50,37 @@ fn get_option_duality(idn: VoteId, idy: VoteId) -> HashMap<VoteId, VoteInfo> {
     options
 }
 
-fn get_motion_alpha(time: u64, options: &HashMap<VoteId, VoteInfo>) -> MotionInfo {
+fn get_motion_alpha(time: Timestamp, options: &HashMap<VoteId, VoteInfo>) -> MotionInfo {
     MotionInfo {
         title: "Motion Alpha".to_string(),
         details: " A alpha unit testing motion".to_string(),
         issued_at: time,
         starts_at: time,
-        expires_at: time + 1,
+        expires_at: time + Duration::from_nanos(1),
         executor: None,
         media: None,
         media_hash: None,
         reference: None,
         reference_hash: None,
         vote_options: options.clone(),
+        reveal_at: None,
     }
 }
 
-fn get_motion_beta(time: u64, options: &HashMap<VoteId, VoteInfo>) -> MotionInfo {
+fn get_motion_beta(time: Timestamp, options: &HashMap<VoteId, VoteInfo>) -> MotionInfo {
     MotionInfo {
         title: "Motion Beta".to_string(),
         details: " A beta unit testing motion".to_string(),
         issued_at: time,
         starts_at: time,
-        expires_at: time + 1,
+        expires_at: time + Duration::from_nanos(1),
         executor: None,
         media: None,
         media_hash: None,
         reference: None,
         reference_hash: None,
         vote_options: options.clone(),
+        reveal_at: None,
     }
 }
 
@@ -90,11 +92,24 @@ fn votes_new() {
     assert_eq!(data.motion_map.len(), 0);
 }
 
+#[test]
+fn votes_next_id() {
+    let gid: GuildId = "G:01".to_string().into();
+
+    let mut data = Votes::new();
+    assert_eq!("LC:1", data.next_id("LC", None).as_str());
+    assert!(data.latest_map.get(&gid).is_none());
+    assert_eq!("GM:2", data.next_id("GM", Some(&gid)).as_str());
+    assert_eq!("GM:3", data.next_id("GM", Some(&gid)).as_str());
+    assert_eq!(3, data.motion_count);
+    assert_eq!(Some("GM:3".to_string().into()), data.latest_map.get(&gid));
+}
+
 #[test]
 fn votes_issue() {
     let mid: &MotionId = &"M:01".to_string().into();
     let vid: VoteId = "V:01".to_string().into();
-    let time = env::block_timestamp();
+    let time = Timestamp::now();
 
     let mut data = Votes::new();
     let options = get_option_agree(vid);
@@ -126,13 +141,12 @@ fn votes_issue() {
         let val = voices
             .tally
             .get(vote_id)
-            .expect("option not found in tally")
-            .clone();
+            .expect("option not found in tally");
         require!(!val.ceo, "initial tally needs to be zero");
         require!(val.board == 0, "initial tally.board needs to be zero");
         require!(val.members == 0, "initial tally.members needs to be zero");
     }
-    require!(0 == voices.votes.len(), "the votes should be zero");
+    require!(voices.votes.is_empty(), "the votes should be zero");
 }
 
 #[test]
@@ -140,7 +154,7 @@ fn votes_issue() {
 fn votes_issue_motion_id() {
     let mid: &MotionId = &"M:01".to_string().into();
     let vid: VoteId = "V:01".to_string().into();
-    let time = env::block_timestamp();
+    let time = Timestamp::now();
 
     let mut data = Votes::new();
     data.issue(mid, &get_motion_beta(time, &get_option_agree(vid.clone())));
@@ -156,13 +170,13 @@ fn votes_issue_start_time() {
 
     let mid: &MotionId = &"M:01".to_string().into();
     let vid: VoteId = "V:01".to_string().into();
-    let time = env::block_timestamp();
+    let time = Timestamp::now();
 
     let mut data = Votes::new();
 
     let mut motion = get_motion_alpha(time, &get_option_agree(vid));
-    motion.issued_at = time - 1;
-    motion.starts_at = time - 1;
+    motion.issued_at = time - Duration::from_nanos(1);
+    motion.starts_at = time - Duration::from_nanos(1);
     data.issue(mid, &motion);
 }
 
@@ -172,7 +186,7 @@ fn votes_voice() {
     let idb: &MotionId = &"M:02".to_string().into();
     let idn: VoteId = "V:NO".to_string().into();
     let idy: VoteId = "V:YES".to_string().into();
-    let time = env::block_timestamp();
+    let time = Timestamp::now();
 
     let mut data = Votes::new();
     data.issue(ida, &get_motion_beta(time, &get_option_agree(idn.clone())));
@@ -191,10 +205,10 @@ fn votes_voice() {
         .get(ida)
         .expect("missing voices on motion a.a");
 
-    require!(voices.votes.len() == 0, "voices.a length hsould be 0");
+    require!(voices.votes.is_empty(), "voices.a length hsould be 0");
 
-    data.vote_ceo(ida, idn.clone(), account_nodra!());
-    data.vote_board(ida, idn.clone(), account_vecno!());
+    data.vote_ceo(ida, idn.clone(), account_nodra!(), 100);
+    data.vote_board(ida, idn.clone(), account_vecno!(), 100);
 
     voices = data
         .voices_map
@@ -210,24 +224,25 @@ fn votes_voice() {
         .votes
         .get(&account_vecno!())
         .expect("missing voice vecno");
-    require!(voice_nodra == idn.clone(), "voice_nodra should be no");
-    require!(voice_vecno == idn.clone(), "voice_vecno should be no");
+    require!(voice_nodra.vote == idn.clone(), "voice_nodra should be no");
+    require!(voice_vecno.vote == idn.clone(), "voice_vecno should be no");
 
     let tally_no = voices.tally.get(&idn.clone()).expect("missing tally no");
 
     require!(tally_no.ceo, "tally_no needs to be true");
     require!(tally_no.board == 1, "tally_no.board should be 1");
     require!(tally_no.members == 2, "tally_no.members should be 2");
+    require!(tally_no.weight == 200, "tally_no.weight should be 200");
 
     voices = data
         .voices_map
         .get(idb)
         .expect("missing voices on motion b.a");
 
-    require!(voices.votes.len() == 0, "voices.b length hsould be 0");
+    require!(voices.votes.is_empty(), "voices.b length hsould be 0");
 
-    data.vote_board(idb, idn.clone(), account_nodra!());
-    data.vote_member(idb, idy.clone(), account_vecno!());
+    data.vote_board(idb, idn.clone(), account_nodra!(), 100);
+    data.vote_member(idb, idy.clone(), account_vecno!(), 100);
 
     voices = data
         .voices_map
@@ -240,7 +255,7 @@ fn votes_voice() {
         .votes
         .get(&account_vecno!())
         .expect("missing voice vecno");
-    require!(voice_vecno == idy.clone(), "voice_vecno should be yes");
+    require!(voice_vecno.vote == idy.clone(), "voice_vecno should be yes");
 
     let tally_no = voices.tally.get(&idn.clone()).expect("missing tally no");
     let tally_yes = voices.tally.get(&idy.clone()).expect("missing tally yes");
@@ -260,14 +275,14 @@ fn votes_voice_motion() {
     let mid: &MotionId = &"M:01".to_string().into();
     let vid: VoteId = "V:01".to_string().into();
     let eid: VoteId = "V:02".to_string().into();
-    let time = env::block_timestamp();
+    let time = Timestamp::now();
 
     let mut data = Votes::new();
     let options = get_option_agree(vid);
     let motion = get_motion_alpha(time, &options.clone());
     data.issue(mid, &motion.clone());
 
-    data.vote_ceo(mid, eid.clone(), account_vecno!());
+    data.vote_ceo(mid, eid.clone(), account_vecno!(), 100);
 }
 
 #[test]
@@ -275,7 +290,7 @@ fn votes_voice_motion() {
 fn votes_voice_expired() {
     let mid: &MotionId = &"M:01".to_string().into();
     let vid: VoteId = "V:01".to_string().into();
-    let time = env::block_timestamp();
+    let time = Timestamp::now();
 
     let mut data = Votes::new();
     let options = get_option_agree(vid.clone());
@@ -286,7 +301,7 @@ fn votes_voice_expired() {
     context.block_timestamp = 10;
     testing_env!(context);
 
-    data.vote_member(mid, vid.clone(), account_vecno!());
+    data.vote_member(mid, vid.clone(), account_vecno!(), 100);
 }
 
 #[test]
@@ -294,7 +309,7 @@ fn votes_voice_expired() {
 fn votes_voice_executed() {
     let mid: &MotionId = &"M:01".to_string().into();
     let vid: VoteId = "V:01".to_string().into();
-    let time = env::block_timestamp();
+    let time = Timestamp::now();
 
     let mut data = Votes::new();
     let options = get_option_agree(vid.clone());
@@ -305,7 +320,7 @@ fn votes_voice_executed() {
     state.executed = true;
     data.motion_map.insert(mid, &state);
 
-    data.vote_member(mid, vid.clone(), account_vecno!());
+    data.vote_member(mid, vid.clone(), account_vecno!(), 100);
 }
 
 #[test]
@@ -313,13 +328,369 @@ fn votes_voice_executed() {
 fn votes_voice_account() {
     let mid: &MotionId = &"M:01".to_string().into();
     let vid: VoteId = "V:01".to_string().into();
-    let time = env::block_timestamp();
+    let time = Timestamp::now();
 
     let mut data = Votes::new();
     let options = get_option_agree(vid.clone());
     let motion = get_motion_alpha(time, &options.clone());
     data.issue(mid, &motion.clone());
 
-    data.vote_board(mid, vid.clone(), account_vecno!());
-    data.vote_member(mid, vid.clone(), account_vecno!());
+    data.vote_board(mid, vid.clone(), account_vecno!(), 100);
+    data.vote_member(mid, vid.clone(), account_vecno!(), 100);
+}
+
+// ==== Views ====
+
+#[test]
+fn votes_get_motion() {
+    let mid: &MotionId = &"M:01".to_string().into();
+    let idn: VoteId = "V:N".to_string().into();
+    let idy: VoteId = "V:Y".to_string().into();
+    let time = Timestamp::now();
+
+    let mut data = Votes::new();
+    assert!(data.get_motion(mid).is_none());
+    data.issue(
+        mid,
+        &get_motion_alpha(time, &get_option_duality(idn.clone(), idy.clone())),
+    );
+    data.vote_board(mid, idy.clone(), account_nodra!(), 70);
+
+    let motion = data.get_motion(mid).expect("missing motion");
+    assert_eq!(mid, &motion.id);
+    assert_eq!(MotionStatus::Open, motion.status);
+    assert_eq!("Motion Alpha", motion.info.title);
+    assert_eq!(
+        Some(&JsonTally {
+            ceo: false,
+            board: 1,
+            members: 1,
+            weight: U128(70),
+        }),
+        motion.tally.get(&idy)
+    );
+    assert_eq!(0, motion.tally.get(&idn).expect("missing tally").members);
+
+    assert_eq!(Some(idy), data.get_vote(mid, &account_nodra!()));
+    assert!(data.get_vote(mid, &account_vecno!()).is_none());
+    assert!(data
+        .get_vote(&"M:02".to_string().into(), &account_nodra!())
+        .is_none());
+}
+
+#[test]
+fn votes_get_motions() {
+    let ida: &MotionId = &"M:01".to_string().into();
+    let idb: &MotionId = &"M:02".to_string().into();
+    let idc: &MotionId = &"M:03".to_string().into();
+    let vid: VoteId = "V:01".to_string().into();
+    let time = Timestamp::now();
+
+    let mut data = Votes::new();
+    let options = get_option_agree(vid);
+    data.issue(ida, &get_motion_alpha(time, &options));
+    data.issue(idb, &get_motion_beta(time, &options));
+    data.issue(idc, &get_motion_alpha(time, &options));
+    data.execute(idb);
+
+    assert_eq!(3, data.get_motions(None, None, None).len());
+    let page = data.get_motions(None, Some(U128(1)), Some(1));
+    assert_eq!(1, page.len());
+    assert_eq!(idb, &page[0].id);
+
+    let open = data.get_motions(Some(MotionStatus::Open), None, None);
+    assert_eq!(2, open.len());
+    assert_eq!(idc, &open[1].id);
+    let executed = data.get_motions(Some(MotionStatus::Executed), None, None);
+    assert_eq!(1, executed.len());
+    assert_eq!(idb, &executed[0].id);
+
+    let mut context = VMContextBuilder::new().build();
+    context.block_timestamp = 10;
+    testing_env!(context);
+    assert_eq!(
+        2,
+        data.get_motions(Some(MotionStatus::Expired), None, None)
+            .len()
+    );
+}
+
+#[test]
+fn votes_unvote() {
+    let mid: &MotionId = &"M:01".to_string().into();
+    let idn: VoteId = "V:NO".to_string().into();
+    let idy: VoteId = "V:YES".to_string().into();
+    let time = Timestamp::now();
+
+    let mut data = Votes::new();
+    data.issue(
+        mid,
+        &get_motion_alpha(time, &get_option_duality(idn.clone(), idy.clone())),
+    );
+    data.vote_ceo(mid, idy.clone(), account_vecno!(), 100);
+    data.vote_board(mid, idy.clone(), account_nodra!(), 70);
+
+    let voices = data.unvote(mid, account_vecno!());
+    assert_eq!(1, voices.votes.len());
+    let tally = voices.tally.get(&idy).expect("missing tally yes");
+    assert!(!tally.ceo);
+    assert_eq!(1, tally.board);
+    assert_eq!(1, tally.members);
+    assert_eq!(70, tally.weight);
+    assert!(data.get_vote(mid, &account_vecno!()).is_none());
+
+    // Note: A retracted voice can vote again
+    data.vote_member(mid, idn.clone(), account_vecno!(), 100);
+    assert_eq!(Some(idn), data.get_vote(mid, &account_vecno!()));
+}
+
+#[test]
+fn votes_revote() {
+    let mid: &MotionId = &"M:01".to_string().into();
+    let idn: VoteId = "V:NO".to_string().into();
+    let idy: VoteId = "V:YES".to_string().into();
+    let time = Timestamp::now();
+
+    let mut data = Votes::new();
+    data.issue(
+        mid,
+        &get_motion_alpha(time, &get_option_duality(idn.clone(), idy.clone())),
+    );
+    data.vote_board(mid, idy.clone(), account_nodra!(), 70);
+
+    let voices = data.revote(mid, idn.clone(), account_nodra!());
+    let tally_no = voices.tally.get(&idn).expect("missing tally no");
+    let tally_yes = voices.tally.get(&idy).expect("missing tally yes");
+    assert_eq!(
+        (1, 1, 70),
+        (tally_no.board, tally_no.members, tally_no.weight)
+    );
+    assert_eq!(
+        (0, 0, 0),
+        (tally_yes.board, tally_yes.members, tally_yes.weight)
+    );
+    assert_eq!(
+        Some(Ballot {
+            vote: idn,
+            role: BallotRole::Board,
+            weight: 70,
+            delegate: None,
+            ranking: vec![],
+        }),
+        voices.votes.get(&account_nodra!())
+    );
+}
+
+#[test]
+fn votes_proxies() {
+    let mid: &MotionId = &"M:01".to_string().into();
+    let idn: VoteId = "V:NO".to_string().into();
+    let idy: VoteId = "V:YES".to_string().into();
+    let time = Timestamp::now();
+    let alice: AccountId = "alice.near".parse().unwrap();
+
+    let mut data = Votes::new();
+    data.issue(
+        mid,
+        &get_motion_alpha(time, &get_option_duality(idn.clone(), idy.clone())),
+    );
+    data.vote_board(mid, idy.clone(), account_nodra!(), 70);
+    data.vote_member(mid, idn.clone(), account_vecno!(), 100);
+
+    // Note: The account that voted directly keeps its own vote
+    let voices = data.vote_proxies(
+        mid,
+        &account_nodra!(),
+        vec![(alice.clone(), 30), (account_vecno!(), 100)],
+    );
+    let tally_yes = voices.tally.get(&idy).expect("missing tally yes");
+    assert_eq!(
+        (1, 2, 100),
+        (tally_yes.board, tally_yes.members, tally_yes.weight)
+    );
+    assert_eq!(
+        Some(Ballot {
+            vote: idy.clone(),
+            role: BallotRole::Member,
+            weight: 30,
+            delegate: Some(account_nodra!()),
+            ranking: vec![],
+        }),
+        voices.votes.get(&alice)
+    );
+    assert_eq!(Some(idn.clone()), data.get_vote(mid, &account_vecno!()));
+    assert_eq!(
+        Some(vec![alice.clone()]),
+        voices.proxies.get(&account_nodra!())
+    );
+
+    // Note: The delegated voices follow the delegate
+    let voices = data.revote(mid, idn.clone(), account_nodra!());
+    let tally_no = voices.tally.get(&idn).expect("missing tally no");
+    assert_eq!((3, 200), (tally_no.members, tally_no.weight));
+    assert_eq!(Some(idn), data.get_vote(mid, &alice));
+
+    let voices = data.unvote(mid, account_nodra!());
+    assert_eq!(1, voices.votes.len());
+    assert!(data.get_vote(mid, &alice).is_none());
+    assert!(voices.proxies.get(&account_nodra!()).is_none());
+}
+
+#[test]
+fn votes_proxies_direct() {
+    let mid: &MotionId = &"M:01".to_string().into();
+    let idn: VoteId = "V:NO".to_string().into();
+    let idy: VoteId = "V:YES".to_string().into();
+    let time = Timestamp::now();
+    let alice: AccountId = "alice.near".parse().unwrap();
+
+    let mut data = Votes::new();
+    data.issue(
+        mid,
+        &get_motion_alpha(time, &get_option_duality(idn.clone(), idy.clone())),
+    );
+    data.vote_board(mid, idy.clone(), account_nodra!(), 70);
+    data.vote_proxies(mid, &account_nodra!(), vec![(alice.clone(), 30)]);
+
+    // Note: A proxy that votes directly leaves the index of its delegate
+    let voices = data.vote_member(mid, idn.clone(), alice.clone(), 30);
+    assert!(voices.proxies.get(&account_nodra!()).is_none());
+    data.unvote(mid, account_nodra!());
+    assert_eq!(Some(idn), data.get_vote(mid, &alice));
+}
+
+#[test]
+#[should_panic(expected = "The account has not voted on the motion")]
+fn votes_unvote_not_voted() {
+    let mid: &MotionId = &"M:01".to_string().into();
+    let vid: VoteId = "V:01".to_string().into();
+
+    let mut data = Votes::new();
+    data.issue(
+        mid,
+        &get_motion_alpha(Timestamp::now(), &get_option_agree(vid)),
+    );
+    data.unvote(mid, account_vecno!());
+}
+
+#[test]
+#[should_panic(expected = "Can not vote on an executed motion")]
+fn votes_unvote_executed() {
+    let mid: &MotionId = &"M:01".to_string().into();
+    let vid: VoteId = "V:01".to_string().into();
+
+    let mut data = Votes::new();
+    data.issue(
+        mid,
+        &get_motion_alpha(Timestamp::now(), &get_option_agree(vid.clone())),
+    );
+    data.vote_member(mid, vid, account_vecno!(), 100);
+    data.execute(mid);
+    data.unvote(mid, account_vecno!());
+}
+
+#[test]
+#[should_panic(expected = "Can not vote on an expired motion")]
+fn votes_revote_expired() {
+    let mid: &MotionId = &"M:01".to_string().into();
+    let idn: VoteId = "V:NO".to_string().into();
+    let idy: VoteId = "V:YES".to_string().into();
+
+    let mut data = Votes::new();
+    data.issue(
+        mid,
+        &get_motion_alpha(
+            Timestamp::now(),
+            &get_option_duality(idn.clone(), idy.clone()),
+        ),
+    );
+    data.vote_member(mid, idy, account_vecno!(), 100);
+
+    let mut context = VMContextBuilder::new().build();
+    context.block_timestamp = 10;
+    testing_env!(context);
+    data.revote(mid, idn, account_vecno!());
+}
+
+#[test]
+fn votes_finalize() {
+    let mid: &MotionId = &"M:01".to_string().into();
+    let vid: VoteId = "V:01".to_string().into();
+    let time = Timestamp::now();
+
+    let mut data = Votes::new();
+    data.issue(mid, &get_motion_alpha(time, &get_option_agree(vid.clone())));
+    data.vote_member(mid, vid.clone(), account_vecno!(), 100);
+    data.vote_board(mid, vid.clone(), account_nodra!(), 70);
+
+    let mut context = VMContextBuilder::new().build();
+    context.block_timestamp = 10;
+    testing_env!(context);
+
+    let result = data.finalize(mid);
+    assert_eq!(MotionStatus::Expired, result.status);
+    assert_eq!(2, result.voters);
+    assert_eq!(
+        Some(&JsonTally {
+            ceo: false,
+            board: 1,
+            members: 2,
+            weight: U128(170),
+        }),
+        result.tally.get(&vid)
+    );
+    assert!(data.motion_map.get(mid).is_none());
+    assert!(data.voices_map.get(mid).is_none());
+    assert!(data.get_vote(mid, &account_vecno!()).is_none());
+    assert_eq!(Some(result), data.get_result(mid));
+}
+
+#[test]
+#[should_panic(expected = "Can not finalize an open motion")]
+fn votes_finalize_open() {
+    let mid: &MotionId = &"M:01".to_string().into();
+    let vid: VoteId = "V:01".to_string().into();
+    let time = Timestamp::now();
+
+    let mut data = Votes::new();
+    data.issue(mid, &get_motion_beta(time, &get_option_agree(vid)));
+    data.finalize(mid);
+}
+
+#[test]
+fn votes_commit_reveal() {
+    let mid: &MotionId = &"M:01".to_string().into();
+    let idn: VoteId = "V:NO".to_string().into();
+    let idy: VoteId = "V:YES".to_string().into();
+    let salt = "salt".to_string();
+    let time = Timestamp::now();
+
+    let mut data = Votes::new();
+    let mut motion = get_motion_alpha(time, &get_option_duality(idn.clone(), idy.clone()));
+    motion.expires_at = time + Duration::from_nanos(20);
+    motion.reveal_at = Some(time + Duration::from_nanos(10));
+    data.issue(mid, &motion);
+
+    let hash = get_commitment_hash(&account_vecno!(), std::slice::from_ref(&idy), &salt);
+    data.commit(mid, hash, account_vecno!(), BallotRole::Ceo, 100);
+    let hash = get_commitment_hash(&account_nodra!(), std::slice::from_ref(&idn), &salt);
+    data.commit(mid, hash, account_nodra!(), BallotRole::Board, 70);
+    assert!(data.get_vote(mid, &account_vecno!()).is_none());
+
+    let mut context = VMContextBuilder::new().build();
+    context.block_timestamp = 10;
+    testing_env!(context);
+
+    let voices = data.reveal(mid, vec![idy.clone()], &salt, account_vecno!());
+    let tally = voices.tally.get(&idy).expect("missing tally yes");
+    assert_eq!((true, 1, 100), (tally.ceo, tally.members, tally.weight));
+    assert_eq!(1, voices.commits.len());
+    assert_eq!(Some(idy), data.get_vote(mid, &account_vecno!()));
+
+    let mut context = VMContextBuilder::new().build();
+    context.block_timestamp = 20;
+    testing_env!(context);
+
+    let result = data.finalize(mid);
+    assert_eq!(vec![account_nodra!()], result.unrevealed);
 }
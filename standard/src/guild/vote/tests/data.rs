@@ -147,51 +147,54 @@ fn motion_info_assert_new() {
     let base = MotionInfo {
         title: "title".to_string(),
         details: "details".to_string(),
-        issued_at: 0,
-        starts_at: 1,
-        expires_at: 2,
+        issued_at: Timestamp::from_nanos(0),
+        starts_at: Timestamp::from_nanos(1),
+        expires_at: Timestamp::from_nanos(2),
         executor: None,
         media: None,
         media_hash: None,
         reference: None,
         reference_hash: None,
         vote_options: get_option_agree(),
+        reveal_at: None,
     };
     base.assert_valid();
     let extra = MotionInfo {
         title: "123456789-123456789-12345678".to_string(),
         details: "123456789-123456789-123456789-123456789-123456789-123456789-123456789-123456789-123456789-123456789-123456789-123456789-12345678".to_string(),
-        issued_at: 1,
-        starts_at: 2,
-        expires_at: 3,
+        issued_at: Timestamp::from_nanos(1),
+        starts_at: Timestamp::from_nanos(2),
+        expires_at: Timestamp::from_nanos(3),
         executor: None,
         media: None,
         media_hash: None,
         reference: None,
         reference_hash: None,
         vote_options: get_option_duality(),
+        reveal_at: None,
     };
     extra.assert_valid();
     let exec = MotionInfo {
         title: "title".to_string(),
         details: "details".to_string(),
-        issued_at: 0,
-        starts_at: 1,
-        expires_at: 2,
+        issued_at: Timestamp::from_nanos(0),
+        starts_at: Timestamp::from_nanos(1),
+        expires_at: Timestamp::from_nanos(2),
         executor: Some(account_nodra!()),
         media: None,
         media_hash: None,
         reference: None,
         reference_hash: None,
         vote_options: get_option_agree(),
+        reveal_at: None,
     };
     exec.assert_valid();
     let exec = MotionInfo {
         title: "title".to_string(),
         details: "details".to_string(),
-        issued_at: 4,
-        starts_at: 4,
-        expires_at: 6,
+        issued_at: Timestamp::from_nanos(4),
+        starts_at: Timestamp::from_nanos(4),
+        expires_at: Timestamp::from_nanos(6),
         executor: Some(account_vecno!()),
         media: Some("ipfs://QmdCT4XXrRqSZHrY3KSnbXMNWWqw4PFLHCTdretVm5DetQ".to_string()),
         media_hash: Some(
@@ -202,6 +205,7 @@ fn motion_info_assert_new() {
             "0ae5c849b217f388fb5de38c3f28dd52ab3c4ea03aee780e1e24092084c4c528".to_string(),
         ),
         vote_options: get_option_duality(),
+        reveal_at: None,
     };
     exec.assert_valid();
 }
@@ -212,15 +216,16 @@ fn motion_info_assert_title() {
     let base = MotionInfo {
         title: "".to_string(),
         details: "details".to_string(),
-        issued_at: 0,
-        starts_at: 1,
-        expires_at: 2,
+        issued_at: Timestamp::from_nanos(0),
+        starts_at: Timestamp::from_nanos(1),
+        expires_at: Timestamp::from_nanos(2),
         executor: None,
         media: None,
         media_hash: None,
         reference: None,
         reference_hash: None,
         vote_options: get_option_agree(),
+        reveal_at: None,
     };
     base.assert_valid();
 }
@@ -231,15 +236,16 @@ fn motion_info_assert_title_max() {
     let base = MotionInfo {
         title: "123456789-123456789-123456789".to_string(),
         details: "details".to_string(),
-        issued_at: 0,
-        starts_at: 1,
-        expires_at: 2,
+        issued_at: Timestamp::from_nanos(0),
+        starts_at: Timestamp::from_nanos(1),
+        expires_at: Timestamp::from_nanos(2),
         executor: None,
         media: None,
         media_hash: None,
         reference: None,
         reference_hash: None,
         vote_options: get_option_agree(),
+        reveal_at: None,
     };
     base.assert_valid();
 }
@@ -250,15 +256,16 @@ fn motion_info_assert_details() {
     let base = MotionInfo {
         title: "title".to_string(),
         details: "123456789-123456789-123456789-123456789-123456789-123456789-123456789-123456789-123456789-123456789-123456789-123456789-123456789".to_string(),
-        issued_at: 0,
-        starts_at: 1,
-        expires_at: 2,
+        issued_at: Timestamp::from_nanos(0),
+        starts_at: Timestamp::from_nanos(1),
+        expires_at: Timestamp::from_nanos(2),
         executor: None,
         media: None,
         media_hash: None,
         reference: None,
         reference_hash: None,
         vote_options: get_option_agree(),
+        reveal_at: None,
     };
     base.assert_valid();
 }
@@ -269,15 +276,16 @@ fn motion_info_assert_time_start() {
     let base = MotionInfo {
         title: "title".to_string(),
         details: "details".to_string(),
-        issued_at: 2,
-        starts_at: 1,
-        expires_at: 2,
+        issued_at: Timestamp::from_nanos(2),
+        starts_at: Timestamp::from_nanos(1),
+        expires_at: Timestamp::from_nanos(2),
         executor: None,
         media: None,
         media_hash: None,
         reference: None,
         reference_hash: None,
         vote_options: get_option_agree(),
+        reveal_at: None,
     };
     base.assert_valid();
 }
@@ -288,15 +296,16 @@ fn motion_info_assert_time_expires() {
     let base = MotionInfo {
         title: "title".to_string(),
         details: "details".to_string(),
-        issued_at: 0,
-        starts_at: 1,
-        expires_at: 1,
+        issued_at: Timestamp::from_nanos(0),
+        starts_at: Timestamp::from_nanos(1),
+        expires_at: Timestamp::from_nanos(1),
         executor: None,
         media: None,
         media_hash: None,
         reference: None,
         reference_hash: None,
         vote_options: get_option_agree(),
+        reveal_at: None,
     };
     base.assert_valid();
 }
@@ -307,15 +316,16 @@ fn motion_info_assert_no_options() {
     let base = MotionInfo {
         title: "title".to_string(),
         details: "details".to_string(),
-        issued_at: 0,
-        starts_at: 1,
-        expires_at: 2,
+        issued_at: Timestamp::from_nanos(0),
+        starts_at: Timestamp::from_nanos(1),
+        expires_at: Timestamp::from_nanos(2),
         executor: None,
         media: None,
         media_hash: None,
         reference: None,
         reference_hash: None,
         vote_options: HashMap::new(),
+        reveal_at: None,
     };
     base.assert_valid();
 }
@@ -326,15 +336,16 @@ fn motion_info_assert_media() {
     let base = MotionInfo {
         title: "title".to_string(),
         details: "details".to_string(),
-        issued_at: 0,
-        starts_at: 1,
-        expires_at: 2,
+        issued_at: Timestamp::from_nanos(0),
+        starts_at: Timestamp::from_nanos(1),
+        expires_at: Timestamp::from_nanos(2),
         executor: None,
         media: Some("ipfs://QmWcSUSvv9Pq2n72V9aoPQUwaMqWeMZt7W1Quvm6RisSja".to_string()),
         media_hash: None,
         reference: None,
         reference_hash: None,
         vote_options: get_option_agree(),
+        reveal_at: None,
     };
     base.assert_valid();
 }
@@ -345,15 +356,16 @@ fn motion_info_assert_media_hash() {
     let base = MotionInfo {
         title: "title".to_string(),
         details: "details".to_string(),
-        issued_at: 0,
-        starts_at: 1,
-        expires_at: 2,
+        issued_at: Timestamp::from_nanos(0),
+        starts_at: Timestamp::from_nanos(1),
+        expires_at: Timestamp::from_nanos(2),
         executor: None,
         media: Some("ipfs://QmWcSUSvv9Pq2n72V9aoPQUwaMqWeMZt7W1Quvm6RisSja".to_string()),
         media_hash: Some("0b5de38c3f28dd52ab3c4ea03aee780e1e24092084c4c528".to_string()),
         reference: None,
         reference_hash: None,
         vote_options: get_option_agree(),
+        reveal_at: None,
     };
     base.assert_valid();
 }
@@ -364,15 +376,16 @@ fn motion_info_assert_reference() {
     let base = MotionInfo {
         title: "title".to_string(),
         details: "details".to_string(),
-        issued_at: 0,
-        starts_at: 1,
-        expires_at: 2,
+        issued_at: Timestamp::from_nanos(0),
+        starts_at: Timestamp::from_nanos(1),
+        expires_at: Timestamp::from_nanos(2),
         executor: None,
         media: None,
         media_hash: None,
         reference: Some("ipfs://QmWcSUSvv9Pq2n72V9aoPQUwaMqWeMZt7W1Quvm6RisSja".to_string()),
         reference_hash: None,
         vote_options: get_option_agree(),
+        reveal_at: None,
     };
     base.assert_valid();
 }
@@ -383,15 +396,318 @@ fn motion_info_assert_reference_hash() {
     let base = MotionInfo {
         title: "title".to_string(),
         details: "details".to_string(),
-        issued_at: 0,
-        starts_at: 1,
-        expires_at: 2,
+        issued_at: Timestamp::from_nanos(0),
+        starts_at: Timestamp::from_nanos(1),
+        expires_at: Timestamp::from_nanos(2),
         executor: None,
         media: None,
         media_hash: None,
         reference: Some("ipfs://QmWcSUSvv9Pq2n72V9aoPQUwaMqWeMZt7W1Quvm6RisSja".to_string()),
         reference_hash: Some("0b5de38c3f28dd52ab3c4ea03aee780e1e24092084c4c528".to_string()),
         vote_options: get_option_agree(),
+        reveal_at: None,
     };
     base.assert_valid();
 }
+
+// ==== Vote Policy ====
+
+fn get_policy_board_members(threshold: u16) -> VotePolicy {
+    VotePolicy {
+        quorum: 0,
+        threshold,
+        roles: vec![VoteRoles {
+            ceo: false,
+            board: true,
+            members: true,
+        }],
+        weighted: false,
+        delay: Duration::from_nanos(0),
+        reveal: EXPIRE_24H,
+        bond: None,
+    }
+}
+
+#[test]
+fn vote_policy_assert_new() {
+    get_policy_board_members(1).assert_valid(MotionKind::ChangeGuild);
+    get_policy_board_members(10000).assert_valid(MotionKind::ChangeGuild);
+    for kind in [
+        MotionKind::LockContract,
+        MotionKind::UnlockContract,
+        MotionKind::ChallengeContract,
+        MotionKind::ReplaceContract,
+        MotionKind::PolicyContract,
+        MotionKind::LockGuild,
+        MotionKind::UnlockGuild,
+        MotionKind::ChangeGuild,
+    ] {
+        get_default_policy(kind).assert_valid(kind);
+    }
+}
+
+#[test]
+#[should_panic(expected = "Quorum can not be more than 100_00 basis points")]
+fn vote_policy_assert_quorum() {
+    let mut policy = get_policy_board_members(8000);
+    policy.quorum = 10001;
+    policy.assert_valid(MotionKind::ChangeGuild);
+}
+
+#[test]
+#[should_panic(expected = "Threshold must be between 1 and 100_00 basis points")]
+fn vote_policy_assert_threshold() {
+    get_policy_board_members(0).assert_valid(MotionKind::ChangeGuild);
+}
+
+#[test]
+#[should_panic(expected = "The policy requires at least one set of roles")]
+fn vote_policy_assert_roles() {
+    let mut policy = get_policy_board_members(8000);
+    policy.roles = vec![];
+    policy.assert_valid(MotionKind::ChangeGuild);
+}
+
+#[test]
+#[should_panic(expected = "Each set of roles requires at least one role")]
+fn vote_policy_assert_roles_empty() {
+    let mut policy = get_policy_board_members(8000);
+    policy.roles.push(VoteRoles {
+        ceo: false,
+        board: false,
+        members: false,
+    });
+    policy.assert_valid(MotionKind::ChangeGuild);
+}
+
+#[test]
+#[should_panic(expected = "A delay is only supported for contract lock and unlock motions")]
+fn vote_policy_assert_delay() {
+    let mut policy = get_policy_board_members(8000);
+    policy.delay = Duration::from_hours(1);
+    policy.assert_valid(MotionKind::UnlockContract);
+    policy.assert_valid(MotionKind::SpendContract);
+}
+
+#[test]
+#[should_panic(expected = "The reveal has to start before the motion expires")]
+fn vote_policy_assert_reveal() {
+    let policy = get_policy_board_members(8000);
+    policy.assert_valid(MotionKind::ChallengeContract);
+    policy.assert_valid(MotionKind::LockContract);
+}
+
+fn get_count(board: u64, members: u64) -> MotionCount {
+    MotionCount {
+        board,
+        members,
+        value: members as u128 * 100,
+    }
+}
+
+#[test]
+fn vote_policy_is_passed() {
+    let policy = get_policy_board_members(8000);
+    let tally = MotionTally {
+        ceo: false,
+        board: 2,
+        members: 4,
+        weight: 400,
+    };
+    assert!(policy.is_passed(&tally, &get_count(2, 5)));
+    assert!(!policy.is_passed(&tally, &get_count(3, 5)));
+    assert!(!policy.is_passed(&tally, &get_count(2, 6)));
+    assert!(!policy.is_passed(&tally, &get_count(0, 5)));
+
+    let mut policy = get_default_policy(MotionKind::UnlockContract);
+    let tally = MotionTally {
+        ceo: true,
+        board: 0,
+        members: 4,
+        weight: 400,
+    };
+    assert!(policy.is_passed(&tally, &get_count(2, 5)));
+    assert!(!policy.is_passed(&tally, &get_count(2, 6)));
+
+    policy.quorum = 10000;
+    assert!(!policy.is_passed(&tally, &get_count(2, 5)));
+    assert!(policy.is_passed(&tally, &get_count(2, 4)));
+}
+
+#[test]
+fn vote_policy_is_passed_quorum_board() {
+    // Note: The member quorum does not gate a board only set of roles
+    let mut policy = get_default_policy(MotionKind::LockContract);
+    policy.quorum = 9000;
+    let tally = MotionTally {
+        ceo: false,
+        board: 2,
+        members: 2,
+        weight: 200,
+    };
+    assert!(policy.is_passed(&tally, &get_count(2, 5)));
+
+    let mut policy = get_policy_board_members(8000);
+    policy.quorum = 9000;
+    assert!(!policy.is_passed(&tally, &get_count(2, 3)));
+}
+
+#[test]
+fn vote_policy_is_passed_weighted() {
+    let mut policy = get_policy_board_members(8000);
+    policy.weighted = true;
+    let tally = MotionTally {
+        ceo: false,
+        board: 2,
+        members: 2,
+        weight: 800,
+    };
+    let count = MotionCount {
+        board: 2,
+        members: 5,
+        value: 1000,
+    };
+    assert!(policy.is_passed(&tally, &count));
+    assert!(!policy.is_passed(
+        &tally,
+        &MotionCount {
+            value: 1001,
+            ..count.clone()
+        }
+    ));
+    assert!(!policy.is_passed(
+        &tally,
+        &MotionCount {
+            value: 0,
+            ..count.clone()
+        }
+    ));
+
+    policy.quorum = 9000;
+    assert!(!policy.is_passed(&tally, &count));
+
+    policy.weighted = false;
+    policy.quorum = 0;
+    assert!(!policy.is_passed(&tally, &count));
+}
+
+#[test]
+fn vote_policy_get_target() {
+    assert_eq!(0, get_target(0, 8000));
+    assert_eq!(1, get_target(1, 8000));
+    assert_eq!(2, get_target(2, 8000));
+    assert_eq!(4, get_target(5, 8000));
+    assert_eq!(5, get_target(6, 8000));
+    assert_eq!(1, get_target(2, 5000));
+    assert_eq!(0, get_target(7, 0));
+}
+
+#[test]
+fn vote_policy_get_target_value() {
+    assert_eq!(0, get_target_value(0, 8000));
+    assert_eq!(1, get_target_value(1, 8000));
+    assert_eq!(800, get_target_value(1000, 8000));
+    assert_eq!(801, get_target_value(1001, 8000));
+    assert_eq!(u128::MAX, get_target_value(u128::MAX, 10000));
+    assert_eq!(0, get_target_value(u128::MAX, 0));
+}
+
+#[test]
+#[should_panic(expected = "The reveal must start while the motion is open")]
+fn motion_info_assert_reveal() {
+    let info = MotionInfo {
+        title: "title".to_string(),
+        details: "details".to_string(),
+        issued_at: Timestamp::from_nanos(0),
+        starts_at: Timestamp::from_nanos(1),
+        expires_at: Timestamp::from_nanos(3),
+        executor: None,
+        media: None,
+        media_hash: None,
+        reference: None,
+        reference_hash: None,
+        vote_options: get_option_agree(),
+        reveal_at: Some(Timestamp::from_nanos(2)),
+    };
+    info.assert_valid();
+    MotionInfo {
+        reveal_at: Some(Timestamp::from_nanos(3)),
+        ..info
+    }
+    .assert_valid();
+}
+
+fn get_ranking(options: &[usize]) -> Vec<VoteId> {
+    options
+        .iter()
+        .map(|index| get_vote_id_option(*index))
+        .collect()
+}
+
+#[test]
+fn choice_method_plurality() {
+    let options = get_ranking(&[0, 1, 2]);
+    let method = ChoiceMethod::Plurality;
+
+    let rankings = vec![get_ranking(&[0]), get_ranking(&[0]), get_ranking(&[1])];
+    assert_eq!(
+        Some(get_vote_id_option(0)),
+        method.resolve(&options, &rankings)
+    );
+
+    let rankings = vec![get_ranking(&[0]), get_ranking(&[1])];
+    assert_eq!(None, method.resolve(&options, &rankings));
+    assert_eq!(None, method.resolve(&options, &[]));
+}
+
+#[test]
+fn choice_method_ranked_choice() {
+    let options = get_ranking(&[0, 1, 2]);
+    let method = ChoiceMethod::RankedChoice;
+
+    // Note: The last option is eliminated and its ballot moves to the first option
+    let rankings = vec![
+        get_ranking(&[0, 1]),
+        get_ranking(&[0, 1]),
+        get_ranking(&[1, 0]),
+        get_ranking(&[2, 1]),
+        get_ranking(&[2, 1]),
+    ];
+    assert_eq!(
+        Some(get_vote_id_option(0)),
+        method.resolve(&options, &rankings)
+    );
+    assert_eq!(None, ChoiceMethod::Plurality.resolve(&options, &rankings));
+
+    // Note: Exhausted ballots no longer count towards the majority
+    let rankings = vec![
+        get_ranking(&[0]),
+        get_ranking(&[0]),
+        get_ranking(&[1]),
+        get_ranking(&[2]),
+    ];
+    assert_eq!(
+        Some(get_vote_id_option(0)),
+        method.resolve(&options, &rankings)
+    );
+
+    let rankings = vec![get_ranking(&[0]), get_ranking(&[1]), get_ranking(&[2, 0])];
+    assert_eq!(None, method.resolve(&options, &rankings));
+}
+
+#[test]
+fn motion_bond_settle() {
+    let bond = MotionBond {
+        amount: U128(100),
+        quorum: 2,
+        status: BondStatus::Held,
+    };
+    let settle = |status, voters| {
+        let mut bond = bond.clone();
+        bond.settle(status, voters);
+        bond.status
+    };
+    assert_eq!(BondStatus::Returned, settle(MotionStatus::Executed, 0));
+    assert_eq!(BondStatus::Returned, settle(MotionStatus::Expired, 2));
+    assert_eq!(BondStatus::Kept, settle(MotionStatus::Expired, 1));
+}
@@ -1,8 +1,8 @@
 use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
 use near_sdk::collections::{LazyOption, LookupMap, UnorderedMap, UnorderedSet};
-use near_sdk::json_types::{Base64VecU8, U128};
+use near_sdk::json_types::{Base64VecU8, U128, U64};
 use near_sdk::serde::{Deserialize, Serialize};
-use near_sdk::{env, require, AccountId, Balance, Gas, Promise, PromiseOrValue};
+use near_sdk::{env, require, AccountId, Balance, Gas, Promise, PromiseOrValue, PromiseResult};
 use std::{collections::HashMap, fmt};
 
 pub mod actor;
@@ -1,9 +1,11 @@
 use crate::*;
 
 pub mod meta;
+pub mod time;
 pub mod utility;
 
 pub use self::meta::*;
+pub use self::time::*;
 
 pub const MAX_BASE_POINTS_TOTAL: u16 = 10000;
 
@@ -33,6 +35,23 @@ pub enum StorageKey {
     VotesResultMap,
     VotesVoicesMap,
     VotesVoicesMapList { id: MotionId },
+    // Note: Prefixes follow the variant index, new keys are only appended
+    VotesPolicyMap,
+    GuildProposalMap,
+    VotesLatestMap,
+    GuildSpendMap,
+    GuildIncomeMap,
+    GuildCreateMap,
+    GuildDelegateMap,
+    GuildDelegatorMap,
+    GuildChoiceMap,
+    VotesCommitMapList { id: MotionId },
+    GuildQueueMap,
+    VotesSnapshotMap,
+    GuildMetadataMap,
+    GuildAccountHistory,
+    GuildStakeHistory,
+    VotesProxyMapList { id: MotionId },
 }
 
 // ==== Lock State ====
@@ -92,5 +111,7 @@ pub fn royalty_to_payout(royalty_percentage: u16, amount_to_pay: Balance) -> U12
 
 #[cfg(test)]
 mod tests {
+    mod keys;
     mod strings;
+    mod time;
 }
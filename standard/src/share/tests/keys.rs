@@ -0,0 +1,26 @@
+use crate::share::*;
+
+fn get_prefix(key: StorageKey) -> u8 {
+    key.try_to_vec().unwrap()[0]
+}
+
+// ==== Storage Keys ====
+
+#[test]
+fn storage_key_prefixes() {
+    // Note: Deployed contracts read their storage with these prefixes
+    assert_eq!(0, get_prefix(StorageKey::ActorDataForId));
+    assert_eq!(6, get_prefix(StorageKey::GuildsState));
+    let id: GuildId = "G:01".to_string().into();
+    assert_eq!(13, get_prefix(StorageKey::GuildMembersList { id }));
+    assert_eq!(14, get_prefix(StorageKey::TokenInfoById));
+    let owner_key: AccountId = "nodra.near".parse().unwrap();
+    assert_eq!(
+        17,
+        get_prefix(StorageKey::TokenListPerOwnerSet { owner_key })
+    );
+    assert_eq!(18, get_prefix(StorageKey::VotesMotionMap));
+    let id: MotionId = "GM:1".parse().unwrap();
+    assert_eq!(21, get_prefix(StorageKey::VotesVoicesMapList { id }));
+    assert_eq!(22, get_prefix(StorageKey::VotesPolicyMap));
+}
@@ -0,0 +1,57 @@
+use crate::share::*;
+
+use near_sdk::{test_utils::VMContextBuilder, testing_env};
+
+// ==== Duration ====
+
+#[test]
+fn duration_units() {
+    assert_eq!(1_000_000_000, Duration::from_secs(1).as_nanos());
+    assert_eq!(60_000_000_000, Duration::from_mins(1).as_nanos());
+    assert_eq!(3_600_000_000_000, Duration::from_hours(1).as_nanos());
+    assert_eq!(86400, Duration::from_hours(24).as_secs());
+    assert_eq!(0, Duration::from_nanos(999_999_999).as_secs());
+}
+
+#[test]
+fn duration_json() {
+    let json = serde_json::to_string(&Duration::from_secs(2)).unwrap();
+    assert_eq!("\"2000000000\"", json);
+    let duration: Duration = serde_json::from_str(&json).unwrap();
+    assert_eq!(Duration::from_secs(2), duration);
+}
+
+// ==== Timestamp ====
+
+#[test]
+fn timestamp_now() {
+    let mut context = VMContextBuilder::new().build();
+    context.block_timestamp = 1_500_000_000;
+    testing_env!(context);
+
+    let now = Timestamp::now();
+    assert_eq!(1_500_000_000, now.as_nanos());
+    assert_eq!(1, now.as_secs());
+    assert!(!now.is_past());
+    assert!((now - Duration::from_nanos(1)).is_past());
+}
+
+#[test]
+fn timestamp_math() {
+    let time = Timestamp::from_secs(10);
+    assert_eq!(Timestamp::from_secs(70), time + Duration::from_mins(1));
+    assert_eq!(Timestamp::from_secs(5), time - Duration::from_secs(5));
+    assert_eq!(Timestamp::from_nanos(0), time - Duration::from_hours(1));
+    assert_eq!(
+        Timestamp::from_nanos(u64::MAX),
+        time + Duration::from_nanos(u64::MAX)
+    );
+}
+
+#[test]
+fn timestamp_json() {
+    let json = serde_json::to_string(&Timestamp::from_nanos(42)).unwrap();
+    assert_eq!("\"42\"", json);
+    let time: Timestamp = serde_json::from_str(&json).unwrap();
+    assert_eq!(Timestamp::from_nanos(42), time);
+}
@@ -0,0 +1,139 @@
+use super::*;
+
+use std::ops::{Add, Sub};
+
+const NANOS_PER_SECOND: u64 = 1_000_000_000;
+const SECONDS_PER_MINUTE: u64 = 60;
+const SECONDS_PER_HOUR: u64 = 3600;
+
+// ==== Duration ====
+
+/// A span of time, stored in nanoseconds like the block timestamp.
+///
+/// Note: JSON encodes the value as a string of nanoseconds.
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    Hash,
+    Ord,
+    PartialEq,
+    PartialOrd,
+    BorshDeserialize,
+    BorshSerialize,
+)]
+pub struct Duration(u64);
+
+impl Duration {
+    pub const fn from_nanos(nanos: u64) -> Self {
+        Self(nanos)
+    }
+    pub const fn from_secs(seconds: u64) -> Self {
+        Self(seconds * NANOS_PER_SECOND)
+    }
+    pub const fn from_mins(minutes: u64) -> Self {
+        Self::from_secs(minutes * SECONDS_PER_MINUTE)
+    }
+    pub const fn from_hours(hours: u64) -> Self {
+        Self::from_secs(hours * SECONDS_PER_HOUR)
+    }
+
+    pub const fn as_nanos(&self) -> u64 {
+        self.0
+    }
+    pub const fn as_secs(&self) -> u64 {
+        self.0 / NANOS_PER_SECOND
+    }
+}
+
+impl Serialize for Duration {
+    fn serialize<S: near_sdk::serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        Serialize::serialize(&U64(self.0), serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Duration {
+    fn deserialize<D: near_sdk::serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Self, D::Error> {
+        <U64 as Deserialize>::deserialize(deserializer).map(|value| Self(value.0))
+    }
+}
+
+// ==== Timestamp ====
+
+/// A point in time, stored in nanoseconds since the unix epoch like the block timestamp.
+///
+/// Note: JSON encodes the value as a string of nanoseconds.
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    Hash,
+    Ord,
+    PartialEq,
+    PartialOrd,
+    BorshDeserialize,
+    BorshSerialize,
+)]
+pub struct Timestamp(u64);
+
+impl Timestamp {
+    /// Returns the timestamp of the current block.
+    pub fn now() -> Self {
+        Self(env::block_timestamp())
+    }
+
+    pub const fn from_nanos(nanos: u64) -> Self {
+        Self(nanos)
+    }
+    pub const fn from_secs(seconds: u64) -> Self {
+        Self(seconds * NANOS_PER_SECOND)
+    }
+
+    pub const fn as_nanos(&self) -> u64 {
+        self.0
+    }
+    pub const fn as_secs(&self) -> u64 {
+        self.0 / NANOS_PER_SECOND
+    }
+
+    /// Returns true when the current block is past the timestamp.
+    pub fn is_past(&self) -> bool {
+        env::block_timestamp() > self.0
+    }
+}
+
+impl Add<Duration> for Timestamp {
+    type Output = Timestamp;
+
+    fn add(self, duration: Duration) -> Timestamp {
+        Timestamp(self.0.saturating_add(duration.0))
+    }
+}
+
+impl Sub<Duration> for Timestamp {
+    type Output = Timestamp;
+
+    fn sub(self, duration: Duration) -> Timestamp {
+        Timestamp(self.0.saturating_sub(duration.0))
+    }
+}
+
+impl Serialize for Timestamp {
+    fn serialize<S: near_sdk::serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        Serialize::serialize(&U64(self.0), serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Timestamp {
+    fn deserialize<D: near_sdk::serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Self, D::Error> {
+        <U64 as Deserialize>::deserialize(deserializer).map(|value| Self(value.0))
+    }
+}
@@ -101,12 +101,12 @@ where
 pub fn refund_storage_deposit(storage_used: u64) {
     let required_cost = env::storage_byte_cost() * Balance::from(storage_used);
     let attached_deposit = env::attached_deposit();
-    let finalized_refund = attached_deposit - required_cost;
     //make sure that the attached deposit is greater than or equal to the required cost
     require!(
         required_cost <= attached_deposit,
         format!("Must attach {} yocto to cover storage cost", required_cost),
     );
+    let finalized_refund = attached_deposit - required_cost;
 
     if finalized_refund > 1 {
         Promise::new(env::predecessor_account_id()).transfer(finalized_refund);
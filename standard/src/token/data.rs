@@ -91,10 +91,10 @@ impl TokenPayout {
     // pub fn load_valid_cfg() -> TokenPayoutCfg
 
     pub fn new() -> Self {
-        return Self {
+        Self {
             guild: 0,
             accounts: HashMap::new(),
-        };
+        }
     }
 
     pub fn require_valid(&self) {
@@ -103,7 +103,7 @@ impl TokenPayout {
             self.accounts.len() < 5,
             "Cannot add more than 4 payouts per token"
         );
-        for (_account, amount) in &self.accounts {
+        for amount in self.accounts.values() {
             total += amount;
         }
         require!(
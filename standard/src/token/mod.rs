@@ -19,14 +19,19 @@ pub struct Tokens {
     pub list_per_owner: LookupMap<AccountId, UnorderedSet<TokenId>>,
 }
 
+impl Default for Tokens {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl Tokens {
     pub fn new() -> Self {
-        let this = Self {
+        Self {
             info_by_id: LookupMap::new(StorageKey::TokenInfoById.try_to_vec().unwrap()),
             data_for_id: UnorderedMap::new(StorageKey::TokenDataForId.try_to_vec().unwrap()),
             list_per_owner: LookupMap::new(StorageKey::TokenListPerOwner.try_to_vec().unwrap()),
-        };
-        this
+        }
     }
 
     pub fn register(
@@ -42,19 +47,19 @@ impl Tokens {
         token_payout.require_valid();
 
         let token = Token {
-            type_id: type_id,
+            type_id,
             owner: owner.clone(),
             payout: token_payout,
             approval_index: 0,
             approved_accounts: Default::default(),
         };
         require!(
-            self.info_by_id.insert(&token_id, &token).is_none(),
+            self.info_by_id.insert(token_id, &token).is_none(),
             "a token with the provided id already exits"
         );
 
-        self.add_to_owner(owner.account.clone().into(), &token_id);
-        self.data_for_id.insert(&token_id, &token_data);
+        self.add_to_owner(owner.account.clone(), token_id);
+        self.data_for_id.insert(token_id, &token_data);
 
         let nft_mint_log: JsonEventLog = JsonEventLog {
             standard: EVENT_NFT_STANDARD_NAME.to_string(),
@@ -76,10 +81,7 @@ impl Tokens {
         approval_id: Option<u64>,
         memo: Option<String>,
     ) -> Token {
-        let token = self
-            .info_by_id
-            .get(&token_id)
-            .expect("token info not found");
+        let token = self.info_by_id.get(token_id).expect("token info not found");
 
         require!(
             token.owner.token_id.is_none(),
@@ -87,13 +89,13 @@ impl Tokens {
         );
 
         if sender_id != &token.owner.account {
-            if !token.approved_accounts.contains_key(&sender_id) {
+            if !token.approved_accounts.contains_key(sender_id) {
                 env::panic_str("unauthorized transfer");
             }
             if let Some(enforced_approval_id) = approval_id {
                 let actual_approval_id = token
                     .approved_accounts
-                    .get(&sender_id)
+                    .get(sender_id)
                     .expect("sender is not authorized to transfer");
                 require!(
                     actual_approval_id == &enforced_approval_id,
@@ -117,10 +119,10 @@ impl Tokens {
             approval_index: token.approval_index,
             approved_accounts: Default::default(),
         };
-        self.info_by_id.insert(&token_id, &new_token);
+        self.info_by_id.insert(token_id, &new_token);
 
-        self.remove_from_owner(sender_id.clone(), &token_id);
-        self.add_to_owner(receiver_id.clone(), &token_id);
+        self.remove_from_owner(sender_id.clone(), token_id);
+        self.add_to_owner(receiver_id.clone(), token_id);
 
         let mut authorized_id = None;
         if approval_id.is_some() {